@@ -103,7 +103,8 @@
 //!
 //! - **Fiat–Shamir.** Replacing the verifier's random `c` with `c = H(g, h, u)` makes the proof
 //!   non-interactive and single-message — that transformation is exactly how one gets a Schnorr
-//!   *signature*. It is not applied here.
+//!   *signature*. It is not applied here; `scl_rs::sigma` provides this proof as
+//!   `sigma::schnorr::Schnorr`, with both an interactive and a Fiat–Shamir driver.
 //! - **Malicious verifiers.** The zero-knowledge argument above assumes the verifier samples `c`
 //!   honestly. Schnorr is proved honest-verifier zero-knowledge; full zero-knowledge against a
 //!   verifier that chooses `c` adversarially as a function of `u` needs additional machinery.
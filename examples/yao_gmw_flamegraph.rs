@@ -0,0 +1,309 @@
+//! Comparing the bandwidth of garbled circuits and GMW on the same boolean circuit.
+//!
+//! Two parties add their private 32-bit numbers with a ripple-carry adder, once with Yao's garbled
+//! circuits ([`YaoGarbledCircuit`]) and once with GMW ([`GmwAnd`]), both on the deterministic
+//! simulator. Each run writes its folded bandwidth stacks (see `examples/bandwidth_flamegraph.rs`),
+//! `yao.folded` and `gmw.folded`, and the example prints both totals and simulated running times:
+//!
+//! ```text
+//! <simulation>;YaoGarbledCircuit;IknpOtExtension;BaseOt ...
+//! <simulation>;YaoGarbledCircuit;IknpOtExtension        ...
+//! <simulation>;YaoGarbledCircuit                        ...
+//! <simulation>;GmwCircuit;PassiveDealLinearShr          ...
+//! <simulation>;GmwCircuit;TrustedBooleanTriples         ...
+//! <simulation>;GmwCircuit;GmwAnd;BatchedPassiveOpenShr  ...
+//! ```
+//!
+//! The two flamegraphs show the trade-off between the protocols. Yao sends the whole garbled
+//! circuit, 32 bytes per AND gate, in one message, after a fixed-cost OT extension for the
+//! evaluator's input. GMW sends a few bytes per AND gate, but needs one round per layer of AND
+//! gates: the adder's carry chain is 32 layers deep, and on the default 100 ms network the rounds,
+//! not the bytes, dominate its running time.
+//!
+//! Pass the path of a two-input Bristol Fashion file (such as `adder64.txt` from
+//! <https://nigelsmart.github.io/MPC-Circuits/>) to compare on that circuit instead:
+//!
+//! ```text
+//! cargo run --example yao_gmw_flamegraph -- adder64.txt
+//! ```
+//!
+//! GMW's triples come from [`TrustedBooleanTriples`] with the first party as the dealer, which
+//! stands in for a preprocessing phase: a dealer that also computes learns the other's input, so
+//! this is only a bandwidth comparison.
+
+use std::{env, fs, path::Path, time::Duration};
+
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    math::gf2::Gf2Word,
+    net::{simulation::channel::SimpleNetworkConfig, Network, PartyId},
+    prelude::{
+        simulate, Error, GeneralEnv, Protocol, ProtocolId, RandEnvironment, Ring, SimulationOutcome,
+    },
+    protocol::{
+        gmw::{and::GmwAnd, triple::TrustedBooleanTriples},
+        share::{deal::PassiveDealShr, open::BatchedPassiveOpenShr},
+        yao::{
+            circuit::{BooleanCircuit, Gate},
+            garble::YaoGarbledCircuit,
+        },
+    },
+    ss::xor::XorSS,
+};
+
+/// A ripple-carry adder of two `bits`-bit numbers in Bristol Fashion, least significant bit first,
+/// with one AND gate per bit: the carry is `c ⊕ ((a ⊕ c) ∧ (b ⊕ c))`.
+fn adder(bits: usize) -> String {
+    let mut gates = Vec::new();
+    let mut next = 2 * bits;
+    let mut wire = || {
+        next += 1;
+        next - 1
+    };
+    let mut carry = wire();
+    gates.push(format!("1 1 0 {carry} EQ"));
+    let mut sums = Vec::new();
+    for i in 0..bits {
+        let (a, b) = (i, bits + i);
+        let (ac, bc, and, sum, next_carry) = (wire(), wire(), wire(), wire(), wire());
+        gates.push(format!("2 1 {a} {carry} {ac} XOR"));
+        gates.push(format!("2 1 {b} {carry} {bc} XOR"));
+        gates.push(format!("2 1 {ac} {bc} {and} AND"));
+        gates.push(format!("2 1 {ac} {b} {sum} XOR"));
+        gates.push(format!("2 1 {carry} {and} {next_carry} XOR"));
+        sums.push(sum);
+        carry = next_carry;
+    }
+    for sum in sums {
+        let out = wire();
+        gates.push(format!("1 1 {sum} {out} EQW"));
+    }
+    format!(
+        "{} {next}\n2 {bits} {bits}\n1 {bits}\n\n{}\n",
+        gates.len(),
+        gates.join("\n")
+    )
+}
+
+// GMW over a whole circuit: the owners deal their input bits, the dealer hands out one triple per
+// AND gate, and the gates are evaluated layer by layer, with one `GmwAnd` per layer of AND gates.
+// Every wire holds the same bit in all 64 lanes of its word.
+struct GmwCircuit {
+    circuit: BooleanCircuit,
+    input: Vec<bool>,
+}
+
+impl<E: RandEnvironment> Protocol<E> for GmwCircuit {
+    // One bit vector per output value, on both parties.
+    type Output = Vec<Vec<bool>>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let parties = env.network().party_ids();
+        let mut wires: Vec<Option<XorSS>> = vec![None; self.circuit.wires()];
+
+        let mut wire = 0;
+        for (owner, bits) in parties.iter().zip(self.circuit.inputs()) {
+            for i in 0..*bits {
+                let deal = if *owner == me {
+                    let word = if self.input[i] {
+                        Gf2Word::ONE
+                    } else {
+                        Gf2Word::ZERO
+                    };
+                    PassiveDealShr::dealer(me, word, parties.clone(), ())
+                } else {
+                    PassiveDealShr::receiver(*owner)
+                };
+                wires[wire] = Some(deal.execute(env).await?);
+                wire += 1;
+            }
+        }
+        let zero = wires[0].clone().expect("the circuit has inputs") * &Gf2Word::ZERO;
+
+        let and_count = self.circuit.and_count();
+        let mut triples = TrustedBooleanTriples::new(parties[0], parties.clone(), and_count)?
+            .execute(env)
+            .await?;
+
+        // Every gate with the AND depth of its output: the number of AND gates on its longest path.
+        let mut depth = vec![0; self.circuit.wires()];
+        let mut gates = Vec::with_capacity(self.circuit.gates().len());
+        for gate in self.circuit.gates() {
+            let (out, d) = match *gate {
+                Gate::Xor { a, b, out } => (out, depth[a].max(depth[b])),
+                Gate::And { a, b, out } => (out, depth[a].max(depth[b]) + 1),
+                Gate::Inv { a, out } | Gate::Eqw { a, out } => (out, depth[a]),
+                Gate::Eq { out, .. } => (out, 0),
+            };
+            depth[out] = d;
+            gates.push((*gate, d));
+        }
+
+        let share = |wires: &[Option<XorSS>], wire: usize| {
+            wires[wire]
+                .clone()
+                .expect("gates come in topological order")
+        };
+        let layers = gates.iter().map(|(_, d)| *d).max().unwrap_or(0);
+        for layer in 0..=layers {
+            let in_layer = |gate: &&(Gate, usize)| gate.1 == layer;
+
+            // The layer's AND gates first, in one round; their inputs are all in earlier layers.
+            let ands: Vec<(usize, usize, usize)> = gates
+                .iter()
+                .filter(in_layer)
+                .filter_map(|(gate, _)| match *gate {
+                    Gate::And { a, b, out } => Some((a, b, out)),
+                    _ => None,
+                })
+                .collect();
+            if !ands.is_empty() {
+                let x = ands.iter().map(|(a, _, _)| share(&wires, *a)).collect();
+                let y = ands.iter().map(|(_, b, _)| share(&wires, *b)).collect();
+                let layer_triples = triples.drain(..ands.len()).collect();
+                let z = GmwAnd::new(x, y, layer_triples)?.execute(env).await?;
+                for ((_, _, out), z) in ands.iter().zip(z) {
+                    wires[*out] = Some(z);
+                }
+            }
+
+            // Then its local gates, in circuit order.
+            for (gate, _) in gates.iter().filter(in_layer) {
+                match *gate {
+                    Gate::Xor { a, b, out } => {
+                        wires[out] = Some(share(&wires, a) + &share(&wires, b));
+                    }
+                    Gate::Inv { a, out } => wires[out] = Some(share(&wires, a) + &Gf2Word::ONE),
+                    Gate::Eqw { a, out } => wires[out] = Some(share(&wires, a)),
+                    Gate::Eq { value, out } => {
+                        let word = if value { Gf2Word::ONE } else { Gf2Word::ZERO };
+                        wires[out] = Some(zero.clone() + &word);
+                    }
+                    Gate::And { .. } => {}
+                }
+            }
+        }
+
+        let outputs = self
+            .circuit
+            .output_wires()
+            .map(|wire| share(&wires, wire))
+            .collect();
+        let opened = BatchedPassiveOpenShr::new(outputs).execute(env).await?;
+        let bits: Vec<bool> = opened.iter().map(|word| word.bit(0)).collect();
+        let mut rest = &bits[..];
+        Ok(self
+            .circuit
+            .outputs()
+            .iter()
+            .map(|size| {
+                let (value, tail) = rest.split_at(*size);
+                rest = tail;
+                value.to_vec()
+            })
+            .collect())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("GmwCircuit")
+    }
+}
+
+/// Writes every party's bandwidth stacks to `path`, and returns the total bytes sent and the
+/// simulated time at which the last party finished.
+fn profile<O>(
+    outcome: &SimulationOutcome<O>,
+    parties: &[PartyId],
+    path: &str,
+) -> (usize, Duration) {
+    let mut folded = Vec::new();
+    for party in parties {
+        outcome
+            .bandwidth_tree_for(*party)
+            .expect("the party was part of the simulation")
+            .write_folded(&mut folded)
+            .expect("writing to a Vec cannot fail");
+    }
+    fs::write(path, &folded).expect("the folded file must be writable");
+
+    let bytes = String::from_utf8_lossy(&folded)
+        .lines()
+        .filter_map(|line| line.rsplit_once(' '))
+        .map(|(_, bytes)| bytes.parse::<usize>().expect("folded counts are numbers"))
+        .sum();
+    let time = parties
+        .iter()
+        .filter_map(|party| outcome.traces[party].events().last())
+        .map(|event| event.timestamp())
+        .max()
+        .unwrap_or_default();
+    (bytes, time)
+}
+
+fn main() {
+    let circuit = match env::args().nth(1) {
+        Some(path) => BooleanCircuit::load_bristol(Path::new(&path)).expect("a Bristol file"),
+        None => BooleanCircuit::from_bristol(&adder(32)).expect("the adder is well formed"),
+    };
+    let parties: Vec<PartyId> = (0..2).map(PartyId::from).collect();
+    let mut rng = rand::rng();
+    let inputs: Vec<Vec<bool>> = circuit
+        .inputs()
+        .iter()
+        .map(|bits| (0..*bits).map(|_| rng.random()).collect())
+        .collect();
+    assert_eq!(
+        inputs.len(),
+        2,
+        "the circuit must take one input value per party"
+    );
+    let expected = circuit
+        .evaluate(&inputs)
+        .expect("the inputs fit the circuit");
+
+    let yao = simulate(
+        SimpleNetworkConfig::default(),
+        parties.clone(),
+        |party| {
+            YaoGarbledCircuit::new(
+                circuit.clone(),
+                parties[0],
+                inputs[party.as_usize()].clone(),
+            )
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    let gmw = simulate(
+        SimpleNetworkConfig::default(),
+        parties.clone(),
+        |party| GmwCircuit {
+            circuit: circuit.clone(),
+            input: inputs[party.as_usize()].clone(),
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    for party in &parties {
+        assert_eq!(yao.outputs[party], expected, "Yao computed the circuit");
+        assert_eq!(gmw.outputs[party], expected, "GMW computed the circuit");
+    }
+
+    println!(
+        "circuit: {} AND gates, {} gates in all\n",
+        circuit.and_count(),
+        circuit.gates().len()
+    );
+    for (name, (bytes, time), path) in [
+        ("Yao", profile(&yao, &parties, "yao.folded"), "yao.folded"),
+        ("GMW", profile(&gmw, &parties, "gmw.folded"), "gmw.folded"),
+    ] {
+        println!("{name}: {bytes} bytes in {time:.2?} of simulated time, stacks in {path}");
+    }
+    println!("\nrender them as SVG flamegraphs with inferno (https://github.com/jonhoo/inferno):");
+    println!("    cargo install inferno");
+    println!("    inferno-flamegraph --countname bytes < yao.folded > yao.svg");
+    println!("    inferno-flamegraph --countname bytes < gmw.folded > gmw.svg");
+}
@@ -0,0 +1,350 @@
+//! Arithmetic circuits over a field, for describing a function once and evaluating it with any
+//! protocol that can add, scale and multiply shared values.
+//!
+//! An [`ArithmeticCircuit`](crate::circuit::ArithmeticCircuit) is a list of gates in topological
+//! order: each party's **inputs**, **additions** and **multiplications by a public constant** of
+//! earlier wires, **multiplications** of two earlier wires, and **outputs**, each revealed to one
+//! party. It is built gate by gate, and every gate that produces a value hands back the
+//! [`Wire`](crate::circuit::Wire) carrying it:
+//!
+//! ```
+//! use scl_rs::circuit::ArithmeticCircuit;
+//! use scl_rs::math::field::mersenne61::Mersenne61;
+//! use scl_rs::net::PartyId;
+//!
+//! // The inner product of two parties' vectors of length 2, revealed to a third party.
+//! let (alice, bob, carol) = (PartyId::from(0), PartyId::from(1), PartyId::from(2));
+//! let mut circuit = ArithmeticCircuit::<Mersenne61>::new();
+//! let x = [circuit.input(alice), circuit.input(alice)];
+//! let y = [circuit.input(bob), circuit.input(bob)];
+//! let products = [circuit.mul(x[0], y[0]), circuit.mul(x[1], y[1])];
+//! let sum = circuit.add(products[0], products[1]);
+//! circuit.output(sum, carol);
+//!
+//! assert_eq!(circuit.mul_count(), 2);
+//! assert_eq!(circuit.depth(), 1);
+//! ```
+//!
+//! Linear gates are local for any linear secret sharing, so what a protocol pays for is the
+//! multiplications, and with batched multiplication it pays one round per **layer** of them:
+//! [`ArithmeticCircuit::layers`](crate::circuit::ArithmeticCircuit::layers) groups the
+//! multiplications by their depth, the number of multiplications on the longest path to them, so
+//! that all the products of a layer can go in one
+//! batch. The protocol evaluating a circuit over Shamir sharing is
+//! [`PassiveShamirCircuit`](crate::protocol::passive_shamir::circuit::PassiveShamirCircuit).
+
+use std::collections::HashMap;
+
+use thiserror::Error;
+
+use crate::{math::ring::Ring, net::PartyId};
+
+/// Errors that occur when evaluating an [`ArithmeticCircuit`] in the clear.
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum ArithCircuitError {
+    /// A party was given a different number of input values than the circuit takes from it.
+    #[error("party {party:?} should have {expected} inputs, found {found}")]
+    InputCount {
+        /// The party whose inputs do not match.
+        party: PartyId,
+        /// The number of input gates the party owns.
+        expected: usize,
+        /// The number of input values given for it.
+        found: usize,
+    },
+}
+
+/// A wire of an [`ArithmeticCircuit`]: the value computed by one of its gates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Wire(usize);
+
+impl Wire {
+    /// Returns the index of the gate that computes the wire.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// A gate of an [`ArithmeticCircuit`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArithGate<F> {
+    /// The next input value of `owner`.
+    Input {
+        /// The party that provides the value.
+        owner: PartyId,
+    },
+    /// `a + b`.
+    Add {
+        /// The first operand.
+        a: Wire,
+        /// The second operand.
+        b: Wire,
+    },
+    /// `constant · a`, for a public constant.
+    ConstMul {
+        /// The operand.
+        a: Wire,
+        /// The public constant.
+        constant: F,
+    },
+    /// `a · b`.
+    Mul {
+        /// The first operand.
+        a: Wire,
+        /// The second operand.
+        b: Wire,
+    },
+    /// Reveals `a` to `owner`. An output gate computes no wire.
+    Output {
+        /// The wire revealed.
+        a: Wire,
+        /// The party that learns it.
+        owner: PartyId,
+    },
+}
+
+/// The gates at one multiplicative depth of an [`ArithmeticCircuit`], as indices into its gates.
+///
+/// The multiplications of a layer only read wires of earlier layers, so they can all be computed
+/// in one batch; the local gates of the layer, additions and multiplications by a constant, may
+/// read the layer's products and each other, and come in circuit order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Layer {
+    muls: Vec<usize>,
+    locals: Vec<usize>,
+}
+
+impl Layer {
+    /// Returns the multiplication gates of the layer, in circuit order.
+    pub fn muls(&self) -> &[usize] {
+        &self.muls
+    }
+
+    /// Returns the addition and constant-multiplication gates of the layer, in circuit order.
+    pub fn locals(&self) -> &[usize] {
+        &self.locals
+    }
+}
+
+/// An arithmetic circuit over `F` with private inputs and outputs; see the [module
+/// documentation](self).
+///
+/// The wire of the `i`-th gate has index `i`, and gates can only read the wires of earlier gates,
+/// so the gates are always in topological order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArithmeticCircuit<F> {
+    gates: Vec<ArithGate<F>>,
+}
+
+impl<F> Default for ArithmeticCircuit<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F> ArithmeticCircuit<F> {
+    /// Creates an empty circuit.
+    pub fn new() -> Self {
+        Self { gates: Vec::new() }
+    }
+
+    /// Adds an input gate for the next input value of `owner`, and returns its wire. A party's
+    /// input values are taken in the order of its input gates.
+    pub fn input(&mut self, owner: PartyId) -> Wire {
+        self.push(ArithGate::Input { owner })
+    }
+
+    /// Adds a gate computing `a + b`, and returns its wire.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not a wire of this circuit.
+    pub fn add(&mut self, a: Wire, b: Wire) -> Wire {
+        self.check(a);
+        self.check(b);
+        self.push(ArithGate::Add { a, b })
+    }
+
+    /// Adds a gate computing `constant · a`, and returns its wire.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` is not a wire of this circuit.
+    pub fn const_mul(&mut self, a: Wire, constant: F) -> Wire {
+        self.check(a);
+        self.push(ArithGate::ConstMul { a, constant })
+    }
+
+    /// Adds a gate computing `a · b`, and returns its wire.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not a wire of this circuit.
+    pub fn mul(&mut self, a: Wire, b: Wire) -> Wire {
+        self.check(a);
+        self.check(b);
+        self.push(ArithGate::Mul { a, b })
+    }
+
+    /// Adds a gate revealing `a` to `owner`. A party's outputs come in the order of its output
+    /// gates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` is not a wire of this circuit.
+    pub fn output(&mut self, a: Wire, owner: PartyId) {
+        self.check(a);
+        self.gates.push(ArithGate::Output { a, owner });
+    }
+
+    /// Returns the gates, in topological order.
+    pub fn gates(&self) -> &[ArithGate<F>] {
+        &self.gates
+    }
+
+    /// Returns the number of input gates owned by `party`.
+    pub fn input_count(&self, party: PartyId) -> usize {
+        self.gates
+            .iter()
+            .filter(|gate| matches!(gate, ArithGate::Input { owner } if *owner == party))
+            .count()
+    }
+
+    /// Returns the number of output gates owned by `party`.
+    pub fn output_count(&self, party: PartyId) -> usize {
+        self.gates
+            .iter()
+            .filter(|gate| matches!(gate, ArithGate::Output { owner, .. } if *owner == party))
+            .count()
+    }
+
+    /// Returns the number of multiplication gates, the ones that are not free to evaluate
+    /// securely.
+    pub fn mul_count(&self) -> usize {
+        self.gates
+            .iter()
+            .filter(|gate| matches!(gate, ArithGate::Mul { .. }))
+            .count()
+    }
+
+    /// Returns the multiplicative depth: the largest number of multiplications on a path from an
+    /// input to a wire.
+    pub fn depth(&self) -> usize {
+        self.depths().into_iter().max().unwrap_or(0)
+    }
+
+    /// Groups the gates by multiplicative depth, into `depth() + 1` layers.
+    ///
+    /// A wire's depth is `0` for an input, the larger depth of its operands for an addition or a
+    /// multiplication by a constant, and one more than that for a multiplication. Layer `d` holds
+    /// the multiplications and the local gates of depth `d`, so layer `0` has no multiplications:
+    /// it is the linear preprocessing of the inputs. Input and output gates belong to no layer.
+    pub fn layers(&self) -> Vec<Layer> {
+        let depths = self.depths();
+        let mut layers = vec![
+            Layer {
+                muls: Vec::new(),
+                locals: Vec::new(),
+            };
+            self.depth() + 1
+        ];
+        for (index, (gate, depth)) in self.gates.iter().zip(depths).enumerate() {
+            match gate {
+                ArithGate::Mul { .. } => layers[depth].muls.push(index),
+                ArithGate::Add { .. } | ArithGate::ConstMul { .. } => {
+                    layers[depth].locals.push(index)
+                }
+                ArithGate::Input { .. } | ArithGate::Output { .. } => {}
+            }
+        }
+        layers
+    }
+
+    /// Returns the depth of every gate's wire; an output gate has the depth of the wire it reveals.
+    fn depths(&self) -> Vec<usize> {
+        let mut depths: Vec<usize> = Vec::with_capacity(self.gates.len());
+        for gate in &self.gates {
+            let depth = match *gate {
+                ArithGate::Input { .. } => 0,
+                ArithGate::Add { a, b } => depths[a.0].max(depths[b.0]),
+                ArithGate::Mul { a, b } => depths[a.0].max(depths[b.0]) + 1,
+                ArithGate::ConstMul { a, .. } | ArithGate::Output { a, .. } => depths[a.0],
+            };
+            depths.push(depth);
+        }
+        depths
+    }
+
+    /// Appends `gate`, and returns its wire.
+    fn push(&mut self, gate: ArithGate<F>) -> Wire {
+        self.gates.push(gate);
+        Wire(self.gates.len() - 1)
+    }
+
+    /// Panics unless `wire` is computed by a gate of this circuit.
+    fn check(&self, wire: Wire) {
+        assert!(
+            self.gates
+                .get(wire.0)
+                .is_some_and(|gate| !matches!(gate, ArithGate::Output { .. })),
+            "wire {} is not a wire of this circuit",
+            wire.0
+        );
+    }
+}
+
+impl<F: Ring> ArithmeticCircuit<F> {
+    /// Evaluates the circuit in the clear on every party's input values, and returns every
+    /// party's output values, in the order of its input and output gates. Parties without output
+    /// gates are absent from the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ArithCircuitError::InputCount`] if a party is given a different number of input
+    /// values than the circuit takes from it; a missing party counts as giving none.
+    pub fn evaluate(
+        &self,
+        inputs: &HashMap<PartyId, Vec<F>>,
+    ) -> Result<HashMap<PartyId, Vec<F>>, ArithCircuitError> {
+        let mut owners: Vec<PartyId> = inputs.keys().copied().collect();
+        owners.extend(self.gates.iter().filter_map(|gate| match gate {
+            ArithGate::Input { owner } => Some(*owner),
+            _ => None,
+        }));
+        for party in owners {
+            let expected = self.input_count(party);
+            let found = inputs.get(&party).map_or(0, Vec::len);
+            if found != expected {
+                return Err(ArithCircuitError::InputCount {
+                    party,
+                    expected,
+                    found,
+                });
+            }
+        }
+
+        let mut next_input: HashMap<PartyId, usize> = HashMap::new();
+        let mut outputs: HashMap<PartyId, Vec<F>> = HashMap::new();
+        let mut values = Vec::with_capacity(self.gates.len());
+        for gate in &self.gates {
+            let value = match *gate {
+                ArithGate::Input { owner } => {
+                    let next = next_input.entry(owner).or_default();
+                    *next += 1;
+                    inputs[&owner][*next - 1]
+                }
+                ArithGate::Add { a, b } => values[a.0] + &values[b.0],
+                ArithGate::ConstMul { a, constant } => values[a.0] * &constant,
+                ArithGate::Mul { a, b } => values[a.0] * &values[b.0],
+                ArithGate::Output { a, owner } => {
+                    outputs.entry(owner).or_default().push(values[a.0]);
+                    // Output gates compute no wire; the placeholder keeps indices aligned.
+                    F::ZERO
+                }
+            };
+            values.push(value);
+        }
+        Ok(outputs)
+    }
+}
@@ -0,0 +1,313 @@
+//! Commitment schemes: commit to a value now, reveal it later.
+//!
+//! A [`Commitment`](crate::commitment::Commitment) scheme turns a value into a *commitment*, which
+//! can be published without revealing the value (**hiding**), and an *opening*, which the
+//! committer keeps. Revealing the value together with the opening later convinces anyone holding
+//! the commitment that this is the value committed to, and no other (**binding**). Commit-then-open
+//! is what keeps the last party to speak in a protocol from choosing its message after seeing
+//! everyone else's.
+//!
+//! Two schemes are provided:
+//!
+//! - [`HashScheme`](crate::commitment::HashScheme) commits to a ring element with SHA-256 over a
+//!   fresh 32-byte nonce and the element's canonical encoding. It is cheap and works for any
+//!   [`Ring`](crate::math::ring::Ring), but has no algebraic structure. Through
+//!   [`ContextualCommitment`](crate::commitment::ContextualCommitment) its commitments can also be
+//!   bound to a session and a committer, so they cannot be replayed elsewhere.
+//! - [`PedersenScheme`](crate::commitment::PedersenScheme) commits to a secp256k1 scalar `v` as
+//!   `v · G + r · H`. It is perfectly hiding and computationally binding, and **additively
+//!   homomorphic**: the sum of two commitments is a commitment to the sum of the values, opened by
+//!   the sum of the openings.
+//!
+//! Commitments and openings implement [`Abbreviate`](crate::abbreviate::Abbreviate), so they are
+//! labeled in the simulator's `SEND` traces when written with
+//! [`Packet::write_labeled`](crate::net::Packet::write_labeled).
+
+use std::{marker::PhantomData, ops::Add, sync::OnceLock};
+
+use rand::CryptoRng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{
+        ec::{secp256k1::Secp256k1, EllipticCurve},
+        field::secp256k1_scalar::Secp256k1ScalarField,
+        ring::Ring,
+    },
+    net::PartyId,
+};
+
+/// A commitment scheme for values of type [`Value`](Self::Value).
+pub trait Commitment {
+    /// The type of the committed values.
+    type Value;
+
+    /// The commitment, which can be published without revealing the value.
+    type Commitment;
+
+    /// The opening, kept by the committer and revealed together with the value.
+    type Opening;
+
+    /// Commits to `value`, returning the commitment and the opening that reveals it.
+    fn commit<R: CryptoRng>(value: &Self::Value, rng: &mut R) -> (Self::Commitment, Self::Opening);
+
+    /// Checks that `opening` opens `commitment` to `value`.
+    fn verify(commitment: &Self::Commitment, value: &Self::Value, opening: &Self::Opening) -> bool;
+}
+
+/// A [`Commitment`] scheme whose commitments can also be bound to a [`Context`](Self::Context),
+/// such as the session and the party that made them.
+///
+/// A commitment made in a context only opens in that same context. Protocols where every party
+/// commits use this to stop a party from echoing another party's commitment, or one from an
+/// earlier session, as its own.
+pub trait ContextualCommitment: Commitment {
+    /// What the commitments are bound to besides the value.
+    type Context;
+
+    /// Commits to `value` in `context`, returning the commitment and the opening that reveals it.
+    fn commit_in<R: CryptoRng>(
+        context: &Self::Context,
+        value: &Self::Value,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Opening);
+
+    /// Checks that `opening` opens `commitment`, made in `context`, to `value`.
+    fn verify_in(
+        context: &Self::Context,
+        commitment: &Self::Commitment,
+        value: &Self::Value,
+        opening: &Self::Opening,
+    ) -> bool;
+}
+
+/// A commitment of the [`HashScheme`]: the SHA-256 digest of the nonce and the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HashCommitment([u8; 32]);
+
+impl Abbreviate for HashCommitment {
+    const ABBREVIATION: &'static str = "hash com.";
+}
+
+/// An opening of the [`HashScheme`]: the 32-byte nonce that was hashed with the value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HashOpening([u8; 32]);
+
+impl Abbreviate for HashOpening {
+    const ABBREVIATION: &'static str = "hash opening";
+}
+
+/// The context of a [`HashScheme`] commitment made through [`ContextualCommitment`]: the session
+/// it belongs to and the party that made it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HashContext {
+    session: Vec<u8>,
+    committer: PartyId,
+}
+
+impl HashContext {
+    /// Creates the context of commitments made by `committer` in the session tagged `session`.
+    pub fn new(session: &[u8], committer: PartyId) -> Self {
+        Self {
+            session: session.to_vec(),
+            committer,
+        }
+    }
+
+    /// Returns the session tag.
+    pub fn session(&self) -> &[u8] {
+        &self.session
+    }
+
+    /// Returns the committing party.
+    pub fn committer(&self) -> PartyId {
+        self.committer
+    }
+}
+
+/// A hash-based commitment to an element of the ring `T`: `SHA-256(tag || nonce || value)` for a
+/// fresh 32-byte `nonce`.
+///
+/// The value is hashed in its canonical ([`postcard`]) encoding — ring elements are always stored
+/// reduced, so equal values encode to equal bytes. The scheme is binding as long as SHA-256 is
+/// collision resistant, and hiding in the random-oracle model.
+///
+/// Committed in a [`HashContext`] through [`ContextualCommitment`], the digest is instead
+/// `SHA-256(tag' || session || committer || nonce || value)`, under a different tag and with the
+/// session prefixed by its length.
+#[derive(Debug, Clone, Copy)]
+pub struct HashScheme<T>(PhantomData<T>);
+
+impl<T: Ring> HashScheme<T> {
+    fn digest(context: Option<&HashContext>, value: &T, nonce: &[u8; 32]) -> [u8; 32] {
+        let encoded = postcard::to_allocvec(value).expect("ring elements always serialize");
+        let mut hasher = Sha256::new();
+        match context {
+            None => hasher.update(b"scl-rs/hash-commitment"),
+            Some(context) => {
+                hasher.update(b"scl-rs/contextual-hash-commitment");
+                hasher.update((context.session.len() as u64).to_le_bytes());
+                hasher.update(&context.session);
+                hasher.update((context.committer.as_usize() as u64).to_le_bytes());
+            }
+        }
+        hasher.update(nonce);
+        hasher.update(encoded);
+        hasher.finalize().into()
+    }
+
+    fn commit_with<R: CryptoRng>(
+        context: Option<&HashContext>,
+        value: &T,
+        rng: &mut R,
+    ) -> (HashCommitment, HashOpening) {
+        let mut nonce = [0; 32];
+        rng.fill_bytes(&mut nonce);
+        (
+            HashCommitment(Self::digest(context, value, &nonce)),
+            HashOpening(nonce),
+        )
+    }
+}
+
+impl<T: Ring> Commitment for HashScheme<T> {
+    type Value = T;
+    type Commitment = HashCommitment;
+    type Opening = HashOpening;
+
+    fn commit<R: CryptoRng>(value: &T, rng: &mut R) -> (HashCommitment, HashOpening) {
+        Self::commit_with(None, value, rng)
+    }
+
+    fn verify(commitment: &HashCommitment, value: &T, opening: &HashOpening) -> bool {
+        Self::digest(None, value, &opening.0) == commitment.0
+    }
+}
+
+impl<T: Ring> ContextualCommitment for HashScheme<T> {
+    type Context = HashContext;
+
+    fn commit_in<R: CryptoRng>(
+        context: &HashContext,
+        value: &T,
+        rng: &mut R,
+    ) -> (HashCommitment, HashOpening) {
+        Self::commit_with(Some(context), value, rng)
+    }
+
+    fn verify_in(
+        context: &HashContext,
+        commitment: &HashCommitment,
+        value: &T,
+        opening: &HashOpening,
+    ) -> bool {
+        Self::digest(Some(context), value, &opening.0) == commitment.0
+    }
+}
+
+/// A commitment of the [`PedersenScheme`]: the point `v · G + r · H`.
+///
+/// Adding two commitments gives a commitment to the sum of their values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PedersenCommitment(Secp256k1);
+
+impl PedersenCommitment {
+    /// Returns the committed point.
+    pub fn point(&self) -> &Secp256k1 {
+        &self.0
+    }
+}
+
+impl Abbreviate for PedersenCommitment {
+    const ABBREVIATION: &'static str = "Pedersen com.";
+}
+
+impl Add<&Self> for PedersenCommitment {
+    type Output = Self;
+
+    fn add(self, rhs: &Self) -> Self::Output {
+        Self(self.0.add(&rhs.0))
+    }
+}
+
+/// An opening of the [`PedersenScheme`]: the blinding scalar `r`.
+///
+/// Adding two openings gives the opening of the sum of their commitments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PedersenOpening(Secp256k1ScalarField);
+
+impl Abbreviate for PedersenOpening {
+    const ABBREVIATION: &'static str = "Pedersen opening";
+}
+
+impl Add<&Self> for PedersenOpening {
+    type Output = Self;
+
+    fn add(self, rhs: &Self) -> Self::Output {
+        Self(self.0 + &rhs.0)
+    }
+}
+
+/// A Pedersen commitment to a secp256k1 scalar `v`: `v · G + r · H` for a uniform blinding scalar
+/// `r`.
+///
+/// `G` is the curve generator and `H` a second generator whose discrete logarithm with respect to
+/// `G` nobody knows (see [`PedersenScheme::h`]). The commitment is uniformly distributed whatever
+/// `v` is, so it hides perfectly; opening it to two different values would reveal `log_G(H)`, so
+/// it binds under the discrete-logarithm assumption.
+#[derive(Debug, Clone, Copy)]
+pub struct PedersenScheme;
+
+impl PedersenScheme {
+    /// Returns the second generator `H`.
+    ///
+    /// `H` is derived by hashing: it is the point of even `y` whose `x`-coordinate is the first
+    /// `SHA-256("scl-rs/pedersen-h" || i)`, for a counter `i = 0, 1, …`, that lies on the curve.
+    /// Nobody chose it, so nobody knows its discrete logarithm.
+    pub fn h() -> Secp256k1 {
+        static H: OnceLock<Secp256k1> = OnceLock::new();
+        *H.get_or_init(|| {
+            (0u64..)
+                .find_map(|i| {
+                    let mut hasher = Sha256::new();
+                    hasher.update(b"scl-rs/pedersen-h");
+                    hasher.update(i.to_be_bytes());
+                    Secp256k1::lift_x(&hasher.finalize().into())
+                })
+                .expect("about half of all x-coordinates lie on the curve")
+        })
+    }
+
+    fn point(value: &Secp256k1ScalarField, blinding: &Secp256k1ScalarField) -> Secp256k1 {
+        Secp256k1::gen()
+            .scalar_mul(value)
+            .add(&Self::h().scalar_mul(blinding))
+    }
+}
+
+impl Commitment for PedersenScheme {
+    type Value = Secp256k1ScalarField;
+    type Commitment = PedersenCommitment;
+    type Opening = PedersenOpening;
+
+    fn commit<R: CryptoRng>(
+        value: &Secp256k1ScalarField,
+        rng: &mut R,
+    ) -> (PedersenCommitment, PedersenOpening) {
+        let blinding = Secp256k1ScalarField::random(rng);
+        (
+            PedersenCommitment(Self::point(value, &blinding)),
+            PedersenOpening(blinding),
+        )
+    }
+
+    fn verify(
+        commitment: &PedersenCommitment,
+        value: &Secp256k1ScalarField,
+        opening: &PedersenOpening,
+    ) -> bool {
+        Self::point(value, &opening.0) == commitment.0
+    }
+}
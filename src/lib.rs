@@ -427,4 +427,17 @@ pub mod ss;
 /// Traits and structs to write and run protocols and manage their results.
 pub mod protocol;
 
+/// Hash-based and Pedersen commitment schemes.
+pub mod commitment;
+
+/// Σ-protocols over elliptic curves, their AND/OR composition, and how to run them interactively
+/// or non-interactively.
+pub mod sigma;
+
+/// Fiat–Shamir transcripts.
+pub mod transcript;
+
+/// Arithmetic circuits with private inputs and outputs, and their layering by multiplicative depth.
+pub mod circuit;
+
 pub mod abbreviate;
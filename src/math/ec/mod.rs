@@ -43,6 +43,12 @@ pub trait EllipticCurve<const LIMBS: usize>:
     ///
     /// A point at infinity is considered to be on the curve.
     fn is_on_curve(&self) -> bool;
+
+    /// Returns a canonical byte encoding of the point: equal points encode identically, whatever
+    /// their internal representation. Hash the point through this encoding rather than through its
+    /// serialization, which may differ between representations of the same point (for example,
+    /// projective coordinates).
+    fn to_canonical_bytes(&self) -> Vec<u8>;
 }
 
 macro_rules! impl_ec_abbreviation {
@@ -144,6 +144,35 @@ impl Secp256k1 {
         self.z().eq(&Secp256k1PrimeField::ZERO)
     }
 
+    /// Returns the SEC 1 compressed encoding of the point: a `0x02` (even `y`) or `0x03` (odd `y`)
+    /// prefix followed by the big-endian `x`-coordinate. The point at infinity encodes as 33 zero
+    /// bytes.
+    pub fn to_compressed(&self) -> [u8; 33] {
+        let mut bytes = [0; 33];
+        if self.is_point_at_infinity() {
+            return bytes;
+        }
+        let affine = self.to_affine();
+        let y = affine.y().to_be_bytes();
+        bytes[0] = 0x02 | (y[31] & 1);
+        bytes[1..].copy_from_slice(&affine.x().to_be_bytes());
+        bytes
+    }
+
+    /// Returns the point with the given `x`-coordinate and an even `y`-coordinate — the `lift_x`
+    /// of BIP-340 — or `None` if `x` is not the coordinate of a point on the curve.
+    pub fn lift_x(x: &[u8; 32]) -> Option<Self> {
+        let x = Secp256k1PrimeField::from_be_bytes(x)?;
+        let y_squared = x * &x * &x + &Secp256k1PrimeField::from(7);
+        let y = y_squared.sqrt()?;
+        let y = if y.to_be_bytes()[31] & 1 == 0 {
+            y
+        } else {
+            y.negate()
+        };
+        Some(Self(x, y, Secp256k1PrimeField::ONE))
+    }
+
     /// Doubles this point.
     pub fn dbl(&self) -> Self {
         let b3 = Secp256k1PrimeField::from(3 * 7);
@@ -287,6 +316,11 @@ impl EllipticCurve<4> for Secp256k1 {
     fn sub(&self, rhs: &Self) -> Self {
         self.add(&rhs.negate())
     }
+
+    /// The SEC 1 compressed encoding of [`to_compressed`](Secp256k1::to_compressed).
+    fn to_canonical_bytes(&self) -> Vec<u8> {
+        self.to_compressed().to_vec()
+    }
 }
 
 impl PartialEq for Secp256k1 {
@@ -345,4 +379,21 @@ mod tests {
         );
         assert!(!p.is_on_curve());
     }
+
+    #[test]
+    fn compressed_encoding_round_trips_through_lift_x() {
+        // The generator's y-coordinate is even, so it is its own `lift_x`; its negation is not.
+        let g = Secp256k1::gen();
+        let encoded = g.to_compressed();
+        assert_eq!(encoded[0], 0x02);
+        let x: [u8; 32] = encoded[1..].try_into().unwrap();
+        assert_eq!(Secp256k1::lift_x(&x), Some(g));
+        assert_eq!(g.negate().to_compressed()[0], 0x03);
+        assert_eq!(Secp256k1::lift_x(&x).map(|p| p.negate()), Some(g.negate()));
+
+        // x = 5 is not the coordinate of a point: 5^3 + 7 = 132 is not a square mod p.
+        let mut five = [0; 32];
+        five[31] = 5;
+        assert_eq!(Secp256k1::lift_x(&five), None);
+    }
 }
@@ -14,6 +14,34 @@ impl Secp256k1PrimeField {
     pub fn new(value: Uint<4>) -> Self {
         Self(value)
     }
+
+    /// Returns the 32-byte big-endian encoding of the element, as used for coordinates in SEC 1 and
+    /// BIP-340.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes().into()
+    }
+
+    /// Decodes a 32-byte big-endian encoding, returning `None` if it is not below the modulus.
+    pub fn from_be_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let value = Uint::<4>::from_be_slice(bytes);
+        (value < *Self::MODULUS).then_some(Self(value))
+    }
+
+    /// Returns a square root of the element, or `None` if it is not a square.
+    ///
+    /// The modulus is `3 mod 4`, so a root of a square `a` is `a^((p + 1) / 4)`; the result is
+    /// checked by squaring it.
+    pub fn sqrt(&self) -> Option<Self> {
+        let exp = Self::MODULUS.get().wrapping_add(&Uint::ONE).shr_vartime(2);
+        let mut root = Self::ONE;
+        for i in (0..exp.bits_vartime()).rev() {
+            root = root * &root;
+            if exp.bit_vartime(i) {
+                root = root * self;
+            }
+        }
+        (root * &root == *self).then_some(root)
+    }
 }
 
 impl FiniteField<4> for Secp256k1PrimeField {
@@ -12,6 +12,23 @@ const LIMBS: usize = 4;
 pub struct Secp256k1ScalarField(Uint<LIMBS>);
 
 impl Secp256k1ScalarField {
+    /// Returns the 32-byte big-endian encoding of the element, as used for scalars in BIP-340.
+    pub fn to_be_bytes(&self) -> [u8; 32] {
+        self.0.to_be_bytes().into()
+    }
+
+    /// Decodes a 32-byte big-endian encoding, returning `None` if it is not below the group order.
+    pub fn from_be_bytes(bytes: &[u8; 32]) -> Option<Self> {
+        let value = Uint::<4>::from_be_slice(bytes);
+        (value < *Self::MODULUS).then_some(Self(value))
+    }
+
+    /// Interprets 32 big-endian bytes — typically a hash — as an integer and reduces it modulo the
+    /// group order.
+    pub fn from_be_bytes_reduced(bytes: &[u8; 32]) -> Self {
+        Self(Uint::<4>::from_be_slice(bytes).rem(&Self::MODULUS))
+    }
+
     /// Computes the NAF representation of this field element.
     pub fn to_naf(&self) -> NafEncoding {
         let mut naf = NafEncoding::new(Self::BIT_SIZE + 1);
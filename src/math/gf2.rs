@@ -0,0 +1,127 @@
+use crate::{abbreviate::Abbreviate, math::ring::Ring};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// Sixty-four elements of GF(2) packed in a `u64`, one per bit.
+///
+/// The word is an element of the ring `GF(2)^64` with componentwise operations: addition (and
+/// subtraction) is bitwise XOR, multiplication is bitwise AND. Every bit is therefore an
+/// independent boolean wire, and a single ring operation evaluates the same gate on 64 instances
+/// at once. The additive identity is the all-zeros word and the multiplicative identity is the
+/// all-ones word; since every element is its own additive inverse, negation is the identity.
+///
+/// Because this is a [`Ring`], the generic machinery applies unchanged: additive sharing over
+/// `Gf2Word` is XOR sharing (see [`crate::ss::xor`]).
+///
+/// # Examples
+///
+/// ```
+/// use scl_rs::math::{gf2::Gf2Word, ring::Ring};
+///
+/// let x = Gf2Word::from(0b1100u64);
+/// let y = Gf2Word::from(0b1010u64);
+/// assert_eq!(x + &y, Gf2Word::from(0b0110u64)); // XOR
+/// assert_eq!(x * &y, Gf2Word::from(0b1000u64)); // AND
+/// assert_eq!(x * &Gf2Word::ONE, x);
+/// ```
+#[derive(PartialEq, Eq, Copy, Clone, Debug, Hash, Serialize, Deserialize)]
+pub struct Gf2Word(u64);
+
+impl Gf2Word {
+    /// Creates a word from its packed bits: bit `i` of `bits` is the `i`-th element.
+    pub fn new(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the packed bits of the word.
+    pub fn bits(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the `i`-th element of the word, as a boolean.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i >= 64`.
+    pub fn bit(&self, i: usize) -> bool {
+        assert!(i < 64, "a GF(2) word holds 64 elements, not {}", i + 1);
+        (self.0 >> i) & 1 == 1
+    }
+}
+
+impl From<u64> for Gf2Word {
+    fn from(bits: u64) -> Self {
+        Self(bits)
+    }
+}
+
+impl Abbreviate for Gf2Word {
+    const ABBREVIATION: &'static str = "GF(2) word";
+}
+
+impl Ring for Gf2Word {
+    const BIT_SIZE: usize = 64;
+    const ZERO: Self = Self(0);
+    const LIMBS: usize = 1;
+    const ONE: Self = Self(u64::MAX);
+
+    fn negate(&self) -> Self {
+        *self
+    }
+
+    fn random<R: Rng>(generator: &mut R) -> Self {
+        Self(generator.next_u64())
+    }
+
+    fn random_non_zero<R: Rng>(generator: &mut R) -> Self {
+        let mut value = generator.next_u64();
+        while value == 0 {
+            value = generator.next_u64();
+        }
+        Self(value)
+    }
+
+    /// Samples 64 independent random bits: a uniform word, whose every element is a random bit.
+    fn random_bit<R: Rng>(generator: &mut R) -> Self {
+        Self::random(generator)
+    }
+}
+
+impl Add<&Self> for Gf2Word {
+    type Output = Self;
+
+    // Addition in GF(2) is XOR.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, other: &Self) -> Self::Output {
+        Self(self.0 ^ other.0)
+    }
+}
+
+impl Sub<&Self> for Gf2Word {
+    type Output = Self;
+
+    // Subtraction in GF(2) is addition, that is, XOR.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, other: &Self) -> Self::Output {
+        Self(self.0 ^ other.0)
+    }
+}
+
+impl Mul<&Self> for Gf2Word {
+    type Output = Self;
+
+    // Multiplication in GF(2) is AND.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, other: &Self) -> Self::Output {
+        Self(self.0 & other.0)
+    }
+}
+
+impl Neg for Gf2Word {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        self
+    }
+}
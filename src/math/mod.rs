@@ -4,6 +4,10 @@
 /// This module contains the main traits and implementations of finite fields.
 pub mod field;
 
+/// This module contains the implementation of GF(2) elements packed in machine words, the
+/// boolean counterpart of the finite fields.
+pub mod gf2;
+
 /// This module contains the implementation of matrices over finite rings and
 /// their operations.
 pub mod matrix;
@@ -43,6 +43,19 @@ pub trait Ring:
     /// Returns a non-zero element from the ring sampled uniformly at random.
     fn random_non_zero<R: Rng>(generator: &mut R) -> Self;
 
+    /// Samples a uniformly random bit of the ring: [`ZERO`](Ring::ZERO) or [`ONE`](Ring::ONE).
+    ///
+    /// Rings that pack several independent bits in one element, such as
+    /// [`Gf2Word`](crate::math::gf2::Gf2Word), override this to sample every packed bit
+    /// independently, since their `ONE` sets all of them at once.
+    fn random_bit<R: Rng>(generator: &mut R) -> Self {
+        if generator.next_u32() & 1 == 1 {
+            Self::ONE
+        } else {
+            Self::ZERO
+        }
+    }
+
     /// Computes the power of a ring with an exponent.
     ///
     /// The maximum possible exponent is $2^{64}$ which is enough for common cryptography
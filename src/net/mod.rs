@@ -514,6 +514,20 @@ pub trait Network: Send {
         timeout: Duration,
     ) -> impl Future<Output = Result<(PartyId, Packet)>> + Send;
 
+    /// Waits for `duration` to pass without receiving anything; packets that arrive meanwhile stay
+    /// queued for later receives.
+    ///
+    /// This is the round timer of synchronous protocols. The deterministic simulator sleeps in
+    /// virtual time, so parties that start a round at the same virtual instant end it together; a
+    /// real-network backend sleeps in wall-clock time, which is what the default implementation
+    /// does.
+    fn sleep(&mut self, duration: Duration) -> impl Future<Output = Result<()>> + Send {
+        async move {
+            tokio::time::sleep(duration).await;
+            Ok(())
+        }
+    }
+
     /// Closes the connection with the network.
     fn close(&mut self) -> impl Future<Output = Result<()>> + Send;
 
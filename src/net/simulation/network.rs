@@ -8,7 +8,7 @@
 
 use crate::net;
 use crate::net::simulation::event::Event;
-use crate::net::simulation::switchboard::recv::RecvAnyTimeout;
+use crate::net::simulation::switchboard::recv::{RecvAnyTimeout, Sleep};
 use crate::net::simulation::switchboard::{
     recv::{Recv, RecvAny, RecvTimeout},
     Switchboard,
@@ -95,6 +95,11 @@ impl Network for SimNetwork {
         RecvTimeout::new(self.switchboard.clone(), party_id, self.local, timeout).await
     }
 
+    async fn sleep(&mut self, duration: Duration) -> net::Result<()> {
+        Sleep::new(self.switchboard.clone(), self.local, duration).await;
+        Ok(())
+    }
+
     fn other(&self) -> net::Result<PartyId> {
         if self.parties.len() != 2 {
             Err(NetworkError::ExpectedTwoNodeNet(self.parties.len()))
@@ -105,12 +105,10 @@ impl ProtocolBandwidthTree {
         while let Some((first, rest)) = events.split_first() {
             *events = rest;
             match first {
-                Event::SendData { link, size, .. } => {
-                    // Self-sends never touch the wire (TcpNetwork loops them back in-process),
-                    // so they don't count as bandwidth.
-                    if link.sender() != link.recipient() {
-                        metric.self_bytes += size;
-                    }
+                // Self-sends never touch the wire (TcpNetwork loops them back in-process), so they
+                // don't count as bandwidth.
+                Event::SendData { link, size, .. } if link.sender() != link.recipient() => {
+                    metric.self_bytes += size;
                 }
                 Event::ProtocolBegin { protocol_name, .. } => {
                     let child = Self::parse(*protocol_name, events);
@@ -9,6 +9,7 @@
 //!   virtual clock reaches the deadline.
 //! - [`RecvAnyTimeout`] combines the last two: the next packet from any peer, or
 //!   [`NetworkError::Timeout`] once the deadline passes.
+//! - [`Sleep`] receives nothing: it resolves once the party's virtual clock reaches a deadline.
 //!
 //! Every future holds an `Arc<Mutex<Switchboard>>`. On each poll it locks the switchboard and tries
 //! to take a packet; if none is ready it parks its waker on the relevant link, so a later delivery
@@ -258,3 +259,61 @@ impl Future for RecvAnyTimeout {
         }
     }
 }
+
+/// Suspension primitive that waits for virtual time to pass.
+///
+/// The first poll schedules a timer at the party's current virtual instant plus `duration`, on the
+/// party's link to itself; the future resolves once the party's clock reaches that deadline.
+/// Packets delivered meanwhile stay queued for later receives.
+pub(crate) struct Sleep {
+    /// The shared router that keeps the party's clock.
+    switchboard: Arc<Mutex<Switchboard>>,
+    /// The sleeping party.
+    local: PartyId,
+    /// How long to sleep, measured from the first poll.
+    duration: Duration,
+    /// The virtual instant the sleep ends, set by the first poll.
+    deadline: Option<Duration>,
+}
+
+impl Sleep {
+    /// Creates a future that resolves once `duration` of virtual time has passed for `local`.
+    pub(crate) fn new(
+        switchboard: Arc<Mutex<Switchboard>>,
+        local: PartyId,
+        duration: Duration,
+    ) -> Self {
+        Self {
+            switchboard,
+            local,
+            duration,
+            deadline: None,
+        }
+    }
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut switchboard = this.switchboard.lock().expect("the lock must be free");
+        let link = Link::new(this.local, this.local);
+        let deadline = match this.deadline {
+            Some(d) => d,
+            None => {
+                let deadline = switchboard.clock_of(this.local) + this.duration;
+                switchboard.schedule_timer(link, deadline);
+                this.deadline = Some(deadline);
+                deadline
+            }
+        };
+        if switchboard.clock_of(this.local) >= deadline {
+            Poll::Ready(())
+        } else {
+            // A delivery on the link to itself may wake the task early; it then parks again.
+            switchboard.park(link, cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
@@ -0,0 +1,137 @@
+use std::time::Duration;
+
+use crate::{
+    net::{Network, PartyId},
+    protocol::{
+        agreement::Rounds, broadcast::check_threshold, Environment, Error, Protocol, ProtocolId,
+    },
+};
+
+/// Binary Byzantine agreement, following the phase-king protocol of Berman, Garay and Perry
+/// (*Towards Optimal Distributed Consensus*, FOCS 1989).
+///
+/// The protocol runs `t + 1` phases of three rounds each; the king of phase `k` is the `k`-th
+/// party in ascending ID order.
+///
+/// 1. **Value.** Every party sends its current bit. A party proposes a bit that it got from at
+///    least `n − t` parties.
+/// 2. **Proposal.** Every party sends its proposal, or that it has none. A party adopts a bit that
+///    more than `t` parties proposed, and keeps it for the phase if `n − t` did.
+/// 3. **King.** The king sends its current bit, and every party that did not keep its own adopts
+///    the king's — `false` if the king is silent.
+///
+/// Honest parties propose at most one bit between them, since `n > 3t`. If they all start with
+/// the same bit, they all keep it through every phase (**validity**); otherwise, one of the
+/// `t + 1` kings is honest, and after its phase every honest party holds the same bit, which they
+/// then keep (**agreement**).
+///
+/// # Preconditions
+///
+/// Every party in the network runs this protocol with the same threshold and round duration,
+/// starting at the same instant, and the parties exchange no other messages while it runs. The
+/// round duration must exceed the network delay plus the skew between the parties' clocks: an
+/// honest message that misses its round is taken to be missing.
+///
+/// # Security model: active adversary
+///
+/// Tolerates up to `t < n / 3` Byzantine parties that may stay silent, send malformed messages,
+/// or send different bits to different parties. Every party finishes after `3(t + 1)` rounds.
+pub struct BinaryAgreement {
+    /// The local party's bit.
+    input: bool,
+    /// The number `t` of Byzantine parties tolerated.
+    threshold: usize,
+    /// How long every round lasts.
+    round_duration: Duration,
+}
+
+impl BinaryAgreement {
+    /// Creates the protocol instance for a party with the bit `input`.
+    pub fn new(input: bool, threshold: usize, round_duration: Duration) -> Self {
+        Self {
+            input,
+            threshold,
+            round_duration,
+        }
+    }
+}
+
+impl<E: Environment> Protocol<E> for BinaryAgreement {
+    /// The agreed bit.
+    type Output = bool;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        check_threshold(env, self.threshold)?;
+        let mut rounds = Rounds::new(env, self.round_duration);
+        let agreed = phase_king(env, &mut rounds, vec![self.input], self.threshold).await?;
+        Ok(agreed[0])
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("BinaryAgreement")
+    }
+}
+
+/// Runs the phases of a [`BinaryAgreement`] in `rounds`, on every bit of `inputs` side by side,
+/// and returns the agreed bits.
+///
+/// Every message carries one entry per bit; a message with another number of entries comes from
+/// a faulty party, and is dropped.
+pub(super) async fn phase_king<E: Environment>(
+    env: &mut E,
+    rounds: &mut Rounds,
+    inputs: Vec<bool>,
+    threshold: usize,
+) -> Result<Vec<bool>, Error> {
+    let me = env.network().local_party();
+    let mut parties = env.network().party_ids();
+    parties.sort_by_key(PartyId::as_usize);
+    let n = parties.len();
+    let len = inputs.len();
+
+    let mut values = inputs;
+    for king in &parties[..=threshold] {
+        let received = rounds.exchange(env, Some(values.clone())).await?;
+        let received: Vec<_> = received.values().filter(|bits| bits.len() == len).collect();
+        let proposals: Vec<Option<bool>> = (0..len)
+            .map(|i| {
+                let ones = received.iter().filter(|bits| bits[i]).count();
+                if ones >= n - threshold {
+                    Some(true)
+                } else if received.len() - ones >= n - threshold {
+                    Some(false)
+                } else {
+                    None
+                }
+            })
+            .collect();
+
+        let received = rounds.exchange(env, Some(proposals)).await?;
+        let received: Vec<_> = received
+            .values()
+            .filter(|proposals| proposals.len() == len)
+            .collect();
+        let mut keep = vec![false; len];
+        for i in 0..len {
+            let ones = received.iter().filter(|p| p[i] == Some(true)).count();
+            let zeros = received.iter().filter(|p| p[i] == Some(false)).count();
+            if ones > threshold {
+                values[i] = true;
+            } else if zeros > threshold {
+                values[i] = false;
+            }
+            keep[i] = ones.max(zeros) >= n - threshold;
+        }
+
+        let kings = rounds
+            .exchange(env, (*king == me).then(|| values.clone()))
+            .await?;
+        let king_values = kings.get(king).filter(|bits| bits.len() == len);
+        for i in 0..len {
+            if !keep[i] {
+                values[i] = king_values.is_some_and(|bits| bits[i]);
+            }
+        }
+    }
+    Ok(values)
+}
@@ -0,0 +1,216 @@
+use std::{mem, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    net::{Network, PartyId},
+    protocol::{
+        agreement::{binary::phase_king, Rounds},
+        broadcast::{bracha::BrachaInstance, check_threshold, BroadcastMessage, Instance},
+        Error, Protocol, ProtocolId, RandEnvironment,
+    },
+    ss::LinearShare,
+};
+
+/// The number of rounds the reports are broadcast in: with an honest sender, every honest party
+/// delivers after three, and the fourth lets the parties pulled along by the others' readies
+/// deliver too.
+const BROADCAST_ROUNDS: usize = 4;
+
+/// Input collection that tolerates missing dealers: every party deals its input, and the parties
+/// agree on the set of dealers whose dealings completed before a deadline, following the
+/// structure of Ben-Or, Kelmer and Rabin (*Asynchronous Secure Computations with Optimal
+/// Resilience*, PODC 1994).
+///
+/// 1. **Dealing.** Every party with an input splits it with [`LinearShare::shares_from_secret`]
+///    and sends every party its share, like
+///    [`PassiveDealShr`](crate::protocol::share::deal::PassiveDealShr). The dealing round lasts
+///    until `deadline`; a dealer whose shares have not arrived by then is taken to be missing.
+/// 2. **Reports.** Every party reports which dealers it got a share from, by a
+///    [Bracha broadcast](crate::protocol::broadcast::bracha::BrachaBroadcast) — one per party, all
+///    of them side by side in four rounds.
+/// 3. **Agreement.** The parties run one [`BinaryAgreement`](super::binary::BinaryAgreement) per
+///    dealer, side by side. A party votes for a dealer if it got its share and `n − t` of the
+///    delivered reports say so too.
+///
+/// Every honest dealer whose shares arrive by the deadline is agreed on, since every honest party
+/// votes for it. An agreed dealer was voted for by at least one honest party, so at least
+/// `n − 2t > t` honest parties hold its shares. The parties output the agreed dealers, in
+/// ascending ID order, with their shares of the dealers' inputs: a computation can then go on
+/// with whoever showed up. A Byzantine dealer may still be agreed on while leaving up to `t`
+/// honest parties without a share; those parties output no share of its input rather than fail,
+/// and the others hold enough shares to go on without them.
+///
+/// Construct a contributing party's instance with [`dealer`](AgreeOnCommonSubset::dealer) and the
+/// instance of a party without an input with [`receiver`](AgreeOnCommonSubset::receiver).
+///
+/// # Preconditions
+///
+/// Every party in the network runs this protocol with the same threshold, deadline and round
+/// duration, starting at the same instant, and the parties exchange no other messages while it
+/// runs. The deadline and the round duration must exceed the network delay plus the skew between
+/// the parties' clocks.
+///
+/// # Security model
+///
+/// The agreement on the set of dealers tolerates up to `t < n / 3` Byzantine parties, which may
+/// stay silent, show up late, or send different reports to different parties; every party
+/// finishes after `1 + 4 + 3(t + 1)` rounds. The dealing itself is passive, as in
+/// [`PassiveDealShr`](crate::protocol::share::deal::PassiveDealShr): a dealer that sends shares
+/// to `n − t` parties only may still be agreed on, and the parties it left out output no share of
+/// its input.
+pub struct AgreeOnCommonSubset<S: LinearShare> {
+    /// The local party's input and how to share it; `None` on parties without an input.
+    dealing: Option<Dealing<S>>,
+    /// The number `t` of Byzantine parties tolerated.
+    threshold: usize,
+    /// How long the dealing round lasts.
+    deadline: Duration,
+    /// How long every later round lasts.
+    round_duration: Duration,
+}
+
+/// The input that only a dealer holds: the secret and the sharing's threshold.
+struct Dealing<S: LinearShare> {
+    /// The secret to split into shares.
+    secret: S::Value,
+    /// The scheme's reconstruction-threshold parameter (see [`LinearShare::Threshold`]).
+    threshold: S::Threshold,
+}
+
+impl<S: LinearShare> AgreeOnCommonSubset<S> {
+    /// Creates the protocol instance for a party that deals `secret`, shared with the scheme's
+    /// reconstruction-threshold parameter `share_threshold`, among parties tolerating `threshold`
+    /// Byzantine ones.
+    pub fn dealer(
+        secret: S::Value,
+        share_threshold: S::Threshold,
+        threshold: usize,
+        deadline: Duration,
+        round_duration: Duration,
+    ) -> Self {
+        Self {
+            dealing: Some(Dealing {
+                secret,
+                threshold: share_threshold,
+            }),
+            threshold,
+            deadline,
+            round_duration,
+        }
+    }
+
+    /// Creates the protocol instance for a party without an input, which only receives shares.
+    pub fn receiver(threshold: usize, deadline: Duration, round_duration: Duration) -> Self {
+        Self {
+            dealing: None,
+            threshold,
+            deadline,
+            round_duration,
+        }
+    }
+}
+
+/// The output of [`AgreeOnCommonSubset`]: the agreed dealers and the local party's shares of their
+/// inputs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CommonSubset<S> {
+    /// The dealers every honest party agreed on, in ascending ID order.
+    pub dealers: Vec<PartyId>,
+    /// The local party's share of every agreed dealer's input: `shares[i]` is of `dealers[i]`'s,
+    /// or `None` if that dealer's share never reached the local party.
+    pub shares: Vec<Option<S>>,
+}
+
+impl<S, E> Protocol<E> for AgreeOnCommonSubset<S>
+where
+    S: LinearShare,
+    E: RandEnvironment,
+    S::Value: Sync + Send + 'static,
+{
+    type Output = CommonSubset<S>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let n = check_threshold(env, self.threshold)?;
+        let me = env.network().local_party();
+        let mut parties = env.network().party_ids();
+        parties.sort_by_key(PartyId::as_usize);
+        let mut rounds = Rounds::new(env, self.round_duration);
+
+        // Deal, and take the shares that arrive before the deadline.
+        let messages = match self.dealing {
+            Some(dealing) => {
+                let shares = S::shares_from_secret(
+                    dealing.secret,
+                    &parties,
+                    dealing.threshold,
+                    env.rng_mut(),
+                )?;
+                parties.iter().copied().zip(shares).collect()
+            }
+            None => Vec::new(),
+        };
+        let mut shares = rounds.scatter(env, messages, self.deadline).await?;
+
+        // Broadcast the reports, all instances at once, and every message of a round in one
+        // packet per party.
+        let report: Vec<bool> = parties.iter().map(|p| shares.contains_key(p)).collect();
+        let mut instances: Vec<BrachaInstance<Vec<bool>>> = parties
+            .iter()
+            .map(|sender| BrachaInstance::new(me, *sender, self.threshold, n))
+            .collect();
+        let mine = parties.iter().position(|p| *p == me).ok_or(Error::Input)?;
+        let mut outbox = vec![(mine, BroadcastMessage::Send(report))];
+        for _ in 0..BROADCAST_ROUNDS {
+            // The local party's own messages are processed in place, as they are sent.
+            let mut batch = Vec::new();
+            while !outbox.is_empty() {
+                for (i, message) in mem::take(&mut outbox) {
+                    let replies = instances[i].on_message(me, message.clone());
+                    outbox.extend(replies.into_iter().map(|reply| (i, reply)));
+                    batch.push((i, message));
+                }
+            }
+            let received = rounds.exchange(env, Some(batch)).await?;
+            for (from, batch) in received {
+                if from == me {
+                    continue;
+                }
+                for (i, message) in batch {
+                    if let Some(instance) = instances.get_mut(i) {
+                        let replies = instance.on_message(from, message);
+                        outbox.extend(replies.into_iter().map(|reply| (i, reply)));
+                    }
+                }
+            }
+        }
+        let reports: Vec<&Vec<bool>> = instances
+            .iter()
+            .filter_map(|instance| instance.output())
+            .filter(|report| report.len() == n)
+            .collect();
+
+        // Agree on every dealer.
+        let votes = parties
+            .iter()
+            .enumerate()
+            .map(|(j, dealer)| {
+                shares.contains_key(dealer)
+                    && reports.iter().filter(|report| report[j]).count() >= n - self.threshold
+            })
+            .collect();
+        let agreed = phase_king(env, &mut rounds, votes, self.threshold).await?;
+
+        let dealers: Vec<PartyId> = parties
+            .into_iter()
+            .zip(agreed)
+            .filter_map(|(dealer, agreed)| agreed.then_some(dealer))
+            .collect();
+        let shares = dealers.iter().map(|dealer| shares.remove(dealer)).collect();
+        Ok(CommonSubset { dealers, shares })
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("AgreeOnCommonSubset")
+    }
+}
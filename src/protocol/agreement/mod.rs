@@ -0,0 +1,185 @@
+//! Byzantine agreement: every honest party outputs the same value, tolerating up to `t < n / 3`
+//! Byzantine parties.
+//!
+//! Unlike [`broadcast`](crate::protocol::broadcast), where one sender's value is distributed, here
+//! every party has an input and the parties settle on one of them — for example, on whether an
+//! input was received before a deadline. The protocols are **synchronous**: they run in lockstep
+//! rounds kept by [`Network::sleep`](crate::net::Network::sleep), so a silent or selectively
+//! silent party cannot make honest parties fall out of step:
+//!
+//! - [`binary`](crate::protocol::agreement::binary) —
+//!   [`BinaryAgreement`](crate::protocol::agreement::binary::BinaryAgreement): the phase-king
+//!   protocol on a bit.
+//! - [`multivalued`](crate::protocol::agreement::multivalued) —
+//!   [`ByzantineAgreement`](crate::protocol::agreement::multivalued::ByzantineAgreement): agreement
+//!   on a value of any serializable type, reduced to binary agreement.
+//! - [`common_subset`](crate::protocol::agreement::common_subset) —
+//!   [`AgreeOnCommonSubset`](crate::protocol::agreement::common_subset::AgreeOnCommonSubset):
+//!   input collection that agrees on the dealers whose dealings completed before a deadline.
+//!
+//! Both exchange [`AgreementMessage`](crate::protocol::agreement::AgreementMessage)s tagged with
+//! their round.
+
+/// Phase-king binary agreement.
+pub mod binary;
+/// Agreement on the dealers whose dealings completed before a deadline.
+pub mod common_subset;
+/// Multivalued agreement, reduced to binary agreement.
+pub mod multivalued;
+
+use std::{collections::HashMap, time::Duration};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    abbreviate::Abbreviate,
+    net::{Network, NetworkError, Packet, PartyId},
+    protocol::{Environment, Error},
+};
+
+/// A message of [`BinaryAgreement`](binary::BinaryAgreement) or
+/// [`ByzantineAgreement`](multivalued::ByzantineAgreement), tagged with the round it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AgreementMessage<T> {
+    /// The round the message is sent in, counted from zero.
+    pub round: u64,
+    /// The value sent.
+    pub value: T,
+}
+
+impl<T> Abbreviate for AgreementMessage<T> {
+    const ABBREVIATION: &'static str = "BA msg.";
+}
+
+/// The round tag of an [`AgreementMessage`], decoded without its value, whose type depends on the
+/// round.
+#[derive(Deserialize)]
+struct RoundTag {
+    round: u64,
+}
+
+/// How many rounds ahead of the current one a message may be, and still be kept for its round.
+///
+/// Honest parties are at most one round ahead of each other; the margin covers a whole phase of
+/// [`BinaryAgreement`](binary::BinaryAgreement) while bounding what a faulty party can make the
+/// others hold on to.
+const MAX_ROUNDS_AHEAD: u64 = 3;
+
+/// The lockstep rounds of a synchronous protocol.
+///
+/// Every round lasts `duration` for every party: a party sends its message, sleeps until the round
+/// ends, and then takes the messages that arrived. As long as `duration` exceeds the network
+/// delay, every honest message of a round arrives within it, and the parties that start together
+/// end every round together, whatever the faulty parties do.
+struct Rounds {
+    /// The local party.
+    me: PartyId,
+    /// How long every round lasts.
+    duration: Duration,
+    /// The number of the next round.
+    round: u64,
+    /// The packets of rounds still to come, by round, with the first one of every party.
+    pending: HashMap<u64, HashMap<PartyId, Packet>>,
+}
+
+impl Rounds {
+    fn new<E: Environment>(env: &E, duration: Duration) -> Self {
+        Self {
+            me: env.network().local_party(),
+            duration,
+            round: 0,
+            pending: HashMap::new(),
+        }
+    }
+
+    /// Runs the next round: sends `value`, if any, to every other party, waits for the round to
+    /// end, and returns the value every party sent in it, the local party's included.
+    async fn exchange<T, E>(
+        &mut self,
+        env: &mut E,
+        value: Option<T>,
+    ) -> Result<HashMap<PartyId, T>, Error>
+    where
+        T: Serialize + DeserializeOwned + Clone,
+        E: Environment,
+    {
+        let messages = match value {
+            Some(value) => env
+                .network()
+                .party_ids()
+                .into_iter()
+                .map(|party| (party, value.clone()))
+                .collect(),
+            None => Vec::new(),
+        };
+        let duration = self.duration;
+        self.scatter(env, messages, duration).await
+    }
+
+    /// Runs the next round, lasting `duration`: sends every party its value in `messages`, waits
+    /// for the round to end, and returns the value every party sent the local party in it, the
+    /// local party's own included.
+    ///
+    /// Messages of the next [`MAX_ROUNDS_AHEAD`] rounds, sent by a party that is ahead, are kept
+    /// for their round. Messages of past or later rounds, messages that do not decode, and all but
+    /// the first message of a party in a round come from faulty parties, and are dropped.
+    async fn scatter<T, E>(
+        &mut self,
+        env: &mut E,
+        messages: Vec<(PartyId, T)>,
+        duration: Duration,
+    ) -> Result<HashMap<PartyId, T>, Error>
+    where
+        T: Serialize + DeserializeOwned,
+        E: Environment,
+    {
+        let round = self.round;
+        self.round += 1;
+
+        let mut received = HashMap::new();
+        let mut packets = Vec::with_capacity(messages.len());
+        for (party, value) in messages {
+            if party == self.me {
+                received.insert(party, value);
+                continue;
+            }
+            let mut pkt = Packet::empty();
+            pkt.write_labeled(&AgreementMessage { round, value })?;
+            packets.push((party, pkt));
+        }
+        env.network_mut().send_many(&packets).await?;
+
+        env.network_mut().sleep(duration).await?;
+        loop {
+            match env
+                .network_mut()
+                .recv_any_with_timeout(Duration::ZERO)
+                .await
+            {
+                Ok((from, pkt)) => {
+                    let Ok(tag) = pkt.read::<RoundTag>(0) else {
+                        continue;
+                    };
+                    if from != self.me
+                        && tag.round >= round
+                        && tag.round - round <= MAX_ROUNDS_AHEAD
+                    {
+                        self.pending
+                            .entry(tag.round)
+                            .or_default()
+                            .entry(from)
+                            .or_insert(pkt);
+                    }
+                }
+                Err(NetworkError::Timeout(_)) => break,
+                Err(err) => return Err(err.into()),
+            }
+        }
+        for (from, pkt) in self.pending.remove(&round).unwrap_or_default() {
+            if let Ok(message) = pkt.read::<AgreementMessage<T>>(0) {
+                received.entry(from).or_insert(message.value);
+            }
+        }
+        Ok(received)
+    }
+}
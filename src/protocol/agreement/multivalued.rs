@@ -0,0 +1,105 @@
+use std::{collections::HashMap, time::Duration};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::protocol::{
+    agreement::{binary::phase_king, Rounds},
+    broadcast::check_threshold,
+    Environment, Error, Protocol, ProtocolId,
+};
+
+/// Multivalued Byzantine agreement, following the reduction of Turpin and Coan (*Extending Binary
+/// Byzantine Agreement to Multivalued Byzantine Agreement*, Information Processing Letters, 1984)
+/// to [`BinaryAgreement`](super::binary::BinaryAgreement).
+///
+/// 1. **Value.** Every party sends its input. A party that got the same value from at least
+///    `n − t` parties retains it.
+/// 2. **Retained value.** Every party sends its retained value, if any, and picks the value most
+///    parties retained. It votes for that value if `n − t` parties retained it.
+/// 3. **Vote.** The parties run binary agreement on their votes. If it ends in `true`, every party
+///    outputs its picked value; otherwise, none of them outputs one.
+///
+/// Honest parties retain at most one value between them, since `n > 3t`, so if any honest party
+/// votes for a value, at least `n − 2t > t` honest parties retained it and every honest party
+/// picks it. The parties therefore output the same value or none at all (**agreement**), and if
+/// they all start with the same value, they output it (**validity**).
+///
+/// # Preconditions
+///
+/// Every party in the network runs this protocol with the same threshold and round duration,
+/// starting at the same instant, and the parties exchange no other messages while it runs. The
+/// round duration must exceed the network delay plus the skew between the parties' clocks: an
+/// honest message that misses its round is taken to be missing.
+///
+/// # Security model: active adversary
+///
+/// Tolerates up to `t < n / 3` Byzantine parties that may stay silent, send malformed messages,
+/// or send different values to different parties. Every party finishes after `3(t + 1) + 2`
+/// rounds.
+pub struct ByzantineAgreement<T> {
+    /// The local party's value.
+    input: T,
+    /// The number `t` of Byzantine parties tolerated.
+    threshold: usize,
+    /// How long every round lasts.
+    round_duration: Duration,
+}
+
+impl<T> ByzantineAgreement<T> {
+    /// Creates the protocol instance for a party with the value `input`.
+    pub fn new(input: T, threshold: usize, round_duration: Duration) -> Self {
+        Self {
+            input,
+            threshold,
+            round_duration,
+        }
+    }
+}
+
+impl<T, E> Protocol<E> for ByzantineAgreement<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync,
+    E: Environment,
+{
+    /// The agreed value, or `None` if the honest parties' inputs differ and they agreed on no
+    /// value.
+    type Output = Option<T>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let n = check_threshold(env, self.threshold)?;
+        let quorum = n - self.threshold;
+        let mut rounds = Rounds::new(env, self.round_duration);
+
+        let inputs = rounds.exchange(env, Some(self.input)).await?;
+        let retained = most_frequent(inputs.into_values())
+            .filter(|(_, count)| *count >= quorum)
+            .map(|(value, _)| value);
+
+        let retained = rounds.exchange(env, retained).await?;
+        let picked = most_frequent(retained.into_values());
+        let vote = picked.as_ref().is_some_and(|(_, count)| *count >= quorum);
+
+        let agreed = phase_king(env, &mut rounds, vec![vote], self.threshold).await?;
+        Ok(picked.filter(|_| agreed[0]).map(|(value, _)| value))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("ByzantineAgreement")
+    }
+}
+
+/// Returns the value that occurs most often in `values`, with its number of occurrences; ties go
+/// to the value with the smallest encoding, so that every party breaks them the same way.
+fn most_frequent<T: Serialize>(values: impl IntoIterator<Item = T>) -> Option<(T, usize)> {
+    let mut counts: HashMap<Vec<u8>, (T, usize)> = HashMap::new();
+    for value in values {
+        // A value that does not encode cannot be compared, and is left out.
+        if let Ok(encoded) = postcard::to_allocvec(&value) {
+            counts.entry(encoded).or_insert((value, 0)).1 += 1;
+        }
+    }
+    counts
+        .into_iter()
+        .min_by(|(a, (_, a_count)), (b, (_, b_count))| b_count.cmp(a_count).then(a.cmp(b)))
+        .map(|(_, entry)| entry)
+}
@@ -0,0 +1,191 @@
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    net::{Network, PartyId},
+    protocol::{
+        broadcast::{check_threshold, drive, BroadcastMessage, Instance, Tally},
+        Environment, Error, Protocol, ProtocolId,
+    },
+};
+
+/// Reliable broadcast, following Bracha (*Asynchronous Byzantine Agreement Protocols*, Information
+/// and Computation, 1987): send, echo, ready.
+///
+/// 1. **Send.** The sender sends its value to every party.
+/// 2. **Echo.** Every party echoes the first value it gets from the sender to every party.
+/// 3. **Ready.** A party announces that it is ready to deliver a value once `⌈(n + t + 1) / 2⌉`
+///    parties echoed it, or once `t + 1` parties announced it — at least one of them honest. It
+///    delivers the value once `2t + 1` parties announced it.
+///
+/// On top of the consistency of [`EchoBroadcast`](super::echo::EchoBroadcast), this is
+/// **reliable**: if one honest party delivers, `t + 1` honest parties announced the value, so
+/// every honest party eventually announces it too, and all of them deliver. With a faulty sender
+/// the honest parties may deliver nothing, but then none of them does.
+///
+/// Messages are taken in arrival order with
+/// [`recv_any_with_timeout`](crate::net::Network::recv_any_with_timeout), so a silent party never
+/// blocks the others. A party keeps listening after it delivers, until every other party has sent
+/// its messages or `timeout` passes with no message: it still echoes and announces for the parties
+/// that are behind, which is what lets them deliver.
+///
+/// Construct the sender's instance with [`sender`](BrachaBroadcast::sender) and every other
+/// party's with [`receiver`](BrachaBroadcast::receiver).
+///
+/// # Preconditions
+///
+/// Every party in the network runs this protocol with the same sender, threshold and timeout, and
+/// the parties exchange no other messages while it runs: a message that arrives during the
+/// broadcast is taken to be part of it.
+///
+/// # Security model: active adversary
+///
+/// Tolerates up to `t < n / 3` Byzantine parties, the sender included, that may stay silent, send
+/// malformed messages, or send different values to different parties. Parties that deliver
+/// nothing before the messages stop coming fail with a
+/// [`NetworkError::Timeout`](crate::net::NetworkError::Timeout); the timeout must be long enough
+/// for honest messages to arrive, or reliability is lost to it.
+pub struct BrachaBroadcast<T> {
+    /// The party whose value is broadcast.
+    sender: PartyId,
+    /// The value to broadcast; `Some` on the sender only.
+    payload: Option<T>,
+    /// The number `t` of Byzantine parties tolerated.
+    threshold: usize,
+    /// How long a party waits for the next message before giving up.
+    timeout: Duration,
+}
+
+impl<T> BrachaBroadcast<T> {
+    /// Creates the protocol instance for the **sender**, the local party `sender`, which
+    /// broadcasts `payload`.
+    pub fn sender(sender: PartyId, payload: T, threshold: usize, timeout: Duration) -> Self {
+        Self {
+            sender,
+            payload: Some(payload),
+            threshold,
+            timeout,
+        }
+    }
+
+    /// Creates the protocol instance for a **receiver** of `sender`'s value.
+    pub fn receiver(sender: PartyId, threshold: usize, timeout: Duration) -> Self {
+        Self {
+            sender,
+            payload: None,
+            threshold,
+            timeout,
+        }
+    }
+}
+
+impl<T, E> Protocol<E> for BrachaBroadcast<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync,
+    E: Environment,
+{
+    /// The sender's value.
+    type Output = T;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let n = check_threshold(env, self.threshold)?;
+        let me = env.network().local_party();
+        let initial = if self.sender == me {
+            vec![BroadcastMessage::Send(self.payload.ok_or(Error::Input)?)]
+        } else {
+            Vec::new()
+        };
+        let instance = BrachaInstance::new(me, self.sender, self.threshold, n);
+        drive(env, instance, self.timeout, initial).await
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("BrachaBroadcast")
+    }
+}
+
+/// A party's state in a [`BrachaBroadcast`].
+pub(crate) struct BrachaInstance<T> {
+    me: PartyId,
+    sender: PartyId,
+    threshold: usize,
+    /// The number of matching echoes that makes a party ready.
+    echo_quorum: usize,
+    /// Whether the sender's value has arrived.
+    got_send: bool,
+    /// Whether the local party has announced a value.
+    sent_ready: bool,
+    echoes: Tally,
+    readies: Tally,
+    output: Option<T>,
+    /// The number of messages still expected from the other parties.
+    pending: usize,
+}
+
+impl<T> BrachaInstance<T> {
+    /// Creates the state of the local party `me` in a broadcast from `sender` among `n` parties.
+    pub(crate) fn new(me: PartyId, sender: PartyId, threshold: usize, n: usize) -> Self {
+        Self {
+            me,
+            sender,
+            threshold,
+            echo_quorum: (n + threshold + 2) / 2,
+            got_send: false,
+            sent_ready: false,
+            echoes: Tally::new(),
+            readies: Tally::new(),
+            output: None,
+            // An echo and a ready from every other party, and the value from the sender.
+            pending: 2 * (n - 1) + usize::from(sender != me),
+        }
+    }
+}
+
+impl<T: Serialize + Clone> Instance<T> for BrachaInstance<T> {
+    fn on_message(
+        &mut self,
+        from: PartyId,
+        message: BroadcastMessage<T>,
+    ) -> Vec<BroadcastMessage<T>> {
+        let mut replies = Vec::new();
+        match message {
+            BroadcastMessage::Send(value) if from == self.sender && !self.got_send => {
+                self.got_send = true;
+                self.pending -= usize::from(from != self.me);
+                replies.push(BroadcastMessage::Echo(value));
+            }
+            BroadcastMessage::Echo(value) => {
+                if let Some(count) = self.echoes.vote(from, &value) {
+                    self.pending -= usize::from(from != self.me);
+                    if count >= self.echo_quorum && !self.sent_ready {
+                        self.sent_ready = true;
+                        replies.push(BroadcastMessage::Ready(value));
+                    }
+                }
+            }
+            BroadcastMessage::Ready(value) => {
+                if let Some(count) = self.readies.vote(from, &value) {
+                    self.pending -= usize::from(from != self.me);
+                    if count > self.threshold && !self.sent_ready {
+                        self.sent_ready = true;
+                        replies.push(BroadcastMessage::Ready(value.clone()));
+                    }
+                    if count > 2 * self.threshold && self.output.is_none() {
+                        self.output = Some(value);
+                    }
+                }
+            }
+            _ => {}
+        }
+        replies
+    }
+
+    fn output(&self) -> Option<&T> {
+        self.output.as_ref()
+    }
+
+    fn complete(&self) -> bool {
+        self.output.is_some() && self.pending == 0
+    }
+}
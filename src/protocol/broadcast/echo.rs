@@ -0,0 +1,159 @@
+use std::time::Duration;
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    net::{Network, PartyId},
+    protocol::{
+        broadcast::{check_threshold, drive, BroadcastMessage, Instance, Tally},
+        Environment, Error, Protocol, ProtocolId,
+    },
+};
+
+/// Echo broadcast: the sender sends its value to every party, every party echoes the value it got
+/// to every other, and a party delivers a value once `⌈(n + t + 1) / 2⌉` parties echoed it.
+///
+/// Two such quorums share an honest party, and an honest party echoes only one value, so no two
+/// honest parties deliver different values (**consistency**), and if the sender is honest every
+/// honest party delivers its value. A faulty sender, however, can get some honest parties to
+/// deliver and leave the others without a value;
+/// [`BrachaBroadcast`](super::bracha::BrachaBroadcast) closes that gap at the cost of one more
+/// round.
+///
+/// Messages are taken in arrival order with
+/// [`recv_any_with_timeout`](crate::net::Network::recv_any_with_timeout), so a silent party never
+/// blocks the others. A party keeps listening after it delivers, until every other party has sent
+/// its messages or `timeout` passes with no message, so that the honest parties' messages do not
+/// outlive the protocol.
+///
+/// Construct the sender's instance with [`sender`](EchoBroadcast::sender) and every other party's
+/// with [`receiver`](EchoBroadcast::receiver).
+///
+/// # Preconditions
+///
+/// Every party in the network runs this protocol with the same sender, threshold and timeout, and
+/// the parties exchange no other messages while it runs: a message that arrives during the
+/// broadcast is taken to be part of it.
+///
+/// # Security model: active adversary
+///
+/// Tolerates up to `t < n / 3` Byzantine parties, the sender included, that may stay silent, send
+/// malformed messages, or send different values to different parties. A party that delivers
+/// nothing before the messages stop coming fails with a
+/// [`NetworkError::Timeout`](crate::net::NetworkError::Timeout).
+pub struct EchoBroadcast<T> {
+    /// The party whose value is broadcast.
+    sender: PartyId,
+    /// The value to broadcast; `Some` on the sender only.
+    payload: Option<T>,
+    /// The number `t` of Byzantine parties tolerated.
+    threshold: usize,
+    /// How long a party waits for the next message before giving up.
+    timeout: Duration,
+}
+
+impl<T> EchoBroadcast<T> {
+    /// Creates the protocol instance for the **sender**, the local party `sender`, which
+    /// broadcasts `payload`.
+    pub fn sender(sender: PartyId, payload: T, threshold: usize, timeout: Duration) -> Self {
+        Self {
+            sender,
+            payload: Some(payload),
+            threshold,
+            timeout,
+        }
+    }
+
+    /// Creates the protocol instance for a **receiver** of `sender`'s value.
+    pub fn receiver(sender: PartyId, threshold: usize, timeout: Duration) -> Self {
+        Self {
+            sender,
+            payload: None,
+            threshold,
+            timeout,
+        }
+    }
+}
+
+impl<T, E> Protocol<E> for EchoBroadcast<T>
+where
+    T: Serialize + DeserializeOwned + Clone + Send + Sync,
+    E: Environment,
+{
+    /// The sender's value.
+    type Output = T;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let n = check_threshold(env, self.threshold)?;
+        let me = env.network().local_party();
+        let initial = if self.sender == me {
+            vec![BroadcastMessage::Send(self.payload.ok_or(Error::Input)?)]
+        } else {
+            Vec::new()
+        };
+        let instance = EchoInstance {
+            me,
+            sender: self.sender,
+            quorum: (n + self.threshold + 2) / 2,
+            got_send: false,
+            echoes: Tally::new(),
+            output: None,
+            // An echo from every other party, and the value from the sender.
+            pending: n - 1 + usize::from(self.sender != me),
+        };
+        drive(env, instance, self.timeout, initial).await
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("EchoBroadcast")
+    }
+}
+
+/// A party's state in an [`EchoBroadcast`].
+struct EchoInstance<T> {
+    me: PartyId,
+    sender: PartyId,
+    /// The number of matching echoes that delivers a value.
+    quorum: usize,
+    /// Whether the sender's value has arrived.
+    got_send: bool,
+    echoes: Tally,
+    output: Option<T>,
+    /// The number of messages still expected from the other parties.
+    pending: usize,
+}
+
+impl<T: Serialize + Clone> Instance<T> for EchoInstance<T> {
+    fn on_message(
+        &mut self,
+        from: PartyId,
+        message: BroadcastMessage<T>,
+    ) -> Vec<BroadcastMessage<T>> {
+        let mut replies = Vec::new();
+        match message {
+            BroadcastMessage::Send(value) if from == self.sender && !self.got_send => {
+                self.got_send = true;
+                self.pending -= usize::from(from != self.me);
+                replies.push(BroadcastMessage::Echo(value));
+            }
+            BroadcastMessage::Echo(value) => {
+                if let Some(count) = self.echoes.vote(from, &value) {
+                    self.pending -= usize::from(from != self.me);
+                    if count >= self.quorum && self.output.is_none() {
+                        self.output = Some(value);
+                    }
+                }
+            }
+            _ => {}
+        }
+        replies
+    }
+
+    fn output(&self) -> Option<&T> {
+        self.output.as_ref()
+    }
+
+    fn complete(&self) -> bool {
+        self.output.is_some() && self.pending == 0
+    }
+}
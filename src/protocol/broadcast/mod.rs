@@ -0,0 +1,168 @@
+//! Broadcast of a value from one sender to every party, tolerating up to `t < n / 3` Byzantine
+//! parties — the sender included.
+//!
+//! Point-to-point channels let a faulty sender tell different parties different things. The
+//! protocols here add echo rounds so that honest parties never accept different values, and wait
+//! for a quorum of matching messages through
+//! [`Network::recv_any_with_timeout`](crate::net::Network::recv_any_with_timeout), so silent
+//! parties cost a timeout but never block the others:
+//!
+//! - [`echo`](crate::protocol::broadcast::echo) —
+//!   [`EchoBroadcast`](crate::protocol::broadcast::echo::EchoBroadcast): send, then echo. Honest
+//!   parties that deliver, deliver the same value, but a faulty sender can make some of them
+//!   deliver and others not.
+//! - [`bracha`](crate::protocol::broadcast::bracha) —
+//!   [`BrachaBroadcast`](crate::protocol::broadcast::bracha::BrachaBroadcast): send, echo, then
+//!   ready. Additionally, if one honest party delivers, all of them do.
+//!
+//! Both carry a payload of any serializable type, and exchange
+//! [`BroadcastMessage`](crate::protocol::broadcast::BroadcastMessage)s.
+
+/// Bracha's reliable broadcast.
+pub mod bracha;
+/// Echo broadcast: consistent, but not reliable.
+pub mod echo;
+
+use std::{
+    collections::{HashMap, HashSet},
+    mem,
+    time::Duration,
+};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    abbreviate::Abbreviate,
+    net::{Network, NetworkError, Packet, PartyId},
+    protocol::{Environment, Error},
+};
+
+/// A message of [`EchoBroadcast`](echo::EchoBroadcast) or
+/// [`BrachaBroadcast`](bracha::BrachaBroadcast), tagged with the step it belongs to.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BroadcastMessage<T> {
+    /// The sender's value, sent by the sender only.
+    Send(T),
+    /// A party's echo of the value it received from the sender.
+    Echo(T),
+    /// A party's announcement that it is ready to deliver the value.
+    Ready(T),
+}
+
+impl<T> Abbreviate for BroadcastMessage<T> {
+    const ABBREVIATION: &'static str = "bcast msg.";
+}
+
+/// Counts, for every value, the distinct parties that voted for it. Only a party's first vote
+/// counts, so a party that votes for two values is counted for the first only.
+struct Tally {
+    /// The number of votes of each value, keyed by its encoding.
+    votes: HashMap<Vec<u8>, usize>,
+    /// The parties that have voted.
+    voters: HashSet<PartyId>,
+}
+
+impl Tally {
+    fn new() -> Self {
+        Self {
+            votes: HashMap::new(),
+            voters: HashSet::new(),
+        }
+    }
+
+    /// Records `party`'s vote for `value`, returning the value's vote count, or `None` if `party`
+    /// had voted already.
+    fn vote<T: Serialize>(&mut self, party: PartyId, value: &T) -> Option<usize> {
+        if !self.voters.insert(party) {
+            return None;
+        }
+        let encoded = postcard::to_allocvec(value).ok()?;
+        let count = self.votes.entry(encoded).or_insert(0);
+        *count += 1;
+        Some(*count)
+    }
+}
+
+/// A party's state in one broadcast, advanced by the messages it receives.
+pub(crate) trait Instance<T> {
+    /// Processes `message` from `from`, returning the messages to send to every party in reply.
+    fn on_message(
+        &mut self,
+        from: PartyId,
+        message: BroadcastMessage<T>,
+    ) -> Vec<BroadcastMessage<T>>;
+
+    /// The delivered value, if any.
+    fn output(&self) -> Option<&T>;
+
+    /// Whether the instance has delivered and heard every message it expects from the others.
+    fn complete(&self) -> bool;
+}
+
+/// Runs `instance` until it has delivered and received every message it expects, or until no
+/// message arrives for `timeout`, starting by sending `initial` to every party.
+///
+/// The local party's own messages are processed in place rather than sent to itself.
+///
+/// # Errors
+///
+/// Returns [`NetworkError::Timeout`] wrapped in [`Error::Network`] if the instance did not deliver
+/// before the messages stopped coming.
+async fn drive<T, E, I>(
+    env: &mut E,
+    mut instance: I,
+    timeout: Duration,
+    initial: Vec<BroadcastMessage<T>>,
+) -> Result<T, Error>
+where
+    T: Serialize + DeserializeOwned + Clone,
+    E: Environment,
+    I: Instance<T>,
+{
+    let me = env.network().local_party();
+    let others: Vec<PartyId> = env
+        .network()
+        .party_ids()
+        .into_iter()
+        .filter(|party| *party != me)
+        .collect();
+
+    let mut outbox = initial;
+    loop {
+        while !outbox.is_empty() {
+            for message in mem::take(&mut outbox) {
+                let mut pkt = Packet::empty();
+                pkt.write_labeled(&message)?;
+                let messages: Vec<_> = others.iter().map(|party| (*party, pkt.clone())).collect();
+                env.network_mut().send_many(&messages).await?;
+                outbox.extend(instance.on_message(me, message));
+            }
+        }
+        if instance.complete() {
+            break;
+        }
+        match env.network_mut().recv_any_with_timeout(timeout).await {
+            // A message that does not decode comes from a faulty party, and is dropped.
+            Ok((from, pkt)) => {
+                if let Ok(message) = pkt.read(0) {
+                    outbox = instance.on_message(from, message);
+                }
+            }
+            Err(NetworkError::Timeout(_)) => break,
+            Err(err) => return Err(err.into()),
+        }
+    }
+    instance
+        .output()
+        .cloned()
+        .ok_or(Error::Network(NetworkError::Timeout(None)))
+}
+
+/// Checks that `t` Byzantine parties among the local network's are within `t < n / 3`.
+pub(crate) fn check_threshold<E: Environment>(env: &E, threshold: usize) -> Result<usize, Error> {
+    let n = env.network().party_ids().len();
+    if 3 * threshold >= n {
+        return Err(Error::Input);
+    }
+    Ok(n)
+}
@@ -0,0 +1,499 @@
+//! Preprocessing from a **trusted dealer**, for any linear secret sharing scheme.
+//!
+//! Online-phase protocols consume correlated randomness — Beaver triples, random bits, random
+//! sharings, double sharings, input masks — that a real deployment produces in an interactive
+//! offline phase (for Shamir sharing, see [`crate::protocol::passive_shamir`]).
+//! [`TrustedDealer`](crate::protocol::dealer::TrustedDealer) replaces that phase with a single
+//! party that samples the material itself and sends every party its shares in one round, so
+//! online protocols can be developed, tested and benchmarked without paying for the offline phase
+//! in every run.
+//!
+//! The dealer is generic over [`Dealable`](crate::protocol::dealer::Dealable) schemes: every
+//! [`LinearShare`](crate::ss::LinearShare) scheme — Shamir, additive, XOR or authenticated shares
+//! — with its [`Threshold`](crate::ss::LinearShare::Threshold) as the dealing parameter, which for
+//! authenticated shares is the MAC key. The boolean triples of [`crate::protocol::gmw`] and the
+//! SPDZ preprocessing of [`crate::protocol::spdz`] are both dealt by it.
+//!
+//! # Trust model: trusted dealer
+//!
+//! The dealer knows every value it deals, and so learns every secret later masked with one. It
+//! must be trusted not to collude with any computing party. This is a stand-in for simulation and
+//! prototyping, not a deployment configuration.
+
+use std::collections::HashMap;
+
+use rand::CryptoRng;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::ring::Ring,
+    net::{Network, Packet, PartyId},
+    protocol::{Error, Protocol, ProtocolId, RandEnvironment},
+    ss::LinearShare,
+};
+
+/// A sharing scheme that a [`TrustedDealer`] can deal.
+///
+/// Every [`LinearShare`] scheme is dealable, with its [`Threshold`](LinearShare::Threshold) as the
+/// dealing parameter. For [`AuthenticatedSS`](crate::ss::authenticated::AuthenticatedSS) that is
+/// the [`MacKey`](crate::ss::authenticated::MacKey), which only the dealer holds whole.
+pub trait Dealable: Sized + Serialize + DeserializeOwned {
+    /// The secret domain.
+    type Value: Ring + Send + Sync + 'static;
+
+    /// What the dealer needs to deal a sharing, besides the parties.
+    type Params: Clone + Send + Sync;
+
+    /// Splits `secret` into one share per party of `parties`, the `i`-th share belonging to
+    /// `parties[i]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Share`] if the scheme cannot deal among `parties` with `params`.
+    fn deal<R: CryptoRng>(
+        secret: Self::Value,
+        parties: &[PartyId],
+        params: &Self::Params,
+        rng: &mut R,
+    ) -> Result<Vec<Self>, Error>;
+}
+
+impl<S> Dealable for S
+where
+    S: LinearShare,
+    S::Value: Send + Sync + 'static,
+{
+    type Value = S::Value;
+    type Params = S::Threshold;
+
+    fn deal<R: CryptoRng>(
+        secret: S::Value,
+        parties: &[PartyId],
+        threshold: &S::Threshold,
+        rng: &mut R,
+    ) -> Result<Vec<Self>, Error> {
+        Ok(S::shares_from_secret(
+            secret,
+            parties,
+            threshold.clone(),
+            rng,
+        )?)
+    }
+}
+
+/// A Beaver multiplication triple in a scheme `S`: sharings of random `a`, `b` and of `a · b`.
+pub struct BeaverTriple<S> {
+    a: S,
+    b: S,
+    mult: S,
+}
+
+impl<S> BeaverTriple<S> {
+    /// Assembles a triple from sharings of `a`, `b` and their product.
+    pub fn new(a: S, b: S, mult: S) -> Self {
+        Self { a, b, mult }
+    }
+
+    /// Returns the sharing of `a`.
+    pub fn a(&self) -> &S {
+        &self.a
+    }
+
+    /// Returns the sharing of `b`.
+    pub fn b(&self) -> &S {
+        &self.b
+    }
+
+    /// Returns the sharing of the product `a · b`.
+    pub fn mult(&self) -> &S {
+        &self.mult
+    }
+
+    /// Splits the triple into the sharings of `a`, `b` and `a · b`.
+    pub fn into_parts(self) -> (S, S, S) {
+        (self.a, self.b, self.mult)
+    }
+}
+
+/// An input mask: a sharing `S` of a random `r` in `V` that one party, its **owner**, knows in
+/// the clear.
+///
+/// The owner spends it to input one value `x`: it publishes `x - r`, and every party adds that to
+/// its share of `r` (see [`SpdzInput`](crate::protocol::spdz::input::SpdzInput)).
+pub struct InputMask<S, V> {
+    share: S,
+    value: Option<V>,
+}
+
+impl<S, V> InputMask<S, V> {
+    /// Assembles a mask from the local share of `r` and, on the owner only, `r` itself.
+    pub fn new(share: S, value: Option<V>) -> Self {
+        Self { share, value }
+    }
+
+    /// Returns the local share of the mask.
+    pub fn share(&self) -> &S {
+        &self.share
+    }
+
+    /// Returns the mask in the clear, if the local party owns it.
+    pub fn value(&self) -> Option<&V> {
+        self.value.as_ref()
+    }
+
+    /// Splits the mask into its share and, on the owner, its clear value.
+    pub fn into_parts(self) -> (S, Option<V>) {
+        (self.share, self.value)
+    }
+}
+
+/// How much material of each kind a [`TrustedDealer`] deals.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DealerRequest {
+    /// The number of Beaver triples.
+    pub triples: usize,
+    /// The number of sharings of random bits, sampled with [`Ring::random_bit`]: secrets that are
+    /// [`Ring::ZERO`] or [`Ring::ONE`] or, over a packed ring such as
+    /// [`Gf2Word`](crate::math::gf2::Gf2Word), words of independent random bits.
+    pub random_bits: usize,
+    /// The number of sharings of uniformly random secrets.
+    pub random_shares: usize,
+    /// The number of double sharings: pairs of sharings of the same random secret under two
+    /// thresholds.
+    pub double_shares: usize,
+    /// The number of input masks owned by each receiving party.
+    pub input_masks: usize,
+}
+
+/// One party's material dealt by a [`TrustedDealer`].
+///
+/// Each item is single-use: spending a triple, a random sharing or a mask twice breaks privacy.
+/// The `take_*` methods therefore hand items out by value and remove them from the store.
+pub struct DealtPreprocessing<S: Dealable> {
+    triples: Vec<BeaverTriple<S>>,
+    random_bits: Vec<S>,
+    random_shares: Vec<S>,
+    double_shares: Vec<(S, S)>,
+    input_masks: HashMap<PartyId, Vec<InputMask<S, S::Value>>>,
+}
+
+impl<S: Dealable> DealtPreprocessing<S> {
+    /// Returns an empty store.
+    fn empty() -> Self {
+        Self {
+            triples: Vec::new(),
+            random_bits: Vec::new(),
+            random_shares: Vec::new(),
+            double_shares: Vec::new(),
+            input_masks: HashMap::new(),
+        }
+    }
+
+    /// Returns how many triples, random bits, random sharings and double sharings are left, and
+    /// the fewest input masks left of any owner.
+    pub fn left(&self) -> DealerRequest {
+        DealerRequest {
+            triples: self.triples.len(),
+            random_bits: self.random_bits.len(),
+            random_shares: self.random_shares.len(),
+            double_shares: self.double_shares.len(),
+            input_masks: self.input_masks.values().map(Vec::len).min().unwrap_or(0),
+        }
+    }
+
+    /// Returns how many input masks of `owner` are left.
+    pub fn input_masks_left(&self, owner: PartyId) -> usize {
+        self.input_masks.get(&owner).map_or(0, Vec::len)
+    }
+
+    /// Removes and returns the next `count` triples.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if fewer than `count` triples are left.
+    pub fn take_triples(&mut self, count: usize) -> Result<Vec<BeaverTriple<S>>, Error> {
+        take(&mut self.triples, count)
+    }
+
+    /// Removes and returns the next `count` sharings of random bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if fewer than `count` random bits are left.
+    pub fn take_random_bits(&mut self, count: usize) -> Result<Vec<S>, Error> {
+        take(&mut self.random_bits, count)
+    }
+
+    /// Removes and returns the next `count` sharings of random secrets.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if fewer than `count` random sharings are left.
+    pub fn take_random_shares(&mut self, count: usize) -> Result<Vec<S>, Error> {
+        take(&mut self.random_shares, count)
+    }
+
+    /// Removes and returns the next `count` double sharings, each as the pair of sharings under
+    /// the first and the second threshold.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if fewer than `count` double sharings are left.
+    pub fn take_double_shares(&mut self, count: usize) -> Result<Vec<(S, S)>, Error> {
+        take(&mut self.double_shares, count)
+    }
+
+    /// Removes and returns the next `count` input masks of `owner`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if fewer than `count` masks of `owner` are left.
+    pub fn take_input_masks(
+        &mut self,
+        owner: PartyId,
+        count: usize,
+    ) -> Result<Vec<InputMask<S, S::Value>>, Error> {
+        match self.input_masks.get_mut(&owner) {
+            Some(masks) => take(masks, count),
+            None if count == 0 => Ok(Vec::new()),
+            None => Err(Error::Input),
+        }
+    }
+}
+
+/// Removes and returns the first `count` items of `items`, if there are that many.
+fn take<T>(items: &mut Vec<T>, count: usize) -> Result<Vec<T>, Error> {
+    if count > items.len() {
+        return Err(Error::Input);
+    }
+    Ok(items.drain(..count).collect())
+}
+
+/// Protocol in which a **trusted dealer** samples the material described by a [`DealerRequest`]
+/// and sends every party its shares of it, all in **one round**.
+///
+/// Construct the dealer's instance with [`dealer`](TrustedDealer::dealer) and every other party's
+/// with [`receiver`](TrustedDealer::receiver); all must agree on the request. The dealer may be one
+/// of the parties it deals to — it then outputs its own shares like everyone else — or a separate
+/// party, whose output is empty. Only the dealer needs the dealing parameters, which matters for
+/// authenticated shares, whose parameter is the MAC key.
+///
+/// Every receiving party owns [`input_masks`](DealerRequest::input_masks) masks: every party gets
+/// a share of each, and the owner also gets their clear values.
+///
+/// # Trust model: trusted dealer
+///
+/// See the [module documentation](self): the dealer must not collude with any computing party.
+pub struct TrustedDealer<S: Dealable> {
+    /// The party that samples and distributes the material.
+    dealer: PartyId,
+    /// How much material of each kind is dealt.
+    request: DealerRequest,
+    /// The dealer-only input; `None` on receivers.
+    dealer_info: Option<DealerInfo<S>>,
+}
+
+/// The input that only the dealer holds: whom to deal to, and the parameters to deal with.
+struct DealerInfo<S: Dealable> {
+    /// The parties that receive shares; `parties[i]` gets the `i`-th share of every sharing.
+    parties: Vec<PartyId>,
+    /// The parameter of triples, random bits, random sharings, input masks, and the first half of
+    /// double sharings.
+    threshold: S::Params,
+    /// The parameter of the second half of double sharings.
+    double_threshold: S::Params,
+}
+
+impl<S: Dealable> TrustedDealer<S> {
+    /// Creates the protocol instance for the **dealer** (`dealer`, which must be the local party),
+    /// which deals the material in `request` to `parties`.
+    ///
+    /// Everything is dealt under `threshold`, except the second half of each double sharing, which
+    /// is dealt under `double_threshold` — for Shamir, typically degrees `t` and `2t`.
+    pub fn dealer(
+        dealer: PartyId,
+        parties: Vec<PartyId>,
+        threshold: S::Params,
+        double_threshold: S::Params,
+        request: DealerRequest,
+    ) -> Self {
+        Self {
+            dealer,
+            request,
+            dealer_info: Some(DealerInfo {
+                parties,
+                threshold,
+                double_threshold,
+            }),
+        }
+    }
+
+    /// Creates the protocol instance for a party receiving the material in `request` from
+    /// `dealer`.
+    pub fn receiver(dealer: PartyId, request: DealerRequest) -> Self {
+        Self {
+            dealer,
+            request,
+            dealer_info: None,
+        }
+    }
+}
+
+impl<S, E> Protocol<E> for TrustedDealer<S>
+where
+    S: Dealable + Abbreviate + Send + Sync,
+    E: RandEnvironment,
+{
+    type Output = DealtPreprocessing<S>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let request = self.request;
+        let is_receiver = match self.dealer_info {
+            Some(info) if me == self.dealer => {
+                let is_receiver = info.parties.contains(&me);
+                deal(env, info, request).await?;
+                is_receiver
+            }
+            Some(_) => return Err(Error::Input),
+            None => true,
+        };
+        if !is_receiver {
+            return Ok(DealtPreprocessing::empty());
+        }
+
+        let pkt = env.network_mut().recv_from(self.dealer).await?;
+        let parties: Vec<PartyId> = pkt.read(0)?;
+        let mut offset = 1;
+        let a = read_items::<S>(&pkt, &mut offset, request.triples)?;
+        let b = read_items::<S>(&pkt, &mut offset, request.triples)?;
+        let mult = read_items::<S>(&pkt, &mut offset, request.triples)?;
+        let random_bits = read_items(&pkt, &mut offset, request.random_bits)?;
+        let random_shares = read_items(&pkt, &mut offset, request.random_shares)?;
+        let low = read_items::<S>(&pkt, &mut offset, request.double_shares)?;
+        let high = read_items::<S>(&pkt, &mut offset, request.double_shares)?;
+        let mut mask_shares = Vec::with_capacity(parties.len());
+        for _ in &parties {
+            mask_shares.push(read_items::<S>(&pkt, &mut offset, request.input_masks)?);
+        }
+        let mut own_masks = read_items::<S::Value>(&pkt, &mut offset, request.input_masks)?;
+
+        let input_masks = parties
+            .iter()
+            .zip(mask_shares)
+            .map(|(owner, shares)| {
+                let values: Vec<Option<S::Value>> = if *owner == me {
+                    std::mem::take(&mut own_masks)
+                        .into_iter()
+                        .map(Some)
+                        .collect()
+                } else {
+                    vec![None; shares.len()]
+                };
+                let masks = shares
+                    .into_iter()
+                    .zip(values)
+                    .map(|(share, value)| InputMask::new(share, value));
+                (*owner, masks.collect())
+            })
+            .collect();
+        Ok(DealtPreprocessing {
+            triples: a
+                .into_iter()
+                .zip(b)
+                .zip(mult)
+                .map(|((a, b), mult)| BeaverTriple::new(a, b, mult))
+                .collect(),
+            random_bits,
+            random_shares,
+            double_shares: low.into_iter().zip(high).collect(),
+            input_masks,
+        })
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("TrustedDealer")
+    }
+}
+
+/// Reads the `count` items of `pkt` starting at `offset`, and advances `offset` past them.
+fn read_items<T: DeserializeOwned>(
+    pkt: &Packet,
+    offset: &mut usize,
+    count: usize,
+) -> Result<Vec<T>, Error> {
+    let items = (*offset..*offset + count)
+        .map(|i| pkt.read(i))
+        .collect::<Result<_, _>>()?;
+    *offset += count;
+    Ok(items)
+}
+
+/// Samples the material in `request` and sends every party in `info.parties` its shares in a
+/// single packet, laid out as the list of receiving parties; the triples' `a`s, `b`s and
+/// products; random bits; random sharings; the first and then the second halves of the double
+/// sharings; the input masks of every owner in party order; and finally the clear values of the
+/// receiving party's own masks.
+async fn deal<S, E>(env: &mut E, info: DealerInfo<S>, request: DealerRequest) -> Result<(), Error>
+where
+    S: Dealable + Abbreviate,
+    E: RandEnvironment,
+{
+    let mut packets: Vec<Packet> = info.parties.iter().map(|_| Packet::empty()).collect();
+    for pkt in &mut packets {
+        pkt.write(&info.parties)?;
+    }
+    let mut deal_all = |env: &mut E, secrets: &[S::Value], params: &S::Params| {
+        for secret in secrets {
+            let shares = S::deal(*secret, &info.parties, params, env.rng_mut())?;
+            for (pkt, share) in packets.iter_mut().zip(&shares) {
+                pkt.write_labeled(share)?;
+            }
+        }
+        Ok::<_, Error>(())
+    };
+
+    let (a, b): (Vec<S::Value>, Vec<S::Value>) = (0..request.triples)
+        .map(|_| {
+            (
+                S::Value::random(env.rng_mut()),
+                S::Value::random(env.rng_mut()),
+            )
+        })
+        .unzip();
+    let mult: Vec<S::Value> = a.iter().zip(&b).map(|(a, b)| *a * b).collect();
+    for secrets in [&a, &b, &mult] {
+        deal_all(env, secrets, &info.threshold)?;
+    }
+    let bits: Vec<S::Value> = (0..request.random_bits)
+        .map(|_| S::Value::random_bit(env.rng_mut()))
+        .collect();
+    deal_all(env, &bits, &info.threshold)?;
+    let random: Vec<S::Value> = (0..request.random_shares)
+        .map(|_| S::Value::random(env.rng_mut()))
+        .collect();
+    deal_all(env, &random, &info.threshold)?;
+    let doubles: Vec<S::Value> = (0..request.double_shares)
+        .map(|_| S::Value::random(env.rng_mut()))
+        .collect();
+    deal_all(env, &doubles, &info.threshold)?;
+    deal_all(env, &doubles, &info.double_threshold)?;
+    let mut masks = Vec::with_capacity(info.parties.len());
+    for _ in &info.parties {
+        let owned: Vec<S::Value> = (0..request.input_masks)
+            .map(|_| S::Value::random(env.rng_mut()))
+            .collect();
+        deal_all(env, &owned, &info.threshold)?;
+        masks.push(owned);
+    }
+    for (pkt, owned) in packets.iter_mut().zip(&masks) {
+        for value in owned {
+            pkt.write(value)?;
+        }
+    }
+
+    let messages: Vec<(PartyId, Packet)> = info.parties.iter().copied().zip(packets).collect();
+    env.network_mut().send_many(&messages).await?;
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use crate::{
+    protocol::{
+        gmw::triple::BooleanTriple, share::open::BatchedPassiveOpenShr, Environment, Error,
+        Protocol, ProtocolId,
+    },
+    ss::xor::XorSS,
+};
+
+/// GMW AND: computes `[x ∧ y]` for a whole batch of shared word pairs in **one round**, spending
+/// one [`BooleanTriple`] per pair.
+///
+/// This is Beaver multiplication over GF(2). For each pair the parties open the masked words
+/// `d = x ⊕ a` and `e = y ⊕ b`, which reveal nothing since `a` and `b` are uniform, and compute
+///
+/// ```text
+/// [x ∧ y] = [c] ⊕ (d ∧ [b]) ⊕ (e ∧ [a]) ⊕ (d ∧ e)
+/// ```
+///
+/// locally; the public term `d ∧ e` is absorbed by the leader alone. All `d` and `e` words are
+/// opened together with [`BatchedPassiveOpenShr`], so a circuit should pass all the AND gates of
+/// one layer in one batch.
+///
+/// # Preconditions
+///
+/// Every party in the network must run this protocol, holding XOR shares among all of them and
+/// passing the pairs and their triples in the same order.
+///
+/// # Security model: passive adversary
+///
+/// The masked words are opened to every party, which is safe only if everyone follows the
+/// protocol; the triples themselves must come from a source trusted not to collude (see
+/// [`super::triple::TrustedBooleanTriples`]).
+pub struct GmwAnd {
+    x: Vec<XorSS>,
+    y: Vec<XorSS>,
+    triples: Vec<BooleanTriple>,
+}
+
+impl GmwAnd {
+    /// Creates the protocol for the local party, which computes `x[i] ∧ y[i]` for every `i` using
+    /// `triples[i]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `x`, `y` and `triples` differ in length.
+    pub fn new(x: Vec<XorSS>, y: Vec<XorSS>, triples: Vec<BooleanTriple>) -> Result<Self, Error> {
+        if x.len() != y.len() || x.len() != triples.len() {
+            return Err(Error::Input);
+        }
+        Ok(Self { x, y, triples })
+    }
+}
+
+impl<E: Environment> Protocol<E> for GmwAnd {
+    type Output = Vec<XorSS>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let n_gates = self.x.len();
+
+        // Mask every input: [d] = [x] ⊕ [a] and [e] = [y] ⊕ [b], all d's first, then all e's.
+        let mut masked = Vec::with_capacity(2 * n_gates);
+        for (x, triple) in self.x.into_iter().zip(&self.triples) {
+            masked.push(x + triple.a());
+        }
+        for (y, triple) in self.y.into_iter().zip(&self.triples) {
+            masked.push(y + triple.b());
+        }
+        let opened = BatchedPassiveOpenShr::new(masked).execute(env).await?;
+        let (d, e) = opened.split_at(n_gates);
+
+        Ok(self
+            .triples
+            .into_iter()
+            .zip(d.iter().zip(e))
+            .map(|(triple, (d, e))| {
+                let (a, b, c) = triple.into_parts();
+                c + &(b * d) + &(a * e) + &(*d * e)
+            })
+            .collect())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("GmwAnd")
+    }
+}
@@ -0,0 +1,25 @@
+//! Boolean circuits over XOR sharing, in the style of Goldreich, Micali and Wigderson, *How to Play
+//! any Mental Game* (STOC 1987) — "GMW".
+//!
+//! Wires carry [`XorSS`](crate::ss::xor::XorSS) shares of packed
+//! [`Gf2Word`](crate::math::gf2::Gf2Word)s, so every gate is evaluated on 64 independent circuit
+//! instances at once. XOR and NOT gates, and
+//! AND with a public mask, are local operations on the shares; only the AND of two shared wires
+//! needs interaction:
+//!
+//! - [`triple`](crate::protocol::gmw::triple) — boolean Beaver triples `([a], [b], [a ∧ b])`,
+//!   handed out by a trusted dealer.
+//! - [`and`](crate::protocol::gmw::and) — [`GmwAnd`](crate::protocol::gmw::and::GmwAnd), which
+//!   spends one triple per AND gate and evaluates a whole layer of gates in a single round, so a
+//!   circuit's round count tracks its AND depth.
+//!
+//! # Security model
+//!
+//! The online phase assumes a **passive (semi-honest) adversary** and opens values towards every
+//! party in the network. The triples come from a **trusted dealer** that knows them all; a dealer
+//! that also takes part in the computation is trusted not to collude.
+
+/// GMW AND gates by Beaver multiplication over XOR shares.
+pub mod and;
+/// Boolean Beaver triples from a trusted dealer.
+pub mod triple;
@@ -0,0 +1,81 @@
+use crate::{
+    net::{Network, PartyId},
+    protocol::{
+        dealer::{BeaverTriple, DealerRequest, TrustedDealer},
+        Error, Protocol, ProtocolId, RandEnvironment,
+    },
+    ss::xor::XorSS,
+};
+
+/// A boolean Beaver triple: XOR sharings of random words `a`, `b` and of their AND `a ∧ b`, which
+/// is their product in [`Gf2Word`](crate::math::gf2::Gf2Word).
+pub type BooleanTriple = BeaverTriple<XorSS>;
+
+/// Protocol in which a **trusted dealer** samples `count` boolean Beaver triples and hands every
+/// party its XOR shares of them, all in one round.
+///
+/// This is a [`TrustedDealer`] over [`XorSS`] that deals only triples: the dealer samples uniform
+/// words `a` and `b`, computes `c = a ∧ b`, and XOR-shares all three among `parties`. Each party
+/// receives all of its triple shares in a single packet. The dealer must be one of `parties`:
+/// [`GmwAnd`](super::and::GmwAnd) opens its masked values among every party in the network, so a
+/// party holding no triple shares would stall it.
+///
+/// # Trust model: trusted dealer
+///
+/// The dealer knows every triple, and so learns every value masked with one. It must be trusted
+/// not to collude with any computing party; replacing it with an interactive triple generation is
+/// the usual way to remove the assumption.
+pub struct TrustedBooleanTriples {
+    /// The party that samples and distributes the triples.
+    dealer: PartyId,
+    /// The parties receiving shares of the triples.
+    parties: Vec<PartyId>,
+    /// The number of triples to deal.
+    count: usize,
+}
+
+impl TrustedBooleanTriples {
+    /// Creates the protocol for the local party. Every party in `parties`, and the `dealer`, must
+    /// run it with the same arguments; each party in `parties` outputs its shares of `count`
+    /// triples.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `dealer` is not one of `parties`.
+    pub fn new(dealer: PartyId, parties: Vec<PartyId>, count: usize) -> Result<Self, Error> {
+        if !parties.contains(&dealer) {
+            return Err(Error::Input);
+        }
+        Ok(Self {
+            dealer,
+            parties,
+            count,
+        })
+    }
+}
+
+impl<E: RandEnvironment> Protocol<E> for TrustedBooleanTriples {
+    type Output = Vec<BooleanTriple>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        if !self.parties.contains(&me) {
+            return Err(Error::Input);
+        }
+
+        let request = DealerRequest {
+            triples: self.count,
+            ..DealerRequest::default()
+        };
+        let dealer = if me == self.dealer {
+            TrustedDealer::dealer(self.dealer, self.parties, (), (), request)
+        } else {
+            TrustedDealer::receiver(self.dealer, request)
+        };
+        dealer.execute(env).await?.take_triples(self.count)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("TrustedBooleanTriples")
+    }
+}
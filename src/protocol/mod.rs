@@ -3,8 +3,37 @@ pub mod share;
 
 pub mod passive_shamir;
 
+/// GMW-style boolean circuits over XOR sharing.
+pub mod gmw;
+
+/// Trusted-dealer preprocessing for any [`LinearShare`](crate::ss::LinearShare) scheme.
+pub mod dealer;
+
+/// SPDZ-style actively secure computation over authenticated additive shares.
+pub mod spdz;
+
+/// Threshold cryptography over elliptic curves: distributed key generation, signing and
+/// decryption.
+pub mod threshold;
+
+/// Public and shared randomness: coin tossing and pseudo-random secret sharing.
+pub mod randomness;
+
+/// Byzantine-tolerant broadcast on `recv_any`: echo broadcast and Bracha's reliable broadcast.
+pub mod broadcast;
+
+/// Synchronous Byzantine agreement on round timers: phase-king binary agreement and its
+/// multivalued extension.
+pub mod agreement;
+
+/// Two-party oblivious transfer.
+pub mod ot;
+
+/// Two-party computation of boolean circuits with Yao's garbled circuits.
+pub mod yao;
+
 use crate::{
-    net::{Network, NetworkError},
+    net::{Network, NetworkError, PartyId},
     prelude::Ring,
     ss::ShareError,
 };
@@ -33,6 +62,15 @@ pub enum Error {
     /// without providing a secret.
     #[error("the input is not well formed for the current protocol")]
     Input,
+    /// A MAC check failed: some value opened earlier in the session does not match its MAC, so at
+    /// least one party tampered with a share. The check detects the tampering but cannot tell who
+    /// did it, so the only safe reaction is to abort the session.
+    #[error("MAC check failed: an opened value was tampered with")]
+    MacCheck,
+    /// The given party provably deviated from the protocol — for example by opening a commitment
+    /// to a different value than it committed to.
+    #[error("party {0:?} deviated from the protocol")]
+    Cheater(PartyId),
 }
 
 impl<T> From<ShareError<T>> for Error
@@ -196,3 +234,26 @@ impl<R: CryptoRng + Send, N: Network> RandEnvironment for GeneralEnv<N, R> {
         &mut self.rng
     }
 }
+
+/// A borrowed environment is an environment too, so that a protocol can wrap the environment it
+/// was handed — e.g. in a [`PrssEnv`](crate::protocol::randomness::prss::PrssEnv) — for the
+/// sub-protocols it runs.
+impl<E: Environment + ?Sized> Environment for &mut E {
+    type Net = E::Net;
+
+    fn network(&self) -> &Self::Net {
+        (**self).network()
+    }
+
+    fn network_mut(&mut self) -> &mut Self::Net {
+        (**self).network_mut()
+    }
+}
+
+impl<E: RandEnvironment + ?Sized> RandEnvironment for &mut E {
+    type Rng = E::Rng;
+
+    fn rng_mut(&mut self) -> &mut Self::Rng {
+        (**self).rng_mut()
+    }
+}
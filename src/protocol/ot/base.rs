@@ -0,0 +1,165 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{
+        ec::{secp256k1::Secp256k1, EllipticCurve},
+        field::secp256k1_scalar::Secp256k1ScalarField,
+        ring::Ring,
+    },
+    net::{Network, Packet, PartyId},
+    protocol::{
+        ot::{xor, OtBlock},
+        Error, Protocol, ProtocolId, RandEnvironment,
+    },
+};
+
+/// A batch of 1-out-of-2 oblivious transfers, following Chou and Orlandi (*The Simplest Protocol
+/// for Oblivious Transfer*, LATINCRYPT 2015).
+///
+/// 1. The sender samples `a` and sends `A = a · G`, once for the whole batch.
+/// 2. For the `i`-th transfer, the receiver samples `b_i` and sends `B_i = b_i · G` to choose the
+///    first message, or `B_i = A + b_i · G` to choose the second; either way `B_i` is a uniformly
+///    random point, so the sender learns nothing about the choice.
+/// 3. The sender derives the keys `k_0 = H(a · B_i)` and `k_1 = H(a · (B_i − A))`, and sends both
+///    messages, each XORed with its key. The receiver can compute only the key of the message it
+///    chose, `H(b_i · A)`; the other would take `a · b_i · G`, a Diffie–Hellman secret.
+///
+/// `H` is SHA-256 over a domain tag, `A`, `B_i`, the index `i` and the shared point, so keys are
+/// bound to their transfer. The whole batch takes three messages, whatever its size.
+///
+/// Construct the sender's instance with [`sender`](BaseOt::sender) and the receiver's with
+/// [`receiver`](BaseOt::receiver); both must be for the same number of transfers. The sender's
+/// output is `None`, and the receiver's the chosen messages.
+///
+/// # Security model: passive adversary
+///
+/// Both parties follow the protocol. The receiver's choices are hidden from any sender, but
+/// against an actively corrupted receiver the protocol is only secure in the weaker sense analyzed
+/// by Chou and Orlandi; it is meant to seed an OT extension, not as a standalone malicious-model
+/// primitive. Malformed messages and points off the curve are rejected with [`Error::Cheater`]:
+/// decoding `A` or a `B_i` already fails for a point off the curve.
+pub struct BaseOt {
+    role: Role,
+}
+
+/// The input of each side of a [`BaseOt`].
+enum Role {
+    /// The sender's message pairs, one per transfer.
+    Sender(Vec<(OtBlock, OtBlock)>),
+    /// The receiver's choices, one per transfer: `false` for the first message, `true` for the
+    /// second.
+    Receiver(Vec<bool>),
+}
+
+impl BaseOt {
+    /// Creates the protocol instance for the **sender** of the message pairs `messages`.
+    pub fn sender(messages: Vec<(OtBlock, OtBlock)>) -> Self {
+        Self {
+            role: Role::Sender(messages),
+        }
+    }
+
+    /// Creates the protocol instance for the **receiver**, which learns the second message of
+    /// every transfer whose choice is `true` and the first of the others.
+    pub fn receiver(choices: Vec<bool>) -> Self {
+        Self {
+            role: Role::Receiver(choices),
+        }
+    }
+}
+
+/// Both messages of one transfer, each XORed with its key.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+struct EncryptedPair(OtBlock, OtBlock);
+
+impl Abbreviate for EncryptedPair {
+    const ABBREVIATION: &'static str = "OT ctxt.";
+}
+
+impl<E: RandEnvironment> Protocol<E> for BaseOt {
+    /// `None` on the sender; the chosen messages on the receiver.
+    type Output = Option<Vec<OtBlock>>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let other = env.network().other()?;
+        match self.role {
+            Role::Sender(messages) => {
+                let a = Secp256k1ScalarField::random_non_zero(env.rng_mut());
+                let big_a = Secp256k1::gen().scalar_mul(&a);
+                let mut pkt = Packet::empty();
+                pkt.write_labeled(&big_a)?;
+                env.network_mut().send_to(other, &pkt).await?;
+
+                let pkt = env.network_mut().recv_from(other).await?;
+                let a_times_a = big_a.scalar_mul(&a);
+                let mut pairs = Vec::with_capacity(messages.len());
+                for (i, (m0, m1)) in messages.iter().enumerate() {
+                    let big_b = read_point(&pkt, i, other)?;
+                    let shared = big_b.scalar_mul(&a);
+                    let k0 = key(&big_a, &big_b, i, &shared);
+                    let k1 = key(&big_a, &big_b, i, &shared.sub(&a_times_a));
+                    pairs.push(EncryptedPair(xor(m0, &k0), xor(m1, &k1)));
+                }
+                let mut pkt = Packet::empty();
+                pkt.write_many_labeled(&pairs)?;
+                env.network_mut().send_to(other, &pkt).await?;
+                Ok(None)
+            }
+            Role::Receiver(choices) => {
+                let pkt = env.network_mut().recv_from(other).await?;
+                let big_a = read_point(&pkt, 0, other)?;
+
+                let mut points = Vec::with_capacity(choices.len());
+                let mut keys = Vec::with_capacity(choices.len());
+                for (i, choice) in choices.iter().enumerate() {
+                    let b = Secp256k1ScalarField::random_non_zero(env.rng_mut());
+                    let b_times_g = Secp256k1::gen().scalar_mul(&b);
+                    let big_b = if *choice {
+                        big_a.add(&b_times_g)
+                    } else {
+                        b_times_g
+                    };
+                    keys.push(key(&big_a, &big_b, i, &big_a.scalar_mul(&b)));
+                    points.push(big_b);
+                }
+                let mut pkt = Packet::empty();
+                pkt.write_many_labeled(&points)?;
+                env.network_mut().send_to(other, &pkt).await?;
+
+                let pkt = env.network_mut().recv_from(other).await?;
+                let mut chosen = Vec::with_capacity(choices.len());
+                for (i, (choice, key)) in choices.iter().zip(&keys).enumerate() {
+                    let pair: EncryptedPair = pkt.read(i).map_err(|_| Error::Cheater(other))?;
+                    let encrypted = if *choice { pair.1 } else { pair.0 };
+                    chosen.push(xor(&encrypted, key));
+                }
+                Ok(Some(chosen))
+            }
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("BaseOt")
+    }
+}
+
+/// Reads the point at `index` of a packet from `from`, which cheated if it is malformed or off the
+/// curve. Deserializing a [`Secp256k1`] point checks the curve equation, so the decoding error
+/// covers both.
+fn read_point(pkt: &Packet, index: usize, from: PartyId) -> Result<Secp256k1, Error> {
+    pkt.read(index).map_err(|_| Error::Cheater(from))
+}
+
+/// Derives the key of the `index`-th transfer from the sender's point `a`, the receiver's point
+/// `b` and their shared point.
+fn key(a: &Secp256k1, b: &Secp256k1, index: usize, shared: &Secp256k1) -> OtBlock {
+    let mut hasher = Sha256::new();
+    hasher.update(b"scl-rs/base-ot");
+    hasher.update(a.to_compressed());
+    hasher.update(b.to_compressed());
+    hasher.update((index as u64).to_le_bytes());
+    hasher.update(shared.to_compressed());
+    hasher.finalize().into()
+}
@@ -0,0 +1,361 @@
+use rand::{Rng, RngExt, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    abbreviate::Abbreviate,
+    net::{Network, Packet},
+    protocol::{
+        ot::{base::BaseOt, OtBlock},
+        Error, Protocol, ProtocolId, RandEnvironment,
+    },
+};
+
+/// The number `κ` of base OTs, and the bit length of the correlation `Δ`.
+pub const BASE_OTS: usize = 128;
+
+/// The number of extra OTs the KOS check consumes: `κ` plus a statistical security parameter of
+/// 64.
+const KOS_PADDING: usize = BASE_OTS + 64;
+
+/// OT extension, following Ishai, Kilian, Nissim and Petrank (*Extending Oblivious Transfers
+/// Efficiently*, CRYPTO 2003): [`BASE_OTS`] base OTs turn into any number of OTs at the cost of
+/// symmetric operations only.
+///
+/// 1. **Base OTs, roles reversed.** The extension receiver sends `κ` pairs of random seeds
+///    `(k_0^j, k_1^j)` with [`BaseOt`]; the extension sender samples its correlation
+///    `Δ ∈ {0,1}^κ` and learns `k_{Δ_j}^j`.
+/// 2. **Extension.** For `m` OTs with choices `r ∈ {0,1}^m`, the receiver expands every seed into
+///    `m` bits with a PRG, sets `t^j = G(k_0^j)`, and sends `u^j = t^j ⊕ G(k_1^j) ⊕ r`. The
+///    sender computes `q^j = G(k_{Δ_j}^j) ⊕ Δ_j · u^j = t^j ⊕ Δ_j · r`.
+/// 3. **Transpose.** Read by rows, the matrices give every OT `i` a **correlated OT**: the
+///    sender holds `q_i` and the receiver `t_i = q_i ⊕ r_i · Δ`. Hashing them with a
+///    correlation-robust hash `H` gives a **random OT**: the sender holds
+///    `(H(i, q_i), H(i, q_i ⊕ Δ))`, and the receiver `H(i, t_i)`, the one it chose.
+///
+/// `G` is ChaCha20 keyed with the seed, and `H` SHA-256 over a domain tag, the index and the row.
+/// After the base OTs, the extension is one message from the receiver, `κ` bits per OT.
+///
+/// With [`with_kos_check`](IknpOtExtension::with_kos_check), the parties also run the consistency
+/// check of Keller, Orsini and Scholl (*Actively Secure OT Extension with Optimal Overhead*,
+/// CRYPTO 2015): the sender picks random `χ_i ∈ GF(2^128)` after seeing the `u^j`, the receiver
+/// answers `x = Σ χ_i · r_i` and `t = Σ χ_i · t_i`, and the sender checks `Σ χ_i · q_i = t ⊕ x · Δ`.
+/// A receiver that used different choices in different columns fails the check; `κ + 64` extra
+/// OTs, with random choices, keep the answer from leaking the real choices.
+///
+/// Construct the sender's instance with [`sender`](IknpOtExtension::sender) and the receiver's
+/// with [`receiver`](IknpOtExtension::receiver); both must be for the same number of OTs, and
+/// agree on the check.
+///
+/// # Security model
+///
+/// Without the check, both parties follow the protocol (**passive adversary**). With it, a
+/// malicious receiver is caught with [`Error::Cheater`] rather than learning bits of `Δ`, as long
+/// as the base OTs are secure against it.
+pub struct IknpOtExtension {
+    role: Role,
+    /// The number of OTs to extend to.
+    count: usize,
+    /// Whether to run the KOS consistency check.
+    kos_check: bool,
+}
+
+/// The input of each side of an [`IknpOtExtension`].
+enum Role {
+    Sender,
+    /// The receiver's choices, one per OT.
+    Receiver(Vec<bool>),
+}
+
+impl IknpOtExtension {
+    /// Creates the protocol instance for the **sender** of `count` OTs.
+    pub fn sender(count: usize) -> Self {
+        Self {
+            role: Role::Sender,
+            count,
+            kos_check: false,
+        }
+    }
+
+    /// Creates the protocol instance for the **receiver**, with one OT per choice in `choices`.
+    pub fn receiver(choices: Vec<bool>) -> Self {
+        Self {
+            count: choices.len(),
+            role: Role::Receiver(choices),
+            kos_check: false,
+        }
+    }
+
+    /// Enables the KOS consistency check, which catches a receiver whose choices are not
+    /// consistent across the base OTs.
+    pub fn with_kos_check(mut self) -> Self {
+        self.kos_check = true;
+        self
+    }
+}
+
+/// The sender's side of a batch of extended OTs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtendedOtSender {
+    /// The correlation `Δ`.
+    delta: u128,
+    /// The row `q_i` of every OT.
+    rows: Vec<u128>,
+}
+
+impl ExtendedOtSender {
+    /// Returns the correlation `Δ`.
+    pub fn delta(&self) -> u128 {
+        self.delta
+    }
+
+    /// Returns the correlated OTs: the sender's `q_i`, for every OT `i`.
+    pub fn correlated(&self) -> &[u128] {
+        &self.rows
+    }
+
+    /// Returns the random OTs: the message pair `(H(i, q_i), H(i, q_i ⊕ Δ))` of every OT `i`.
+    pub fn random(&self) -> Vec<(OtBlock, OtBlock)> {
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| (hash(i, *row), hash(i, row ^ self.delta)))
+            .collect()
+    }
+}
+
+/// The receiver's side of a batch of extended OTs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExtendedOtReceiver {
+    /// The choice `r_i` of every OT.
+    choices: Vec<bool>,
+    /// The row `t_i` of every OT.
+    rows: Vec<u128>,
+}
+
+impl ExtendedOtReceiver {
+    /// Returns the choices, one per OT.
+    pub fn choices(&self) -> &[bool] {
+        &self.choices
+    }
+
+    /// Returns the correlated OTs: the receiver's `t_i = q_i ⊕ r_i · Δ`, for every OT `i`.
+    pub fn correlated(&self) -> &[u128] {
+        &self.rows
+    }
+
+    /// Returns the random OTs: the chosen message `H(i, t_i)` of every OT `i`.
+    pub fn random(&self) -> Vec<OtBlock> {
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| hash(i, *row))
+            .collect()
+    }
+}
+
+/// The output of an [`IknpOtExtension`]: each party's side of the extended OTs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExtendedOts {
+    /// The sender's side.
+    Sender(ExtendedOtSender),
+    /// The receiver's side.
+    Receiver(ExtendedOtReceiver),
+}
+
+/// The receiver's masked column `u^j`, packed eight bits to a byte.
+#[derive(Serialize, Deserialize)]
+struct MaskedColumn(Vec<u8>);
+
+impl Abbreviate for MaskedColumn {
+    const ABBREVIATION: &'static str = "IKNP col.";
+}
+
+/// The seed of the KOS challenges `χ_i`.
+#[derive(Serialize, Deserialize)]
+struct KosChallenge([u8; 32]);
+
+impl Abbreviate for KosChallenge {
+    const ABBREVIATION: &'static str = "KOS chal.";
+}
+
+/// The receiver's answer `(x, t)` to the KOS challenge.
+#[derive(Serialize, Deserialize)]
+struct KosResponse {
+    x: u128,
+    t: u128,
+}
+
+impl Abbreviate for KosResponse {
+    const ABBREVIATION: &'static str = "KOS resp.";
+}
+
+impl<E: RandEnvironment> Protocol<E> for IknpOtExtension {
+    type Output = ExtendedOts;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let other = env.network().other()?;
+        let total = self.count + if self.kos_check { KOS_PADDING } else { 0 };
+        let bytes = total.div_ceil(8);
+        match self.role {
+            Role::Sender => {
+                let delta: u128 = env.rng_mut().random();
+                let choices = (0..BASE_OTS).map(|j| delta >> j & 1 == 1).collect();
+                let seeds = BaseOt::receiver(choices)
+                    .execute(env)
+                    .await?
+                    .ok_or(Error::Input)?;
+
+                let pkt = env.network_mut().recv_from(other).await?;
+                let mut columns = Vec::with_capacity(BASE_OTS);
+                for (j, seed) in seeds.iter().enumerate() {
+                    let masked = match pkt.read::<MaskedColumn>(j) {
+                        Ok(MaskedColumn(masked)) if masked.len() == bytes => masked,
+                        _ => return Err(Error::Cheater(other)),
+                    };
+                    let mut column = expand(seed, bytes);
+                    if delta >> j & 1 == 1 {
+                        xor_into(&mut column, &masked);
+                    }
+                    columns.push(column);
+                }
+                let mut rows = transpose(&columns, total);
+
+                if self.kos_check {
+                    let mut seed = [0; 32];
+                    env.rng_mut().fill_bytes(&mut seed);
+                    let mut pkt = Packet::empty();
+                    pkt.write_labeled(&KosChallenge(seed))?;
+                    env.network_mut().send_to(other, &pkt).await?;
+
+                    let pkt = env.network_mut().recv_from(other).await?;
+                    let response: KosResponse = pkt.read(0).map_err(|_| Error::Cheater(other))?;
+                    let q = combine(&seed, &rows);
+                    if q != response.t ^ gf128_mul(response.x, delta) {
+                        return Err(Error::Cheater(other));
+                    }
+                }
+                rows.truncate(self.count);
+                Ok(ExtendedOts::Sender(ExtendedOtSender { delta, rows }))
+            }
+            Role::Receiver(mut choices) => {
+                choices.extend((self.count..total).map(|_| env.rng_mut().random::<bool>()));
+                let packed = pack(&choices, bytes);
+                let mut seeds = Vec::with_capacity(BASE_OTS);
+                for _ in 0..BASE_OTS {
+                    let (mut k0, mut k1) = ([0; 32], [0; 32]);
+                    env.rng_mut().fill_bytes(&mut k0);
+                    env.rng_mut().fill_bytes(&mut k1);
+                    seeds.push((k0, k1));
+                }
+                BaseOt::sender(seeds.clone()).execute(env).await?;
+
+                let mut columns = Vec::with_capacity(BASE_OTS);
+                let mut masked = Vec::with_capacity(BASE_OTS);
+                for (k0, k1) in &seeds {
+                    let column = expand(k0, bytes);
+                    let mut u = expand(k1, bytes);
+                    xor_into(&mut u, &column);
+                    xor_into(&mut u, &packed);
+                    columns.push(column);
+                    masked.push(MaskedColumn(u));
+                }
+                let mut pkt = Packet::empty();
+                pkt.write_many_labeled(&masked)?;
+                env.network_mut().send_to(other, &pkt).await?;
+                let mut rows = transpose(&columns, total);
+
+                if self.kos_check {
+                    let pkt = env.network_mut().recv_from(other).await?;
+                    let KosChallenge(seed) = pkt.read(0).map_err(|_| Error::Cheater(other))?;
+                    let x = combine(
+                        &seed,
+                        &choices.iter().map(|c| u128::from(*c)).collect::<Vec<_>>(),
+                    );
+                    let t = combine(&seed, &rows);
+                    let mut pkt = Packet::empty();
+                    pkt.write_labeled(&KosResponse { x, t })?;
+                    env.network_mut().send_to(other, &pkt).await?;
+                }
+                rows.truncate(self.count);
+                choices.truncate(self.count);
+                Ok(ExtendedOts::Receiver(ExtendedOtReceiver { choices, rows }))
+            }
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("IknpOtExtension")
+    }
+}
+
+/// Expands `seed` into `bytes` pseudo-random bytes.
+fn expand(seed: &OtBlock, bytes: usize) -> Vec<u8> {
+    let mut out = vec![0; bytes];
+    ChaCha20Rng::from_seed(*seed).fill_bytes(&mut out);
+    out
+}
+
+/// XORs `other` into `column`.
+fn xor_into(column: &mut [u8], other: &[u8]) {
+    for (a, b) in column.iter_mut().zip(other) {
+        *a ^= b;
+    }
+}
+
+/// Packs `bits` into `bytes` bytes, least significant bit first.
+fn pack(bits: &[bool], bytes: usize) -> Vec<u8> {
+    let mut packed = vec![0; bytes];
+    for (i, bit) in bits.iter().enumerate() {
+        packed[i / 8] |= u8::from(*bit) << (i % 8);
+    }
+    packed
+}
+
+/// Transposes [`BASE_OTS`] packed columns of `count` bits into `count` rows of [`BASE_OTS`] bits:
+/// bit `j` of row `i` is bit `i` of column `j`.
+fn transpose(columns: &[Vec<u8>], count: usize) -> Vec<u128> {
+    let mut rows = vec![0u128; count];
+    for (j, column) in columns.iter().enumerate() {
+        for (i, row) in rows.iter_mut().enumerate() {
+            *row |= u128::from(column[i / 8] >> (i % 8) & 1) << j;
+        }
+    }
+    rows
+}
+
+/// Returns `Σ χ_i · values_i` in GF(2^128), with the challenges `χ_i` expanded from `seed`.
+fn combine(seed: &[u8; 32], values: &[u128]) -> u128 {
+    let mut challenges = ChaCha20Rng::from_seed(*seed);
+    values.iter().fold(0, |sum, value| {
+        sum ^ gf128_mul(challenges.random::<u128>(), *value)
+    })
+}
+
+/// Multiplies in GF(2^128), represented modulo `x^128 + x^7 + x^2 + x + 1` with bit `k` the
+/// coefficient of `x^k`.
+fn gf128_mul(mut a: u128, mut b: u128) -> u128 {
+    let mut product = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            product ^= a;
+        }
+        b >>= 1;
+        let carry = a >> 127;
+        a <<= 1;
+        if carry == 1 {
+            a ^= 0x87;
+        }
+    }
+    product
+}
+
+/// The correlation-robust hash of the row of the `index`-th OT.
+fn hash(index: usize, row: u128) -> OtBlock {
+    let mut hasher = Sha256::new();
+    hasher.update(b"scl-rs/iknp");
+    hasher.update((index as u64).to_le_bytes());
+    hasher.update(row.to_le_bytes());
+    hasher.finalize().into()
+}
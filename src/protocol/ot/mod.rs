@@ -0,0 +1,35 @@
+//! Oblivious transfer between two parties.
+//!
+//! In a 1-out-of-2 oblivious transfer the **sender** holds two messages and the **receiver** a
+//! choice bit; the receiver learns the message it chose and nothing about the other, and the
+//! sender learns nothing about the choice. OT is complete for two-party computation, and is what
+//! replaces a trusted dealer of correlated randomness between two parties.
+//!
+//! - [`base`](crate::protocol::ot::base) — [`BaseOt`](crate::protocol::ot::base::BaseOt): a batch
+//!   of OTs from public-key operations on secp256k1, following Chou and Orlandi's *Simplest OT*.
+//! - [`extension`](crate::protocol::ot::extension) —
+//!   [`IknpOtExtension`](crate::protocol::ot::extension::IknpOtExtension): IKNP OT extension from
+//!   128 base OTs to any number of random or correlated OTs, with the KOS consistency check as an
+//!   option.
+//! - [`triple`](crate::protocol::ot::triple) —
+//!   [`GilboaTriple`](crate::protocol::ot::triple::GilboaTriple): two-party Beaver triples over
+//!   [`AdditiveSS`](crate::ss::additive::AdditiveSS), from Gilboa's OT-based multiplication, with
+//!   no dealer.
+//!
+//! Messages are 32-byte [`OtBlock`](crate::protocol::ot::OtBlock)s: long enough to carry the key
+//! or seed of a symmetric primitive, which is how longer messages are transferred.
+
+/// Base oblivious transfer from the Diffie–Hellman problem on secp256k1.
+pub mod base;
+/// OT extension following Ishai, Kilian, Nissim and Petrank, with the optional KOS check.
+pub mod extension;
+/// Two-party Beaver triples over additive shares from Gilboa's OT-based multiplication.
+pub mod triple;
+
+/// A message of an oblivious transfer.
+pub type OtBlock = [u8; 32];
+
+/// Returns `a ⊕ b`.
+fn xor(a: &OtBlock, b: &OtBlock) -> OtBlock {
+    std::array::from_fn(|i| a[i] ^ b[i])
+}
@@ -0,0 +1,198 @@
+use std::marker::PhantomData;
+
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{field::FiniteField, ring::Ring},
+    net::{Network, Packet, PartyId},
+    protocol::{
+        dealer::BeaverTriple,
+        ot::{
+            extension::{ExtendedOtReceiver, ExtendedOtSender, ExtendedOts, IknpOtExtension},
+            OtBlock,
+        },
+        Error, Protocol, ProtocolId, RandEnvironment,
+    },
+    ss::additive::AdditiveSS,
+};
+
+/// The number of bits a `b` share has on top of the field's bit size, so that it is within
+/// statistical distance `2^-64` of uniform.
+const STATISTICAL_BITS: usize = 64;
+
+/// Two-party Beaver triple generation over additive shares, from oblivious linear evaluation
+/// (OLE) built on OT as proposed by Gilboa (*Two Party RSA Key Generation*, CRYPTO 1999).
+///
+/// Every party samples its shares `a_p` and `b_p` of a triple, the latter as `Σ_k r_k · 2^k` from
+/// `ℓ = BIT_SIZE + 64` random bits `r_k`. The cross terms of
+/// `a · b = a_0 b_0 + a_1 b_1 + a_0 b_1 + a_1 b_0` are then shared by one OLE each:
+///
+/// - the party holding `a_p` is the sender of `ℓ` OTs, the other the receiver with the bits of
+///   its `b` share as choices;
+/// - for the `k`-th OT, the sender turns its random OT pair into field elements `(x_0, x_1)` and
+///   sends the correction `x_0 + a_p · 2^k − x_1`, so the receiver ends with
+///   `y_k = x_0 + r_k · a_p · 2^k`;
+/// - the sender's share of the cross term is `−Σ x_0`, and the receiver's `Σ y_k`.
+///
+/// The OTs come from one [`IknpOtExtension`] per direction, of `ℓ` OTs per triple; the
+/// corrections of both directions then cross in one round. The lower party ID is the
+/// [leader](AdditiveSS) of the output shares.
+///
+/// # Preconditions
+///
+/// The network has exactly two parties, both running this protocol with the same `count`.
+///
+/// # Security model: passive adversary
+///
+/// Both parties follow the protocol; neither learns anything about the other's shares. No dealer
+/// is involved, so the triples suit dishonest-majority two-party prototypes, but an actively
+/// corrupted party can make the products wrong undetected.
+pub struct GilboaTriple<const LIMBS: usize, F> {
+    /// The number of triples to generate.
+    count: usize,
+    _field: PhantomData<F>,
+}
+
+impl<const LIMBS: usize, F> GilboaTriple<LIMBS, F> {
+    /// Creates the protocol generating `count` triples.
+    pub fn new(count: usize) -> Self {
+        Self {
+            count,
+            _field: PhantomData,
+        }
+    }
+}
+
+impl<const LIMBS: usize, F, E> Protocol<E> for GilboaTriple<LIMBS, F>
+where
+    E: RandEnvironment,
+    F: FiniteField<LIMBS> + Send + Sync + Abbreviate + 'static,
+{
+    type Output = Vec<BeaverTriple<AdditiveSS<F>>>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let other = env.network().other()?;
+        let bits = F::BIT_SIZE + STATISTICAL_BITS;
+        let powers: Vec<F> = std::iter::successors(Some(F::ONE), |power| Some(*power + power))
+            .take(bits)
+            .collect();
+
+        let a: Vec<F> = (0..self.count).map(|_| F::random(env.rng_mut())).collect();
+        let choices: Vec<bool> = (0..self.count * bits)
+            .map(|_| env.rng_mut().random())
+            .collect();
+        let b: Vec<F> = choices
+            .chunks(bits)
+            .map(|chunk| compose(chunk, &powers))
+            .collect();
+
+        // One extension per direction, the lower party sending first.
+        let mut sent = None;
+        let mut received = None;
+        for sender in sorted([me, other]) {
+            let extension = if sender == me {
+                IknpOtExtension::sender(self.count * bits)
+            } else {
+                IknpOtExtension::receiver(choices.clone())
+            };
+            match extension.execute(env).await? {
+                ExtendedOts::Sender(ots) => sent = Some(ots),
+                ExtendedOts::Receiver(ots) => received = Some(ots),
+            }
+        }
+        let (sent, received) = sent.zip(received).ok_or(Error::Input)?;
+
+        let (corrections, sent_terms) = corrections(&sent, &a, &powers);
+        let mut pkt = Packet::empty();
+        pkt.write_many_labeled(&corrections)?;
+        env.network_mut().send_to(other, &pkt).await?;
+        let pkt = env.network_mut().recv_from(other).await?;
+        let received_terms = received_terms(&received, &pkt, other, bits)?;
+
+        let is_leader = me < other;
+        Ok(a.into_iter()
+            .zip(b)
+            .zip(sent_terms.into_iter().zip(received_terms))
+            .map(|((a, b), (sent, received))| {
+                let c = a * &b + &sent + &received;
+                BeaverTriple::new(
+                    AdditiveSS::new(a, me, is_leader),
+                    AdditiveSS::new(b, me, is_leader),
+                    AdditiveSS::new(c, me, is_leader),
+                )
+            })
+            .collect())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("GilboaTriple")
+    }
+}
+
+/// Returns the two parties in ascending ID order.
+fn sorted(mut parties: [PartyId; 2]) -> [PartyId; 2] {
+    parties.sort();
+    parties
+}
+
+/// Returns `Σ r_k · 2^k` for the bits `r_k`.
+fn compose<F: Ring>(bits: &[bool], powers: &[F]) -> F {
+    bits.iter()
+        .zip(powers)
+        .filter(|(bit, _)| **bit)
+        .fold(F::ZERO, |sum, (_, power)| sum + power)
+}
+
+/// Maps a random OT message to a uniformly random field element.
+fn to_field<F: Ring>(block: &OtBlock) -> F {
+    F::random(&mut ChaCha20Rng::from_seed(*block))
+}
+
+/// Computes the sender's corrections for the OLEs of `a`, `powers.len()` OTs each, and its share
+/// `−Σ x_0` of every product.
+fn corrections<F: Ring>(ots: &ExtendedOtSender, a: &[F], powers: &[F]) -> (Vec<F>, Vec<F>) {
+    let pairs = ots.random();
+    let mut corrections = Vec::with_capacity(pairs.len());
+    let mut terms = Vec::with_capacity(a.len());
+    for (a, pairs) in a.iter().zip(pairs.chunks(powers.len())) {
+        let mut term = F::ZERO;
+        for ((m0, m1), power) in pairs.iter().zip(powers) {
+            let (x0, x1): (F, F) = (to_field(m0), to_field(m1));
+            corrections.push(x0 + &(*a * power) - &x1);
+            term = term - &x0;
+        }
+        terms.push(term);
+    }
+    (corrections, terms)
+}
+
+/// Reads the corrections in `pkt` and computes the receiver's share `Σ y_k` of every product.
+fn received_terms<F: Ring>(
+    ots: &ExtendedOtReceiver,
+    pkt: &Packet,
+    sender: PartyId,
+    bits: usize,
+) -> Result<Vec<F>, Error> {
+    let messages = ots.random();
+    let mut terms = Vec::with_capacity(messages.len() / bits.max(1));
+    for (i, (messages, choices)) in messages
+        .chunks(bits)
+        .zip(ots.choices().chunks(bits))
+        .enumerate()
+    {
+        let mut term = F::ZERO;
+        for (k, (message, choice)) in messages.iter().zip(choices).enumerate() {
+            let mut y: F = to_field(message);
+            if *choice {
+                let correction: F = pkt.read(i * bits + k).map_err(|_| Error::Cheater(sender))?;
+                y = y + &correction;
+            }
+            term = term + &y;
+        }
+        terms.push(term);
+    }
+    Ok(terms)
+}
@@ -0,0 +1,261 @@
+use std::collections::HashMap;
+
+use crate::{
+    abbreviate::Abbreviate,
+    circuit::{ArithGate, ArithmeticCircuit},
+    math::field::FiniteField,
+    net::{Network, Packet, PartyId},
+    protocol::{
+        passive_shamir::{mul::PassiveShamirMul, triple::ShamirTriple},
+        Error, Protocol, ProtocolId, RandEnvironment,
+    },
+    ss::{shamir::ShamirSS, LinearShare},
+};
+
+/// Evaluates an [`ArithmeticCircuit`] on degree-`t` Shamir sharings, spending one multiplication
+/// triple per multiplication gate.
+///
+/// The evaluation has three phases:
+///
+/// 1. **Input**: every party shares all of its input values at once, sending each party a single
+///    packet with its shares of them in circuit order, so the inputs take one round however many
+///    there are and whoever owns them.
+/// 2. **Layers**: for every layer of [`ArithmeticCircuit::layers`], the layer's multiplications go
+///    in a single [`PassiveShamirMul`], consuming the next triples in order, and then the layer's
+///    additions and multiplications by a constant are computed locally. The online phase therefore
+///    costs one round per layer, its multiplicative depth, however many multiplications each layer
+///    holds.
+/// 3. **Output**: in a single round, every party sends the owner of each output its shares of that
+///    owner's outputs, and each owner reconstructs. No other party learns an output.
+///
+/// Every party constructs the protocol with the same circuit, `parties`, `king` and `threshold`,
+/// its own input values, in the order of its input gates, and its shares of `mul_count()` triples,
+/// all of them the same triples across parties (see
+/// [`PassiveTriple`](super::triple::PassiveTriple)). The output is the party's own output values,
+/// in the order of its output gates, empty for a party that owns none.
+pub struct PassiveShamirCircuit<const LIMBS: usize, F> {
+    circuit: ArithmeticCircuit<F>,
+    parties: Vec<PartyId>,
+    king: PartyId,
+    threshold: usize,
+    inputs: Vec<F>,
+    triples: Vec<ShamirTriple<LIMBS, F>>,
+}
+
+impl<const LIMBS: usize, F> PassiveShamirCircuit<LIMBS, F>
+where
+    F: FiniteField<LIMBS>,
+{
+    /// Creates the protocol evaluating `circuit` among `parties` at degree `threshold`, with `king`
+    /// reconstructing the masked values of every multiplication.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `triples` does not hold exactly one triple per multiplication
+    /// gate, if a triple is not of degree `threshold`, if `threshold >= parties.len()`, or if
+    /// `king` or the owner of an input or output gate is not one of `parties`. A party given a
+    /// different number of input values than it owns input gates fails with [`Error::Input`] when
+    /// the protocol runs, before sending anything.
+    pub fn new(
+        circuit: ArithmeticCircuit<F>,
+        mut parties: Vec<PartyId>,
+        king: PartyId,
+        threshold: usize,
+        inputs: Vec<F>,
+        triples: Vec<ShamirTriple<LIMBS, F>>,
+    ) -> Result<Self, Error> {
+        let owners_known = circuit.gates().iter().all(|gate| match gate {
+            ArithGate::Input { owner } | ArithGate::Output { owner, .. } => parties.contains(owner),
+            _ => true,
+        });
+        let triples_fit = triples.iter().all(|triple| {
+            triple.a().degree() == threshold
+                && triple.b().degree() == threshold
+                && triple.mult().degree() == threshold
+        });
+        if triples.len() != circuit.mul_count()
+            || !triples_fit
+            || threshold >= parties.len()
+            || !parties.contains(&king)
+            || !owners_known
+        {
+            return Err(Error::Input);
+        }
+
+        parties.sort();
+        Ok(Self {
+            circuit,
+            parties,
+            king,
+            threshold,
+            inputs,
+            triples,
+        })
+    }
+}
+
+impl<const LIMBS: usize, E, F> Protocol<E> for PassiveShamirCircuit<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + Send + Sync + From<u64> + Abbreviate + 'static,
+    E: RandEnvironment,
+{
+    type Output = Vec<F>;
+
+    async fn run(mut self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        if self.inputs.len() != self.circuit.input_count(me) {
+            return Err(Error::Input);
+        }
+
+        // Every gate's wire, filled in as the evaluation reaches it; output gates leave theirs
+        // empty.
+        let mut wires: Vec<Option<ShamirSS<LIMBS, F>>> = vec![None; self.circuit.gates().len()];
+        let share = |wires: &[Option<ShamirSS<LIMBS, F>>], index: usize| {
+            wires[index]
+                .clone()
+                .expect("gates only read the wires of earlier layers")
+        };
+
+        // Share every input of mine, and send each party its shares of all of them in one packet.
+        let mut dealt = vec![Vec::with_capacity(self.inputs.len()); self.parties.len()];
+        for value in self.inputs {
+            let shares = <ShamirSS<LIMBS, F> as LinearShare>::shares_from_secret(
+                value,
+                &self.parties,
+                self.threshold,
+                env.rng_mut(),
+            )?;
+            for (column, share) in dealt.iter_mut().zip(shares) {
+                column.push(share);
+            }
+        }
+        let mut received = HashMap::new();
+        let mut messages = Vec::new();
+        for (party, shares) in self.parties.iter().zip(dealt) {
+            if *party == me {
+                received.insert(me, shares);
+            } else if !shares.is_empty() {
+                let mut pkt = Packet::empty();
+                pkt.write_many_labeled(&shares)?;
+                messages.push((*party, pkt));
+            }
+        }
+        env.network_mut().send_many(&messages).await?;
+
+        // Then take my shares of every other owner's inputs, from its single packet.
+        for owner in &self.parties {
+            let count = self.circuit.input_count(*owner);
+            if *owner == me || count == 0 {
+                continue;
+            }
+            let pkt = env.network_mut().recv_from(*owner).await?;
+            let shares = (0..count)
+                .map(|i| pkt.read(i))
+                .collect::<Result<Vec<ShamirSS<LIMBS, F>>, _>>()?;
+            received.insert(*owner, shares);
+        }
+        let mut received: HashMap<PartyId, _> = received
+            .into_iter()
+            .map(|(owner, shares)| (owner, shares.into_iter()))
+            .collect();
+        for (index, gate) in self.circuit.gates().iter().enumerate() {
+            if let ArithGate::Input { owner } = *gate {
+                wires[index] = received.get_mut(&owner).and_then(Iterator::next);
+            }
+        }
+
+        for layer in self.circuit.layers() {
+            // The layer's products first, in one batch; their operands are all in earlier layers.
+            if !layer.muls().is_empty() {
+                let mut x = Vec::with_capacity(layer.muls().len());
+                let mut y = Vec::with_capacity(layer.muls().len());
+                for index in layer.muls() {
+                    if let ArithGate::Mul { a, b } = self.circuit.gates()[*index] {
+                        x.push(share(&wires, a.index()));
+                        y.push(share(&wires, b.index()));
+                    }
+                }
+                let triples = self.triples.drain(..layer.muls().len()).collect();
+                let products =
+                    PassiveShamirMul::new(self.king, self.parties.clone(), x, y, triples)?
+                        .execute(env)
+                        .await?;
+                for (index, product) in layer.muls().iter().zip(products) {
+                    wires[*index] = Some(product);
+                }
+            }
+
+            // Then its local gates, in circuit order.
+            for index in layer.locals() {
+                wires[*index] = match self.circuit.gates()[*index] {
+                    ArithGate::Add { a, b } => {
+                        Some(share(&wires, a.index()) + &share(&wires, b.index()))
+                    }
+                    ArithGate::ConstMul { a, constant } => {
+                        Some(share(&wires, a.index()) * &constant)
+                    }
+                    _ => unreachable!("layers only hold additions and constant multiplications"),
+                };
+            }
+        }
+
+        // Every owner gets all its shares from each party in one packet, and reads them with
+        // `recv_from`, so the outputs of different owners cannot be confused.
+        let mut messages = Vec::new();
+        let mut own_shares = Vec::new();
+        for owner in &self.parties {
+            let shares: Vec<ShamirSS<LIMBS, F>> = self
+                .circuit
+                .gates()
+                .iter()
+                .filter_map(|gate| match *gate {
+                    ArithGate::Output { a, owner: o } if o == *owner => {
+                        Some(share(&wires, a.index()))
+                    }
+                    _ => None,
+                })
+                .collect();
+            if *owner == me {
+                own_shares = shares;
+            } else if !shares.is_empty() {
+                let mut pkt = Packet::empty();
+                pkt.write_many_labeled(&shares)?;
+                messages.push((*owner, pkt));
+            }
+        }
+        env.network_mut().send_many(&messages).await?;
+
+        if own_shares.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut shares_per_party = Vec::with_capacity(self.parties.len());
+        for party in &self.parties {
+            if *party == me {
+                shares_per_party.push(own_shares.clone());
+            } else {
+                let pkt = env.network_mut().recv_from(*party).await?;
+                let shares = (0..own_shares.len())
+                    .map(|i| pkt.read(i))
+                    .collect::<Result<Vec<ShamirSS<LIMBS, F>>, _>>()?;
+                shares_per_party.push(shares);
+            }
+        }
+
+        let mut outputs = Vec::with_capacity(own_shares.len());
+        for i in 0..own_shares.len() {
+            let shares: Vec<ShamirSS<LIMBS, F>> = shares_per_party
+                .iter()
+                .map(|shares| shares[i].clone())
+                .collect();
+            outputs.push(<ShamirSS<LIMBS, F> as LinearShare>::secret_from_shares(
+                &shares,
+                &self.parties,
+            )?);
+        }
+        Ok(outputs)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("PassiveShamirCircuit")
+    }
+}
@@ -0,0 +1,142 @@
+use crate::{
+    math::field::FiniteField,
+    net::{Network, PartyId},
+    protocol::{
+        passive_shamir::reshare::PassiveReshareShamir, share::deal::PassiveDealShr, Error,
+        Protocol, ProtocolId, RandEnvironment,
+    },
+    ss::{additive::AdditiveSS, shamir::ShamirSS},
+};
+
+/// Converts an **additive** sharing into a degree-`t` **Shamir** sharing of the same secret.
+///
+/// Each party deals its additive component `x_i` with [`PassiveDealShr`] as a degree-`t` Shamir
+/// sharing, and sums the `n` sharings it receives. Since `x = Σ x_i`, the sum is a Shamir sharing
+/// of `x`. The conversion takes a single round. The opposite direction needs no protocol: see
+/// [`ShamirSS::to_additive`].
+///
+/// # Preconditions
+///
+/// - `parties` must be exactly the parties holding the additive sharing, and every one of them
+///   must run this protocol with the same `parties` and `degree`.
+///
+/// # Security model: passive adversary
+///
+/// This assumes a **passive (semi-honest) adversary**: every party deals its true component. A
+/// party that deals another value shifts the converted secret by the difference.
+pub struct PassiveAdditiveToShamir<const LIMBS: usize, F> {
+    /// The local party's additive component.
+    share: AdditiveSS<F>,
+    /// The parties holding the additive sharing; they also receive the Shamir sharing.
+    parties: Vec<PartyId>,
+    /// The degree of the resulting Shamir sharing.
+    degree: usize,
+}
+
+impl<const LIMBS: usize, F> PassiveAdditiveToShamir<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + From<u64> + Send + Sync,
+{
+    /// Creates the protocol for the local party, which holds `share` of a secret additively shared
+    /// among `parties`; the output is its share of a degree-`degree` Shamir sharing of the secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `degree >= parties.len()`: the Shamir sharing could not be
+    /// reconstructed even from every share.
+    pub fn new(share: AdditiveSS<F>, parties: Vec<PartyId>, degree: usize) -> Result<Self, Error> {
+        if degree >= parties.len() {
+            return Err(Error::Input);
+        }
+        Ok(Self {
+            share,
+            parties,
+            degree,
+        })
+    }
+}
+
+impl<const LIMBS: usize, E, F> Protocol<E> for PassiveAdditiveToShamir<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + Send + Sync + From<u64> + 'static,
+    E: RandEnvironment,
+{
+    type Output = ShamirSS<LIMBS, F>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        if !self.parties.contains(&me) {
+            return Err(Error::Input);
+        }
+
+        let mut shamir: ShamirSS<LIMBS, F> =
+            PassiveDealShr::dealer(me, *self.share.share(), self.parties.clone(), self.degree)
+                .execute(env)
+                .await?;
+        for party in self.parties.iter().filter(|&party| *party != me) {
+            let component: ShamirSS<LIMBS, F> = env.network_mut().recv_from(*party).await?.pop()?;
+            shamir = shamir + &component;
+        }
+        Ok(shamir)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("PassiveAdditiveToShamir")
+    }
+}
+
+/// Degree reduction: turns a degree-`2t` Shamir sharing — such as the local product of two
+/// degree-`t` sharings — into a degree-`t` sharing of the same secret.
+///
+/// This is [`PassiveReshareShamir`] with the same committee on both sides: every party reshares
+/// its degree-`2t` share at degree `t`, and every party recombines the sub-shares with the
+/// Lagrange coefficients of the whole committee. It takes a single round and, unlike the Beaver
+/// route of [`mul`](super::mul), consumes no preprocessing; in exchange every party talks to
+/// every other, so it costs `O(n²)` messages.
+///
+/// # Preconditions
+///
+/// - `n >= 2t + 1`, so that the degree-`2t` sharing is determined by the `n` shares.
+/// - Every party in `parties` must run this protocol with the same `parties` and `degree`.
+///
+/// # Security model: passive adversary
+///
+/// As for [`PassiveReshareShamir`]: every party reshares its true share.
+pub struct PassiveDegreeReduction<const LIMBS: usize, F>(PassiveReshareShamir<LIMBS, F>);
+
+impl<const LIMBS: usize, F> PassiveDegreeReduction<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + From<u64> + Send + Sync,
+{
+    /// Creates the protocol for the local party, which holds `share` (of any degree below
+    /// `parties.len()`) and obtains a share of degree `degree` of the same secret.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `share` or the target `degree` is too high for `parties` to
+    /// interpolate, or if `parties` lists a party twice.
+    pub fn new(
+        share: ShamirSS<LIMBS, F>,
+        parties: Vec<PartyId>,
+        degree: usize,
+    ) -> Result<Self, Error> {
+        PassiveReshareShamir::new(Some(share), parties.clone(), parties, degree).map(Self)
+    }
+}
+
+impl<const LIMBS: usize, E, F> Protocol<E> for PassiveDegreeReduction<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + Send + Sync + From<u64> + 'static,
+    E: RandEnvironment,
+{
+    type Output = ShamirSS<LIMBS, F>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        // Every party is in the new committee, so the reshare always hands back a share.
+        self.0.execute(env).await?.ok_or(Error::Input)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("PassiveDegreeReduction")
+    }
+}
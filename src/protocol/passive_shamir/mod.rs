@@ -20,15 +20,38 @@
 //! - [`mul`] — Beaver multiplication, which spends those triples to multiply live wire values. All
 //!   the products at one depth of a circuit go in a single batch, so a circuit's round count tracks
 //!   its multiplicative depth rather than its gate count.
+//! - [`circuit`] — a whole [`ArithmeticCircuit`](crate::circuit::ArithmeticCircuit), evaluated
+//!   with one [`mul`] batch per layer, so a function can be described rather than programmed.
+//!
+//! Alongside the computation itself, [`reshare`] moves a sharing to a new committee with a new
+//! threshold, for deployments whose party set changes over time, and [`convert`] moves secrets
+//! between Shamir and additive sharing and brings a degree-`2t` sharing back down to degree `t`.
+//!
+//! For threshold cryptography over elliptic curves, [`open_exponent`] opens `x · P` for a shared
+//! scalar `x` without revealing `x` (see [`crate::ss::exponent`]).
+//!
+//! Preprocessed material does not have to be used in the session that made it: [`store`] persists
+//! it to disk, for an online phase that runs later.
 
+/// Evaluation of an [`ArithmeticCircuit`](crate::circuit::ArithmeticCircuit), one batched
+/// multiplication per layer.
+pub mod circuit;
+/// Conversions between additive and Shamir sharing, and degree reduction.
+pub mod convert;
 /// DN07 `Double-Random`: batches of degree-`t` / degree-`2t` sharings of the same unknown secrets.
 pub mod double_rand_share;
 /// Beaver multiplication: spends triples to multiply sharings, a whole batch per round.
 pub mod mul;
+/// Opening of Shamir-shared scalars in the exponent of an elliptic curve.
+pub mod open_exponent;
 /// DN07 `Open`: reconstruction through a designated king, one value or a batch at a time.
 pub mod open_king;
 /// DN07 `Random`: batches of degree-`t` sharings of secrets that no party knows.
 pub mod rand_share;
+/// Dynamic resharing of a Shamir sharing to a new committee and threshold.
+pub mod reshare;
+/// Persistent, consume-once storage of preprocessed material.
+pub mod store;
 /// DN07 triple generation: multiplication triples `([a], [b], [a · b])`.
 pub mod triple;
 
@@ -0,0 +1,118 @@
+use crate::{
+    abbreviate::Abbreviate,
+    math::ec::EllipticCurve,
+    net::{Network, Packet, PartyId},
+    protocol::{Environment, Error, Protocol, ProtocolId},
+    ss::{exponent::ExponentSS, shamir::ShamirSS},
+};
+
+/// Opens a batch of Shamir-shared scalars **in the exponent**: for shared `[x]` and a public point
+/// `P`, every party learns `x · P` — but not `x` — in **one round**.
+///
+/// Each party lifts its shares to [`ExponentSS`] points `x_i · P`, sends them to every other
+/// party, and interpolates the points it receives in the exponent. With `P = G` this reveals a
+/// public key from a shared secret key; with `P` the first component of an ElGamal ciphertext it
+/// computes the decryption mask `x · C_1`.
+///
+/// # Preconditions
+///
+/// Every party in `parties` must run this protocol with the same base and party list, and with its
+/// shares of the same scalars, in the same order.
+///
+/// # Security model: passive adversary
+///
+/// The points are interpolated as received, so a party that sends a wrong point silently changes
+/// the result. Against an active adversary each point must come with a proof that it was computed
+/// from the party's committed share (a Chaum–Pedersen proof of discrete-log equality).
+pub struct PassiveOpenInExponent<const LIMBS: usize, C: EllipticCurve<LIMBS>> {
+    base: C,
+    shares: Vec<ShamirSS<LIMBS, C::ScalarField>>,
+    parties: Vec<PartyId>,
+}
+
+impl<const LIMBS: usize, C> PassiveOpenInExponent<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    /// Creates the protocol for the local party, which opens `shares` in the exponent of `base`
+    /// together with the other parties in `parties`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if the shares do not all have the same degree `t`, or if
+    /// `parties` has fewer than `t + 1` members, so the points could not be interpolated.
+    pub fn new(
+        base: C,
+        shares: Vec<ShamirSS<LIMBS, C::ScalarField>>,
+        parties: Vec<PartyId>,
+    ) -> Result<Self, Error> {
+        if let Some(first) = shares.first() {
+            let t = first.degree();
+            if shares.iter().any(|share| share.degree() != t) || parties.len() <= t {
+                return Err(Error::Input);
+            }
+        }
+        Ok(Self {
+            base,
+            shares,
+            parties,
+        })
+    }
+}
+
+impl<const LIMBS: usize, C, E> Protocol<E> for PassiveOpenInExponent<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS> + Abbreviate + Send + Sync + 'static,
+    C::ScalarField: From<u64> + Send + Sync + 'static,
+    E: Environment,
+{
+    /// The opened points `x · P`, one per share.
+    type Output = Vec<C>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let mine: Vec<ExponentSS<LIMBS, C>> = self
+            .shares
+            .iter()
+            .map(|share| ExponentSS::from_share(share, &self.base))
+            .collect();
+
+        let mut pkt = Packet::empty();
+        pkt.write_many_labeled(&mine)?;
+        let messages: Vec<_> = self
+            .parties
+            .iter()
+            .filter(|party| **party != me)
+            .map(|party| (*party, pkt.clone()))
+            .collect();
+        env.network_mut().send_many(&messages).await?;
+
+        // `received[j]` collects every party's share in the exponent of the `j`-th scalar, in the
+        // order of `self.parties`.
+        let mut received: Vec<Vec<ExponentSS<LIMBS, C>>> = (0..mine.len())
+            .map(|_| Vec::with_capacity(self.parties.len()))
+            .collect();
+        for party in &self.parties {
+            if *party == me {
+                for (column, share) in received.iter_mut().zip(&mine) {
+                    column.push(*share);
+                }
+                continue;
+            }
+            let pkt = env.network_mut().recv_from(*party).await?;
+            for (j, column) in received.iter_mut().enumerate() {
+                column.push(pkt.read(j)?);
+            }
+        }
+
+        let mut opened = Vec::with_capacity(received.len());
+        for column in &received {
+            opened.push(ExponentSS::secret_from_shares(column, &self.parties)?);
+        }
+        Ok(opened)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("PassiveOpenInExponent")
+    }
+}
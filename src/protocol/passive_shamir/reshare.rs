@@ -0,0 +1,146 @@
+use crate::{
+    math::{field::FiniteField, poly::compute_lagrange_basis},
+    net::{Network, Packet, PartyId},
+    protocol::{Error, Protocol, ProtocolId, RandEnvironment},
+    ss::{shamir::ShamirSS, LinearShare, ShareError},
+};
+
+/// Dynamic resharing: hands a Shamir-shared secret from an **old committee** to a **new
+/// committee**, possibly of a different size and with a different threshold.
+///
+/// Every old party `i` reshares its own share `s_i` with a fresh polynomial of degree `t'` among
+/// the new parties. Since the secret is `s = Σ λ_i · s_i`, where `λ_i` are the Lagrange
+/// coefficients of the old committee at zero, each new party `j` combines the sub-shares it
+/// received as `s'_j = Σ λ_i · s_ij` and so ends with a share of `s` on a degree-`t'` polynomial
+/// that is independent of the old one. The protocol takes a single round.
+///
+/// The two committees may overlap arbitrarily: a party in both deals like an old party and
+/// combines like a new one, while a party only in the old committee deals and then outputs
+/// `None`. This is what lets a long-running deployment add and remove parties, or change its
+/// corruption threshold, without ever reconstructing the secret.
+///
+/// # Preconditions
+///
+/// - Every party in `old_parties` must hold a share of the secret, all of the same degree `t`, and
+///   there must be at least `t + 1` of them; any `t + 1` holders of the old sharing suffice, so
+///   parties that have already left need not be listed.
+/// - Every party in `old_parties` and `new_parties` must run this protocol with the same party
+///   lists and the same `new_degree`.
+///
+/// # Security model: passive adversary
+///
+/// This assumes a **passive (semi-honest) adversary** corrupting up to `t` old and up to `t'` new
+/// parties: every old party reshares its true share. An old party that reshares a different value
+/// silently shifts the secret; preventing that requires verifiable resharing (e.g. with Feldman
+/// commitments), which is outside the passive model.
+pub struct PassiveReshareShamir<const LIMBS: usize, F> {
+    /// The local party's share of the old sharing; `None` on parties only in the new committee.
+    old_share: Option<ShamirSS<LIMBS, F>>,
+    /// The old committee, whose members each reshare their share.
+    old_parties: Vec<PartyId>,
+    /// The Lagrange coefficients at zero of `old_parties`, aligned with that list.
+    lagrange: Vec<F>,
+    /// The new committee, each member of which receives a share of degree `new_degree`.
+    new_parties: Vec<PartyId>,
+    /// The degree `t'` of the new sharing.
+    new_degree: usize,
+}
+
+impl<const LIMBS: usize, F> PassiveReshareShamir<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + From<u64> + Send + Sync,
+{
+    /// Creates the protocol for the local party, which holds `old_share` if it belongs to
+    /// `old_parties` and passes `None` otherwise. The secret ends up shared with degree
+    /// `new_degree` among `new_parties` (see the preconditions on [`PassiveReshareShamir`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `old_parties` is empty or lists a party twice, if
+    /// `new_degree >= new_parties.len()` (the new sharing could never be reconstructed), or if
+    /// `old_share` has a degree that `old_parties` is too small to interpolate.
+    pub fn new(
+        old_share: Option<ShamirSS<LIMBS, F>>,
+        old_parties: Vec<PartyId>,
+        new_parties: Vec<PartyId>,
+        new_degree: usize,
+    ) -> Result<Self, Error> {
+        if old_parties.is_empty() || new_degree >= new_parties.len() {
+            return Err(Error::Input);
+        }
+        if let Some(share) = &old_share {
+            if share.degree() >= old_parties.len() {
+                return Err(Error::Input);
+            }
+        }
+        let points: Vec<F> = old_parties
+            .iter()
+            .copied()
+            .map(<ShamirSS<LIMBS, F> as LinearShare>::encode_party)
+            .collect();
+        // Fails only on repeated nodes, that is, a party listed twice.
+        let lagrange = compute_lagrange_basis(&points, &F::ZERO).map_err(|_| Error::Input)?;
+        Ok(Self {
+            old_share,
+            old_parties,
+            lagrange,
+            new_parties,
+            new_degree,
+        })
+    }
+}
+
+impl<const LIMBS: usize, E, F> Protocol<E> for PassiveReshareShamir<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + Send + Sync + From<u64> + 'static,
+    E: RandEnvironment,
+{
+    /// The local party's share of the new sharing, or `None` if it is not in the new committee.
+    type Output = Option<ShamirSS<LIMBS, F>>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let is_old = self.old_parties.contains(&me);
+        let is_new = self.new_parties.contains(&me);
+        if !is_old && !is_new {
+            return Err(Error::Input);
+        }
+
+        // Reshare my old share among the new committee.
+        if is_old {
+            let old_share = self.old_share.ok_or(Error::Input)?;
+            let sub_shares = <ShamirSS<LIMBS, F> as LinearShare>::shares_from_secret(
+                *old_share.share(),
+                &self.new_parties,
+                self.new_degree,
+                env.rng_mut(),
+            )?;
+            let mut messages = Vec::with_capacity(sub_shares.len());
+            for (recv, sub_share) in self.new_parties.iter().zip(sub_shares) {
+                let mut pkt = Packet::empty();
+                pkt.write_labeled(&sub_share)?;
+                messages.push((*recv, pkt));
+            }
+            env.network_mut().send_many(&messages).await?;
+        }
+
+        if !is_new {
+            return Ok(None);
+        }
+
+        // Combine the sub-shares with the old committee's Lagrange coefficients.
+        let mut new_share = F::ZERO;
+        for (party, lambda) in self.old_parties.iter().zip(&self.lagrange) {
+            let sub_share: ShamirSS<LIMBS, F> = env.network_mut().recv_from(*party).await?.pop()?;
+            if sub_share.degree() != self.new_degree {
+                return Err(ShareError::<F>::SharesWithDifferentDegree.into());
+            }
+            new_share = new_share.add(&sub_share.share().mul(lambda));
+        }
+        Ok(Some(ShamirSS::new(new_share, self.new_degree)))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("PassiveReshareShamir")
+    }
+}
@@ -0,0 +1,504 @@
+use std::{
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::{
+    math::field::FiniteField, net::PartyId, protocol::passive_shamir::triple::ShamirTriple,
+    ss::shamir::ShamirSS,
+};
+
+/// The magic bytes every store file starts with.
+const MAGIC: &[u8; 8] = b"SCLPREP\0";
+
+/// The version of the store file format written by this module.
+pub const FORMAT_VERSION: u16 = 1;
+
+/// Errors that occur when creating, loading or consuming a [`PreprocessingStore`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum StoreError {
+    /// Reading or writing the store file failed.
+    #[error("IO error: {0:?}")]
+    Io(#[from] io::Error),
+    /// The store file could not be encoded or decoded.
+    #[error("error during the serialization: {0:?}")]
+    Serialization(#[from] postcard::Error),
+    /// The file does not start with the store's magic bytes: it is not a preprocessing store.
+    #[error("the file is not a preprocessing store")]
+    NotAStore,
+    /// The file was written in a format version this build does not read.
+    #[error("unsupported store format version {0}, expected {FORMAT_VERSION}")]
+    UnsupportedVersion(u16),
+    /// The file's counts disagree with the material it holds.
+    #[error("the store file is corrupted")]
+    Corrupted,
+    /// The store was made for a different session, field, degree or party than expected.
+    #[error("the store header does not match: expected {expected:?}, found {found:?}")]
+    HeaderMismatch {
+        /// The header the caller expected.
+        expected: Box<StoreHeader>,
+        /// The header found in the file.
+        found: Box<StoreHeader>,
+    },
+    /// A sharing added to the store does not have the degree in its header.
+    #[error("expected sharings of degree {expected}, found degree {found}")]
+    DegreeMismatch {
+        /// The degree in the store header.
+        expected: usize,
+        /// The degree of the offending sharing.
+        found: usize,
+    },
+    /// Another [`PreprocessingStore`] holds the store's lock file, in this or another process.
+    ///
+    /// A lock file left behind by a process that crashed must be removed by hand, after making
+    /// sure no process still uses the store.
+    #[error("the store is in use: its lock file {0:?} exists")]
+    Locked(PathBuf),
+    /// More items were requested than are left in the store.
+    #[error("requested {requested} {kind}, but only {left} are left")]
+    Exhausted {
+        /// The kind of material requested.
+        kind: &'static str,
+        /// The number of items requested.
+        requested: usize,
+        /// The number of items left.
+        left: usize,
+    },
+}
+
+/// Identifies what a store's material is for: the session that produced it, the field and degree
+/// of its sharings, the party set it was shared among, and the party whose shares it holds.
+///
+/// Material from one store is only meaningful together with the *matching* stores of the other
+/// parties, so [`PreprocessingStore::open`] refuses a file whose header differs from the one the
+/// caller expects.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoreHeader {
+    field: String,
+    degree: usize,
+    parties: Vec<PartyId>,
+    party: PartyId,
+    session_id: u64,
+}
+
+impl StoreHeader {
+    /// Creates the header of `party`'s store of degree-`degree` sharings over `F` among `parties`,
+    /// produced in session `session_id`. The field is recorded by its modulus.
+    pub fn new<const LIMBS: usize, F: FiniteField<LIMBS>>(
+        degree: usize,
+        parties: Vec<PartyId>,
+        party: PartyId,
+        session_id: u64,
+    ) -> Self {
+        Self {
+            field: F::MODULUS.to_string(),
+            degree,
+            parties,
+            party,
+            session_id,
+        }
+    }
+
+    /// Returns the modulus of the field, in hexadecimal.
+    pub fn field(&self) -> &str {
+        &self.field
+    }
+
+    /// Returns the degree of every sharing in the store.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Returns the parties the material is shared among.
+    pub fn parties(&self) -> &[PartyId] {
+        &self.parties
+    }
+
+    /// Returns the party whose shares the store holds.
+    pub fn party(&self) -> PartyId {
+        self.party
+    }
+
+    /// Returns the identifier of the session that produced the material.
+    pub fn session_id(&self) -> u64 {
+        self.session_id
+    }
+}
+
+/// How many items of each kind of material, in the file header.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+struct Counts {
+    triples: u64,
+    random_shares: u64,
+    random_bits: u64,
+}
+
+/// Everything before the material in a store file.
+#[derive(Serialize, Deserialize)]
+struct FileHeader {
+    header: StoreHeader,
+    /// Items left, which must match the material that follows.
+    left: Counts,
+    /// Items handed out over the store's lifetime.
+    consumed: Counts,
+}
+
+/// The material of a store, as written to the file.
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "ShamirSS<LIMBS, F>: Serialize",
+    deserialize = "ShamirSS<LIMBS, F>: Deserialize<'de>"
+))]
+struct Material<const LIMBS: usize, F> {
+    triples: Vec<(ShamirSS<LIMBS, F>, ShamirSS<LIMBS, F>, ShamirSS<LIMBS, F>)>,
+    random_shares: Vec<ShamirSS<LIMBS, F>>,
+    random_bits: Vec<ShamirSS<LIMBS, F>>,
+}
+
+/// One party's preprocessed Shamir material — multiplication triples, sharings of random values,
+/// and sharings of random bits — persisted to a file so that it outlives the process that made it.
+///
+/// The offline phase can then run ahead of time (overnight, say) and the online phase load what it
+/// needs on demand. The file starts with a versioned header recording the session, field, degree,
+/// party set and item counts (see [`StoreHeader`]), followed by the material.
+///
+/// Items are handed out by **consumption**: every `take_*` call removes the items and rewrites the
+/// file *before* returning them, replacing it atomically, so an item that was ever handed out is
+/// gone from disk even if the process crashes right after. Reusing a triple would reveal the values
+/// it masked, and this is what rules it out across restarts. The parties must consume their stores
+/// in the same order so that their shares keep lining up; the header's consumption counters help
+/// check that they do.
+///
+/// A store is **exclusive**: while it is open, it holds a lock file next to the store file (the
+/// store's path with `.lock` appended), created exclusively and removed when the store is dropped.
+/// Two handles on the same file would each hand out the same items, so opening or creating a store
+/// whose lock file exists fails with [`StoreError::Locked`].
+pub struct PreprocessingStore<const LIMBS: usize, F> {
+    path: PathBuf,
+    _lock: StoreLock,
+    header: StoreHeader,
+    consumed: Counts,
+    material: Material<LIMBS, F>,
+}
+
+impl<const LIMBS: usize, F> PreprocessingStore<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + Serialize + for<'de> Deserialize<'de>,
+{
+    /// Creates an empty store at `path` with the given `header`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::Locked`] if the store is open elsewhere, and [`StoreError::Io`] if
+    /// the file already exists — an existing store is never overwritten — or cannot be written.
+    pub fn create(path: impl AsRef<Path>, header: StoreHeader) -> Result<Self, StoreError> {
+        let path = path.as_ref().to_path_buf();
+        let lock = StoreLock::acquire(&path)?;
+        let store = Self {
+            path,
+            _lock: lock,
+            header,
+            consumed: Counts::default(),
+            material: Material {
+                triples: Vec::new(),
+                random_shares: Vec::new(),
+                random_bits: Vec::new(),
+            },
+        };
+        let mut file = File::create_new(&store.path)?;
+        file.write_all(&store.encode()?)?;
+        file.sync_all()?;
+        sync_parent(&store.path)?;
+        Ok(store)
+    }
+
+    /// Loads the store at `path`, checking that it was made for `expected`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::Locked`] if the store is open elsewhere,
+    /// [`StoreError::NotAStore`], [`StoreError::UnsupportedVersion`] or [`StoreError::Corrupted`]
+    /// if the file is not a well-formed store of this format version, and
+    /// [`StoreError::HeaderMismatch`] if its header differs from `expected`.
+    pub fn open(path: impl AsRef<Path>, expected: &StoreHeader) -> Result<Self, StoreError> {
+        let path = path.as_ref().to_path_buf();
+        let lock = StoreLock::acquire(&path)?;
+        let bytes = fs::read(&path)?;
+        let rest = bytes.strip_prefix(MAGIC).ok_or(StoreError::NotAStore)?;
+        let (version, rest) = rest.split_first_chunk::<2>().ok_or(StoreError::NotAStore)?;
+        let version = u16::from_le_bytes(*version);
+        if version != FORMAT_VERSION {
+            return Err(StoreError::UnsupportedVersion(version));
+        }
+
+        let (file_header, rest): (FileHeader, _) = postcard::take_from_bytes(rest)?;
+        if file_header.header != *expected {
+            return Err(StoreError::HeaderMismatch {
+                expected: Box::new(expected.clone()),
+                found: Box::new(file_header.header),
+            });
+        }
+        let material: Material<LIMBS, F> = postcard::from_bytes(rest)?;
+
+        let store = Self {
+            path,
+            _lock: lock,
+            header: file_header.header,
+            consumed: file_header.consumed,
+            material,
+        };
+        if store.left() != file_header.left {
+            return Err(StoreError::Corrupted);
+        }
+        Ok(store)
+    }
+
+    /// Returns the store's header.
+    pub fn header(&self) -> &StoreHeader {
+        &self.header
+    }
+
+    /// Returns how many triples are left.
+    pub fn triples_left(&self) -> usize {
+        self.material.triples.len()
+    }
+
+    /// Returns how many sharings of random values are left.
+    pub fn random_shares_left(&self) -> usize {
+        self.material.random_shares.len()
+    }
+
+    /// Returns how many sharings of random bits are left.
+    pub fn random_bits_left(&self) -> usize {
+        self.material.random_bits.len()
+    }
+
+    /// Returns how many triples, random sharings and random bits have been handed out over the
+    /// store's lifetime, in that order.
+    pub fn consumed(&self) -> (u64, u64, u64) {
+        (
+            self.consumed.triples,
+            self.consumed.random_shares,
+            self.consumed.random_bits,
+        )
+    }
+
+    /// Appends `triples` to the store and persists it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::DegreeMismatch`] if a sharing does not have the header's degree, in
+    /// which case nothing is added, or [`StoreError::Io`] if the file cannot be written.
+    pub fn add_triples(&mut self, triples: Vec<ShamirTriple<LIMBS, F>>) -> Result<(), StoreError> {
+        let triples: Vec<_> = triples.into_iter().map(ShamirTriple::into_parts).collect();
+        for (a, b, c) in &triples {
+            self.check_degree([a, b, c])?;
+        }
+        self.material.triples.extend(triples);
+        self.persist()
+    }
+
+    /// Appends sharings of random values to the store and persists it.
+    ///
+    /// # Errors
+    ///
+    /// As for [`add_triples`](Self::add_triples).
+    pub fn add_random_shares(&mut self, shares: Vec<ShamirSS<LIMBS, F>>) -> Result<(), StoreError> {
+        self.check_degree(&shares)?;
+        self.material.random_shares.extend(shares);
+        self.persist()
+    }
+
+    /// Appends sharings of random bits to the store and persists it.
+    ///
+    /// # Errors
+    ///
+    /// As for [`add_triples`](Self::add_triples).
+    pub fn add_random_bits(&mut self, bits: Vec<ShamirSS<LIMBS, F>>) -> Result<(), StoreError> {
+        self.check_degree(&bits)?;
+        self.material.random_bits.extend(bits);
+        self.persist()
+    }
+
+    /// Removes the next `count` triples, persists the store, and only then returns them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::Exhausted`] if fewer than `count` triples are left, or
+    /// [`StoreError::Io`] if the file cannot be written; in both cases the store is unchanged.
+    pub fn take_triples(
+        &mut self,
+        count: usize,
+    ) -> Result<Vec<ShamirTriple<LIMBS, F>>, StoreError> {
+        check_left("triples", count, self.triples_left())?;
+        let taken: Vec<_> = self.material.triples.drain(..count).collect();
+        self.consumed.triples += count as u64;
+        if let Err(err) = self.persist() {
+            self.consumed.triples -= count as u64;
+            self.material.triples.splice(..0, taken);
+            return Err(err);
+        }
+        Ok(taken
+            .into_iter()
+            .map(|(a, b, c)| ShamirTriple::new(a, b, c))
+            .collect())
+    }
+
+    /// Removes the next `count` sharings of random values, persists the store, and only then
+    /// returns them.
+    ///
+    /// # Errors
+    ///
+    /// As for [`take_triples`](Self::take_triples).
+    pub fn take_random_shares(
+        &mut self,
+        count: usize,
+    ) -> Result<Vec<ShamirSS<LIMBS, F>>, StoreError> {
+        check_left("random shares", count, self.random_shares_left())?;
+        let taken: Vec<_> = self.material.random_shares.drain(..count).collect();
+        self.consumed.random_shares += count as u64;
+        if let Err(err) = self.persist() {
+            self.consumed.random_shares -= count as u64;
+            self.material.random_shares.splice(..0, taken);
+            return Err(err);
+        }
+        Ok(taken)
+    }
+
+    /// Removes the next `count` sharings of random bits, persists the store, and only then returns
+    /// them.
+    ///
+    /// # Errors
+    ///
+    /// As for [`take_triples`](Self::take_triples).
+    pub fn take_random_bits(
+        &mut self,
+        count: usize,
+    ) -> Result<Vec<ShamirSS<LIMBS, F>>, StoreError> {
+        check_left("random bits", count, self.random_bits_left())?;
+        let taken: Vec<_> = self.material.random_bits.drain(..count).collect();
+        self.consumed.random_bits += count as u64;
+        if let Err(err) = self.persist() {
+            self.consumed.random_bits -= count as u64;
+            self.material.random_bits.splice(..0, taken);
+            return Err(err);
+        }
+        Ok(taken)
+    }
+
+    fn left(&self) -> Counts {
+        Counts {
+            triples: self.material.triples.len() as u64,
+            random_shares: self.material.random_shares.len() as u64,
+            random_bits: self.material.random_bits.len() as u64,
+        }
+    }
+
+    fn check_degree<'a>(
+        &self,
+        shares: impl IntoIterator<Item = &'a ShamirSS<LIMBS, F>>,
+    ) -> Result<(), StoreError>
+    where
+        F: 'a,
+    {
+        for share in shares {
+            if share.degree() != self.header.degree {
+                return Err(StoreError::DegreeMismatch {
+                    expected: self.header.degree,
+                    found: share.degree(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Encodes the whole store: magic bytes, format version, file header, material.
+    fn encode(&self) -> Result<Vec<u8>, StoreError> {
+        let file_header = FileHeader {
+            header: self.header.clone(),
+            left: self.left(),
+            consumed: self.consumed,
+        };
+        let mut bytes = MAGIC.to_vec();
+        bytes.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        bytes.extend(postcard::to_allocvec(&file_header)?);
+        bytes.extend(postcard::to_allocvec(&self.material)?);
+        Ok(bytes)
+    }
+
+    /// Replaces the file with the current state, atomically: the new contents are written and
+    /// synced to a sibling temporary file, which is then renamed over the store. Syncing the
+    /// directory afterwards makes the rename itself durable.
+    fn persist(&self) -> Result<(), StoreError> {
+        let tmp = with_suffix(&self.path, ".tmp");
+        let mut file = File::create(&tmp)?;
+        file.write_all(&self.encode()?)?;
+        file.sync_all()?;
+        fs::rename(&tmp, &self.path)?;
+        sync_parent(&self.path)?;
+        Ok(())
+    }
+}
+
+/// The lock file of an open store, removed when dropped.
+struct StoreLock {
+    path: PathBuf,
+}
+
+impl StoreLock {
+    /// Creates the lock file of the store at `store`, failing if it already exists.
+    fn acquire(store: &Path) -> Result<Self, StoreError> {
+        let path = with_suffix(store, ".lock");
+        match File::create_new(&path) {
+            Ok(_) => Ok(Self { path }),
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => Err(StoreError::Locked(path)),
+            Err(err) => Err(err.into()),
+        }
+    }
+}
+
+impl Drop for StoreLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// Returns `path` with `suffix` appended to its file name.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(suffix);
+    path.into()
+}
+
+/// Syncs the directory containing `path`, so that creating or renaming `path` survives a crash.
+#[cfg(unix)]
+fn sync_parent(path: &Path) -> io::Result<()> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    File::open(dir)?.sync_all()
+}
+
+/// Other platforms cannot open a directory to sync it, so only the file contents are synced.
+#[cfg(not(unix))]
+fn sync_parent(_path: &Path) -> io::Result<()> {
+    Ok(())
+}
+
+/// Checks that `count` items of `kind` can be taken when `left` are left.
+fn check_left(kind: &'static str, count: usize, left: usize) -> Result<(), StoreError> {
+    if count > left {
+        return Err(StoreError::Exhausted {
+            kind,
+            requested: count,
+            left,
+        });
+    }
+    Ok(())
+}
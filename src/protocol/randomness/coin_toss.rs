@@ -0,0 +1,183 @@
+use std::marker::PhantomData;
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    abbreviate::Abbreviate,
+    commitment::{ContextualCommitment, HashCommitment, HashContext, HashOpening, HashScheme},
+    math::ring::Ring,
+    net::{Network, Packet, PartyId},
+    protocol::{Error, Protocol, ProtocolId, RandEnvironment},
+};
+
+/// Commit-then-open coin tossing: a batch of public random elements of `F`, learned by every
+/// party and biased by none.
+///
+/// 1. **Commit.** Each party samples its contribution — one random element per coin — and sends
+///    every other party a [`HashScheme`] commitment to each, bound to the session tag and to the
+///    party itself.
+/// 2. **Open.** Once every commitment is in, each party sends its contributions and their
+///    openings, and checks everyone else's against their commitments.
+///
+/// Each coin is the sum of the parties' contributions to it (over a boolean ring such as
+/// [`Gf2Word`](crate::math::gf2::Gf2Word), their XOR). The commitments fix every contribution
+/// before any is revealed, so the coins are uniform as long as one party samples honestly. The
+/// protocol takes two rounds whatever the number of coins, so it is cheapest to toss every coin a
+/// computation needs in one batch.
+///
+/// To derive a Fiat–Shamir challenge, or any other pseudo-random stream the parties agree on,
+/// hash the coins into a seed with [`CoinToss::seed`].
+///
+/// # Preconditions
+///
+/// Every party in the network must run this protocol with the same session tag and number of
+/// coins. The session tag must be unique to the invocation, so that no commitment of one toss can
+/// be replayed in another.
+///
+/// # Security model: active adversary, abort
+///
+/// A party whose opening does not match its commitment, whose commitment repeats one already
+/// received, or whose messages are malformed or short, is named in [`Error::Cheater`], and no coin
+/// is output. Rejecting repeated commitments, and binding each to its committer, keeps a rushing
+/// party from echoing another party's contribution — with two parties, that would cancel the
+/// coins. The adversary learns the
+/// coins before the honest parties do, and can thus still make the protocol abort depending on
+/// them; it cannot choose or bias the coins it lets through.
+pub struct CoinToss<F> {
+    /// The tag that binds the commitments to this invocation.
+    session: Vec<u8>,
+    /// The number of coins to toss.
+    count: usize,
+    field: PhantomData<F>,
+}
+
+impl<F: Ring> CoinToss<F> {
+    /// Creates the protocol for the local party, which tosses `count` coins in the invocation
+    /// tagged `session`.
+    pub fn new(session: &[u8], count: usize) -> Self {
+        Self {
+            session: session.to_vec(),
+            count,
+            field: PhantomData,
+        }
+    }
+
+    /// Hashes `coins` into a 32-byte seed, for instance to seed a
+    /// [`ChaCha20Rng`](rand_chacha::ChaCha20Rng) the parties derive Fiat–Shamir challenges from.
+    ///
+    /// The coins are hashed in their canonical ([`postcard`]) encoding, so parties holding the same
+    /// coins derive the same seed.
+    pub fn seed(coins: &[F]) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(b"scl-rs/coin-toss-seed");
+        for coin in coins {
+            hasher.update(postcard::to_allocvec(coin).expect("ring elements always serialize"));
+        }
+        hasher.finalize().into()
+    }
+}
+
+impl<F, E> Protocol<E> for CoinToss<F>
+where
+    F: Ring + Abbreviate + Send + Sync + 'static,
+    E: RandEnvironment,
+{
+    /// The coins, identical at every party.
+    type Output = Vec<F>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let contributions: Vec<F> = (0..self.count).map(|_| F::random(env.rng_mut())).collect();
+        commit_and_sum(env, &self.session, contributions).await
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("CoinToss")
+    }
+}
+
+/// Commits to `values` towards every other party with the [`HashScheme`], in the session tagged
+/// `session`; opens them once every commitment is in; and returns, position by position, the sum
+/// of every party's values, mine included.
+///
+/// Takes two rounds. A party whose commitment equals mine or one received before, whose opening
+/// does not match, or whose messages are malformed or short is named in [`Error::Cheater`].
+/// Messages are collected per sender with `recv_from`, so a fast party's next message can never be
+/// mistaken for a slow party's current one.
+pub(crate) async fn commit_and_sum<F, E>(
+    env: &mut E,
+    session: &[u8],
+    values: Vec<F>,
+) -> Result<Vec<F>, Error>
+where
+    F: Ring + Abbreviate,
+    E: RandEnvironment,
+{
+    let me = env.network().local_party();
+    let others: Vec<PartyId> = env
+        .network()
+        .party_ids()
+        .into_iter()
+        .filter(|party| *party != me)
+        .collect();
+    let count = values.len();
+
+    let context = HashContext::new(session, me);
+    let (commitments, openings): (Vec<HashCommitment>, Vec<HashOpening>) = values
+        .iter()
+        .map(|value| HashScheme::commit_in(&context, value, env.rng_mut()))
+        .unzip();
+
+    // Round 1: commit to my values.
+    let mut pkt = Packet::empty();
+    pkt.write_many_labeled(&commitments)?;
+    send_to_all(env, &others, &pkt).await?;
+    let mut received: Vec<Vec<HashCommitment>> = Vec::with_capacity(others.len());
+    for party in &others {
+        let pkt = env.network_mut().recv_from(*party).await?;
+        let theirs: Vec<HashCommitment> = (0..count)
+            .map(|i| pkt.read(i))
+            .collect::<Result<_, _>>()
+            .map_err(|_| Error::Cheater(*party))?;
+        let repeated = theirs.iter().any(|commitment| {
+            commitments.contains(commitment)
+                || received.iter().any(|earlier| earlier.contains(commitment))
+        });
+        if repeated {
+            return Err(Error::Cheater(*party));
+        }
+        received.push(theirs);
+    }
+
+    // Round 2: open them, and check everyone else's against their commitments.
+    let mut pkt = Packet::empty();
+    pkt.write_many_labeled(&values)?;
+    pkt.write_many_labeled(&openings)?;
+    send_to_all(env, &others, &pkt).await?;
+    let mut sums = values;
+    for (party, commitments) in others.iter().zip(received) {
+        let context = HashContext::new(session, *party);
+        let pkt = env.network_mut().recv_from(*party).await?;
+        for (i, (sum, commitment)) in sums.iter_mut().zip(&commitments).enumerate() {
+            let (Ok(value), Ok(opening)) = (pkt.read::<F>(i), pkt.read::<HashOpening>(count + i))
+            else {
+                return Err(Error::Cheater(*party));
+            };
+            if !HashScheme::verify_in(&context, commitment, &value, &opening) {
+                return Err(Error::Cheater(*party));
+            }
+            *sum = *sum + &value;
+        }
+    }
+    Ok(sums)
+}
+
+/// Sends `pkt` to every party in `others`.
+async fn send_to_all<E: RandEnvironment>(
+    env: &mut E,
+    others: &[PartyId],
+    pkt: &Packet,
+) -> Result<(), Error> {
+    let messages: Vec<_> = others.iter().map(|party| (*party, pkt.clone())).collect();
+    env.network_mut().send_many(&messages).await?;
+    Ok(())
+}
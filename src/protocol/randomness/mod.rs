@@ -0,0 +1,20 @@
+//! Public and shared randomness.
+//!
+//! - [`coin_toss`](crate::protocol::randomness::coin_toss) —
+//!   [`CoinToss`](crate::protocol::randomness::coin_toss::CoinToss): public random ring elements
+//!   that every party learns, by commit-then-open. No party can bias them, and a party that opens a
+//!   different value than it committed to is named. The coins can seed a Fiat–Shamir challenge
+//!   through [`CoinToss::seed`](crate::protocol::randomness::coin_toss::CoinToss::seed).
+//! - [`prss`](crate::protocol::randomness::prss) —
+//!   [`PrssSetup`](crate::protocol::randomness::prss::PrssSetup): pseudo-random secret sharing. A
+//!   single round of key distribution yields a
+//!   [`Prss`](crate::protocol::randomness::prss::Prss) sampler, from which every party derives
+//!   its shares of random values and of zero locally, without further communication. A
+//!   [`PrssEnv`](crate::protocol::randomness::prss::PrssEnv) carries the sampler alongside the
+//!   network and RNG, for protocols bounded on
+//!   [`PrssEnvironment`](crate::protocol::randomness::prss::PrssEnvironment).
+
+/// Commit-then-open coin tossing.
+pub mod coin_toss;
+/// Pseudo-random secret sharing of random values and of zero.
+pub mod prss;
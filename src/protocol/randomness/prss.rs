@@ -0,0 +1,313 @@
+use std::marker::PhantomData;
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    math::field::FiniteField,
+    net::{Network, Packet, PartyId},
+    protocol::{Environment, Error, Protocol, ProtocolId, RandEnvironment},
+    ss::{shamir::ShamirSS, LinearShare},
+};
+
+/// The one-time setup of pseudo-random secret sharing (PRSS), following Cramer, Damgård and Ishai,
+/// *Share Conversion, Pseudorandom Secret-Sharing and Applications to Secure Computation*, TCC
+/// 2005.
+///
+/// For every set `A` of `n - t` parties — the complement of a maximal unqualified set — the
+/// smallest party of `A` samples a PRF key `k_A` and sends it to the other members of `A`, in a
+/// single round. The output is a [`Prss`] sampler, from which each party then derives as many
+/// Shamir sharings of random values, and of zero, as it needs, locally and without further
+/// communication. Wrapping it with the session environment in a [`PrssEnv`] makes it reachable
+/// from every protocol of a composition, through [`PrssEnvironment`].
+///
+/// Every party holds the keys of `C(n - 1, t)` sets, and every sample costs as many PRF
+/// evaluations, so PRSS pays off for a small number of parties; for larger `n`,
+/// [`PassiveRandShr`](crate::protocol::passive_shamir::rand_share::PassiveRandShr) scales better.
+///
+/// # Preconditions
+///
+/// Every party in `parties` must run this protocol with the same `parties` and `threshold`. A
+/// party outside `parties` gets [`Error::Input`].
+///
+/// # Security model: passive adversary
+///
+/// Secure against a **passive** adversary corrupting up to `t` parties: any `t` parties miss the
+/// key of the set made of everyone else, so the values they cannot compute on their own stay
+/// pseudo-random to them. Key distribution is in the clear over the (authenticated, encrypted)
+/// point-to-point channels.
+pub struct PrssSetup<const LIMBS: usize, F> {
+    /// The parties, sorted, so every party enumerates the key sets in the same order.
+    parties: Vec<PartyId>,
+    /// The degree `t` of the random sharings.
+    threshold: usize,
+    field: PhantomData<F>,
+}
+
+impl<const LIMBS: usize, F> PrssSetup<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + From<u64> + Send + Sync,
+{
+    /// Creates the setup for the local party, among `parties` and for sharings of degree
+    /// `threshold`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `parties` lists a party twice, or if `threshold` is not below
+    /// the number of parties.
+    pub fn new(mut parties: Vec<PartyId>, threshold: usize) -> Result<Self, Error> {
+        let n = parties.len();
+        parties.sort();
+        parties.dedup();
+        if parties.len() != n || threshold >= n {
+            return Err(Error::Input);
+        }
+        Ok(Self {
+            parties,
+            threshold,
+            field: PhantomData,
+        })
+    }
+}
+
+impl<const LIMBS: usize, F, E> Protocol<E> for PrssSetup<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + From<u64> + Send + Sync + 'static,
+    E: RandEnvironment,
+{
+    type Output = Prss<LIMBS, F>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        if self.parties.binary_search(&me).is_err() {
+            return Err(Error::Input);
+        }
+        let n = self.parties.len();
+        let sets = subsets(&self.parties, n - self.threshold);
+        let others: Vec<PartyId> = self.parties.iter().copied().filter(|p| *p != me).collect();
+
+        // Sample the key of every set I lead, and send it to the set's other members.
+        let mut keys: Vec<Option<[u8; 32]>> = vec![None; sets.len()];
+        let mut outgoing: Vec<Packet> = vec![Packet::empty(); others.len()];
+        for (set, key) in sets.iter().zip(&mut keys) {
+            if set[0] != me {
+                continue;
+            }
+            let mut sampled = [0; 32];
+            env.rng_mut().fill_bytes(&mut sampled);
+            for (other, pkt) in others.iter().zip(&mut outgoing) {
+                if set.contains(other) {
+                    pkt.write(&sampled)?;
+                }
+            }
+            *key = Some(sampled);
+        }
+        let messages: Vec<_> = others.iter().copied().zip(outgoing).collect();
+        env.network_mut().send_many(&messages).await?;
+
+        // Receive the keys of the sets other parties lead and I belong to, in the same order.
+        for other in &others {
+            let pkt = env.network_mut().recv_from(*other).await?;
+            let led = sets
+                .iter()
+                .zip(&mut keys)
+                .filter(|(set, _)| set[0] == *other && set.contains(&me));
+            for (i, (_, key)) in led.enumerate() {
+                *key = Some(pkt.read(i)?);
+            }
+        }
+
+        let me_encoded = ShamirSS::<LIMBS, F>::encode_party(me);
+        let mut set_keys = Vec::new();
+        for (set, key) in sets.iter().zip(keys) {
+            let Some(key) = key else {
+                continue;
+            };
+            // `f_A` has degree `t`, `f_A(0) = 1`, and vanishes on every party outside `A`.
+            let mut coefficient = F::ONE;
+            for outsider in self.parties.iter().filter(|p| !set.contains(p)) {
+                let outsider = ShamirSS::<LIMBS, F>::encode_party(*outsider);
+                let inverse = outsider.inverse().map_err(|_| Error::Input)?;
+                coefficient = coefficient * &(F::ONE - &(me_encoded * &inverse));
+            }
+            set_keys.push(SetKey { key, coefficient });
+        }
+
+        Ok(Prss {
+            me: me_encoded,
+            threshold: self.threshold,
+            keys: set_keys,
+            counter: 0,
+        })
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("PrssSetup")
+    }
+}
+
+/// The key of one set of parties, with the local party's evaluation of the set's polynomial.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct SetKey<F> {
+    key: [u8; 32],
+    /// `f_A(i)` for the local party `i`.
+    coefficient: F,
+}
+
+/// A pseudo-random secret-sharing sampler: one party's output of [`PrssSetup`].
+///
+/// Each call derives the local party's share of a fresh sharing from its keys and a counter, with
+/// no communication. The sharing of a random value is
+///
+/// `s_i = Σ_{A ∋ i} PRF(k_A) · f_A(i)`,
+///
+/// a degree-`t` sharing of `Σ_A PRF(k_A)`, where `f_A` is the degree-`t` polynomial with
+/// `f_A(0) = 1` that vanishes outside `A`. The sharing of zero (PRZS) multiplies `f_A` by a
+/// pseudo-random polynomial with no constant term, for a degree-`2t` sharing of zero — the
+/// masks of a degree reduction, or of a sum of products.
+///
+/// # Preconditions
+///
+/// The parties' samplers stay in step through a shared counter: every party must draw the same
+/// sequence of sharings, in the same order, for their shares to lie on a common polynomial.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Prss<const LIMBS: usize, F> {
+    /// The local party's evaluation point.
+    me: F,
+    /// The degree `t` of the random sharings.
+    threshold: usize,
+    /// The keys of the sets the local party belongs to.
+    keys: Vec<SetKey<F>>,
+    /// The number of sharings drawn so far.
+    counter: u64,
+}
+
+impl<const LIMBS: usize, F> Prss<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + From<u64> + Send + Sync,
+{
+    /// Returns the local party's share of a fresh degree-`t` sharing of a pseudo-random value.
+    pub fn random_share(&mut self) -> ShamirSS<LIMBS, F> {
+        let mut share = F::ZERO;
+        for (set, mut prf) in self.next_streams() {
+            share = share + &(F::random(&mut prf) * &set.coefficient);
+        }
+        ShamirSS::new(share, self.threshold)
+    }
+
+    /// Returns the local party's shares of `count` fresh degree-`t` sharings of pseudo-random
+    /// values.
+    pub fn random_shares(&mut self, count: usize) -> Vec<ShamirSS<LIMBS, F>> {
+        (0..count).map(|_| self.random_share()).collect()
+    }
+
+    /// Returns the local party's share of a fresh degree-`2t` sharing of zero.
+    pub fn zero_share(&mut self) -> ShamirSS<LIMBS, F> {
+        let (me, threshold) = (self.me, self.threshold);
+        let mut share = F::ZERO;
+        for (set, mut prf) in self.next_streams() {
+            // `g_A(i) = Σ_{l=1..t} r_l · i^l`, so `g_A(0) = 0`.
+            let mut power = F::ONE;
+            let mut mask = F::ZERO;
+            for _ in 0..threshold {
+                power = power * &me;
+                mask = mask + &(F::random(&mut prf) * &power);
+            }
+            share = share + &(mask * &set.coefficient);
+        }
+        ShamirSS::new(share, 2 * threshold)
+    }
+
+    /// Returns the local party's shares of `count` fresh degree-`2t` sharings of zero.
+    pub fn zero_shares(&mut self, count: usize) -> Vec<ShamirSS<LIMBS, F>> {
+        (0..count).map(|_| self.zero_share()).collect()
+    }
+
+    /// Advances the counter, and returns a PRF stream per key for the new counter value.
+    fn next_streams(&mut self) -> impl Iterator<Item = (&SetKey<F>, ChaCha20Rng)> {
+        let counter = self.counter;
+        self.counter += 1;
+        self.keys.iter().map(move |set| {
+            let mut hasher = Sha256::new();
+            hasher.update(b"scl-rs/prss");
+            hasher.update(set.key);
+            hasher.update(counter.to_be_bytes());
+            (set, ChaCha20Rng::from_seed(hasher.finalize().into()))
+        })
+    }
+}
+
+/// A [`RandEnvironment`] that also carries a [`Prss`] sampler over `F`, so that any protocol of a
+/// composition can draw shared randomness without it being threaded through every constructor.
+///
+/// Protocols that consume PRSS bound their environment on this trait and draw from
+/// [`prss_mut`](PrssEnvironment::prss_mut); every party must draw the same sequence of sharings
+/// (see [`Prss`]).
+pub trait PrssEnvironment<const LIMBS: usize, F>: RandEnvironment {
+    /// Returns a mutable reference to the session's PRSS sampler.
+    fn prss_mut(&mut self) -> &mut Prss<LIMBS, F>;
+}
+
+/// Environment that extends an inner [`RandEnvironment`] `E` — typically a
+/// [`GeneralEnv`](crate::protocol::GeneralEnv) — with the [`Prss`] sampler output by
+/// [`PrssSetup`].
+///
+/// It delegates the network and the session RNG to `E`, and implements [`PrssEnvironment`].
+pub struct PrssEnv<const LIMBS: usize, F, E> {
+    /// The environment the network and the session RNG come from.
+    pub env: E,
+    /// The session's PRSS sampler.
+    pub prss: Prss<LIMBS, F>,
+}
+
+impl<const LIMBS: usize, F, E> PrssEnv<LIMBS, F, E> {
+    /// Creates the environment from `env` and the local party's `prss` sampler.
+    pub fn new(env: E, prss: Prss<LIMBS, F>) -> Self {
+        Self { env, prss }
+    }
+}
+
+impl<const LIMBS: usize, F: Send, E: Environment> Environment for PrssEnv<LIMBS, F, E> {
+    type Net = E::Net;
+
+    fn network(&self) -> &Self::Net {
+        self.env.network()
+    }
+
+    fn network_mut(&mut self) -> &mut Self::Net {
+        self.env.network_mut()
+    }
+}
+
+impl<const LIMBS: usize, F: Send, E: RandEnvironment> RandEnvironment for PrssEnv<LIMBS, F, E> {
+    type Rng = E::Rng;
+
+    fn rng_mut(&mut self) -> &mut Self::Rng {
+        self.env.rng_mut()
+    }
+}
+
+impl<const LIMBS: usize, F: Send, E: RandEnvironment> PrssEnvironment<LIMBS, F>
+    for PrssEnv<LIMBS, F, E>
+{
+    fn prss_mut(&mut self) -> &mut Prss<LIMBS, F> {
+        &mut self.prss
+    }
+}
+
+/// Returns every subset of `parties` of the given size, each sorted, in lexicographic order.
+fn subsets(parties: &[PartyId], size: usize) -> Vec<Vec<PartyId>> {
+    if size == 0 {
+        return vec![Vec::new()];
+    }
+    let mut result = Vec::new();
+    for (i, first) in parties.iter().enumerate() {
+        for mut rest in subsets(&parties[i + 1..], size - 1) {
+            rest.insert(0, *first);
+            result.push(rest);
+        }
+    }
+    result
+}
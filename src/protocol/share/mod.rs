@@ -1,14 +1,18 @@
-//! Protocols for moving secrets in and out of a linear secret sharing scheme.
+//! Protocols for moving secrets in and out of a linear secret sharing scheme, and for maintaining
+//! a sharing while it is held.
 //!
 //! These protocols are written generically over [`LinearShare`](crate::ss::LinearShare), so the
 //! same implementation runs over any linear scheme (Shamir, additive, ...). Together they cover
 //! the two interactive ends of a shared computation — everything in between (linear arithmetic on
-//! shares) is local and needs no protocol:
+//! shares) is local and needs no protocol — plus the upkeep of a long-lived sharing:
 //!
 //! - [`deal`](crate::protocol::share::deal): a designated dealer splits a secret and distributes
 //!   one share to each receiver.
 //! - [`open`](crate::protocol::share::open): the parties reveal their shares to reconstruct
-//!   (open) the secret — either to everyone, or towards a single designated party.
+//!   (open) the secret — either to everyone, one value or a batch at a time, or towards a single
+//!   designated party.
+//! - [`refresh`](crate::protocol::share::refresh): the parties re-randomize a sharing in place, so
+//!   that shares leaked in different epochs cannot be combined.
 //!
 //! # Security model: passive adversary
 //!
@@ -24,3 +28,6 @@ pub mod deal;
 
 /// Opening (reconstructing) a shared secret by exchanging shares.
 pub mod open;
+
+/// Proactive refresh of a shared secret with fresh sharings of zero.
+pub mod refresh;
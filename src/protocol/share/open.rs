@@ -79,6 +79,90 @@ where
     }
 }
 
+/// The batched form of [`PassiveOpenShr`]: opens a whole vector of shared secrets in **one
+/// round**, no matter how many there are.
+///
+/// Each party sends all of its shares to every peer in a single packet, then collects one packet
+/// from every other party and reconstructs each secret from the shares at the same position. This
+/// is what keeps protocols that open many masked values at once — Beaver multiplication of a whole
+/// circuit layer, say — from paying a round per value.
+///
+/// # Security model: passive adversary
+///
+/// As for [`PassiveOpenShr`]: every party in the network holds a share of each secret and always
+/// sends them, so waiting for every peer is safe.
+pub struct BatchedPassiveOpenShr<S> {
+    /// The local party's shares, one per secret being opened.
+    my_shares: Vec<S>,
+}
+
+impl<S> BatchedPassiveOpenShr<S>
+where
+    S: LinearShare,
+{
+    /// Creates the protocol instance for the local party holding `my_shares`. The `i`-th output is
+    /// reconstructed from the `i`-th entry of every party's `my_shares`, so every party in the
+    /// network must run this protocol with its shares in the same order and of the same number.
+    pub fn new(my_shares: Vec<S>) -> Self {
+        Self { my_shares }
+    }
+}
+
+impl<S, E> Protocol<E> for BatchedPassiveOpenShr<S>
+where
+    S: LinearShare + Abbreviate,
+    E: Environment,
+    S::Value: Sync + Send + 'static,
+{
+    type Output = Vec<S::Value>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let parties = env.network().party_ids();
+        let n_secrets = self.my_shares.len();
+
+        // Reveal: send all my shares to every other party, in one packet each.
+        let mut messages = Vec::with_capacity(parties.len().saturating_sub(1));
+        for party in parties.iter().filter(|&party| *party != me) {
+            let mut pkt = Packet::empty();
+            pkt.write_many_labeled(&self.my_shares)?;
+            messages.push((*party, pkt));
+        }
+        env.network_mut().send_many(&messages).await?;
+
+        // Collect every party's shares in party order, one packet from each other party, so a
+        // duplicate or unexpected sender cannot stand in for a missing one.
+        let mut my_shares = Some(self.my_shares);
+        let mut shares_per_party = Vec::with_capacity(parties.len());
+        for party in &parties {
+            if *party == me {
+                shares_per_party.extend(my_shares.take());
+                continue;
+            }
+            let pkt = env.network_mut().recv_from(*party).await?;
+            let mut shares = Vec::with_capacity(n_secrets);
+            for i in 0..n_secrets {
+                shares.push(pkt.read(i)?);
+            }
+            shares_per_party.push(shares);
+        }
+
+        let mut secrets = Vec::with_capacity(n_secrets);
+        for i in 0..n_secrets {
+            let shares: Vec<S> = shares_per_party
+                .iter()
+                .map(|shares| shares[i].clone())
+                .collect();
+            secrets.push(S::secret_from_shares(&shares, &parties)?);
+        }
+        Ok(secrets)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("BatchedPassiveOpenLinearShr")
+    }
+}
+
 /// Protocol that opens a shared secret **towards a single party**: every party sends its share to
 /// the designated `receiver`, and only the receiver reconstructs. The receiver's output is
 /// `Some(secret)`; every other party's output is `None`.
@@ -0,0 +1,100 @@
+use crate::{
+    math::ring::Ring,
+    net::{Network, PartyId},
+    prelude::{Abbreviate, Error, Protocol, RandEnvironment},
+    protocol::{share::deal::PassiveDealShr, ProtocolId},
+    ss::LinearShare,
+};
+
+/// Protocol that **proactively refreshes** a shared secret: every party ends with a fresh share of
+/// the *same* secret, independent of the share it held before.
+///
+/// Long-lived sharings (a shared signing key, say) leak over time: an adversary that corrupts
+/// different parties in different periods may eventually hold enough shares to reconstruct. A
+/// refresh defeats this by re-randomizing the sharing at the start of every period ("epoch"), so
+/// that shares taken in different epochs do not combine. Each party deals a sharing of **zero**
+/// with [`PassiveDealShr`] and adds every share of zero it receives to its own share. Since the sum
+/// of sharings of zero is a random sharing of zero, the result is a uniformly fresh sharing of the
+/// old secret; a mix of old and new shares lies on no common sharing and reconstructs garbage.
+///
+/// The refresh is a single round, and every party both deals and receives, so the message pattern
+/// is exactly balanced: each party consumes one share of zero from every other party.
+///
+/// # Preconditions
+///
+/// - Every party in `parties` must hold a share of the same secret and run this protocol with the
+///   same `parties` and the same `threshold`.
+/// - `threshold` must be the one the secret was originally dealt with (the polynomial degree for
+///   Shamir): the shares of zero are added to the old shares, so both must have the same shape.
+///
+/// # Security model: passive adversary
+///
+/// Like the other protocols in [`crate::protocol::share`], this assumes a **passive
+/// (semi-honest) adversary**: every party deals a well-formed sharing of zero. A dealer that shares
+/// a non-zero value silently shifts the secret; detecting that needs verifiable dealing, which is
+/// out of the passive model.
+pub struct PassiveProactiveRefresh<S>
+where
+    S: LinearShare,
+{
+    /// The local party's share from the previous epoch.
+    my_share: S,
+    /// The parties holding the sharing; each deals one sharing of zero.
+    parties: Vec<PartyId>,
+    /// The scheme's reconstruction-threshold parameter (see [`LinearShare::Threshold`]).
+    threshold: S::Threshold,
+}
+
+impl<S> PassiveProactiveRefresh<S>
+where
+    S: LinearShare,
+{
+    /// Creates the protocol instance for the local party, which holds `my_share` of a secret shared
+    /// among `parties` with the given `threshold` (see the preconditions on
+    /// [`PassiveProactiveRefresh`]).
+    pub fn new(my_share: S, parties: Vec<PartyId>, threshold: S::Threshold) -> Self {
+        Self {
+            my_share,
+            parties,
+            threshold,
+        }
+    }
+}
+
+impl<S, E> Protocol<E> for PassiveProactiveRefresh<S>
+where
+    S: LinearShare + Abbreviate,
+    E: RandEnvironment,
+    S::Value: Sync + Send + 'static,
+{
+    type Output = S;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        if !self.parties.contains(&me) {
+            return Err(Error::Input);
+        }
+
+        // Deal my own sharing of zero; dealing to myself hands my share of it straight back.
+        let own_zero: S = PassiveDealShr::dealer(
+            me,
+            <S::Value as Ring>::ZERO,
+            self.parties.clone(),
+            self.threshold,
+        )
+        .execute(env)
+        .await?;
+        let mut refreshed = self.my_share + &own_zero;
+
+        // Absorb the share of zero dealt by every other party.
+        for party in self.parties.iter().filter(|&party| *party != me) {
+            let zero_share: S = env.network_mut().recv_from(*party).await?.pop()?;
+            refreshed = refreshed + &zero_share;
+        }
+        Ok(refreshed)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("PassiveProactiveRefresh")
+    }
+}
@@ -0,0 +1,113 @@
+use crate::{
+    abbreviate::Abbreviate,
+    math::ring::Ring,
+    net::{Network, Packet, PartyId},
+    protocol::{Environment, Error, Protocol, ProtocolId},
+    ss::authenticated::AuthenticatedSS,
+};
+
+use super::preprocessing::InputMask;
+
+/// Secret-shares a batch of private inputs of one party, its **owner**, in **one round**, by
+/// spending one preprocessed [`InputMask`] per input.
+///
+/// For each input `x` the owner knows the mask `r` in the clear and everyone holds `[r]`. The owner
+/// sends `ε = x - r` to every other party, and each party sets `[x] = [r] + ε`, which needs no
+/// further interaction because adding a public constant to an authenticated share is local. Since
+/// `r` is uniform and used once, `ε` reveals nothing about `x`.
+///
+/// A corrupted owner that sends different `ε` to different parties leaves them with shares whose
+/// MACs are inconsistent with any single value; any later opening that depends on the input then
+/// fails the [`MacCheck`](super::mac_check::MacCheck), so the deviation is caught, just later.
+///
+/// # Preconditions
+///
+/// Every party in the network must run this protocol for the same owner, with its shares of the
+/// same masks, in the same order.
+pub struct SpdzInput<F> {
+    /// The party providing the inputs.
+    owner: PartyId,
+    /// The inputs, known only to the owner.
+    values: Option<Vec<F>>,
+    /// The local party's input masks, one per input.
+    masks: Vec<InputMask<F>>,
+}
+
+impl<F: Ring> SpdzInput<F> {
+    /// Creates the protocol for the owner, which inputs `values` spending `masks`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `values` and `masks` differ in length, or if any mask does not
+    /// carry its clear value (that is, it is not a mask owned by the local party).
+    pub fn owner(owner: PartyId, values: Vec<F>, masks: Vec<InputMask<F>>) -> Result<Self, Error> {
+        if values.len() != masks.len() || masks.iter().any(|mask| mask.value().is_none()) {
+            return Err(Error::Input);
+        }
+        Ok(Self {
+            owner,
+            values: Some(values),
+            masks,
+        })
+    }
+
+    /// Creates the protocol for a party receiving shares of `owner`'s inputs, one per mask in
+    /// `masks`.
+    pub fn receiver(owner: PartyId, masks: Vec<InputMask<F>>) -> Self {
+        Self {
+            owner,
+            values: None,
+            masks,
+        }
+    }
+}
+
+impl<F, E> Protocol<E> for SpdzInput<F>
+where
+    F: Ring + Abbreviate + Send + Sync + 'static,
+    E: Environment,
+{
+    /// The local party's shares of the inputs.
+    type Output = Vec<AuthenticatedSS<F>>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let epsilons: Vec<F> = match self.values {
+            Some(values) if me == self.owner => {
+                let epsilons: Vec<F> = values
+                    .iter()
+                    .zip(&self.masks)
+                    .map(|(x, mask)| mask.value().map(|r| *x - r).ok_or(Error::Input))
+                    .collect::<Result<_, _>>()?;
+                let mut pkt = Packet::empty();
+                pkt.write_many_labeled(&epsilons)?;
+                let messages: Vec<_> = env
+                    .network()
+                    .party_ids()
+                    .into_iter()
+                    .filter(|party| *party != me)
+                    .map(|party| (party, pkt.clone()))
+                    .collect();
+                env.network_mut().send_many(&messages).await?;
+                epsilons
+            }
+            _ => {
+                let pkt = env.network_mut().recv_from(self.owner).await?;
+                (0..self.masks.len())
+                    .map(|i| pkt.read(i))
+                    .collect::<Result<_, _>>()?
+            }
+        };
+
+        Ok(self
+            .masks
+            .into_iter()
+            .zip(&epsilons)
+            .map(|(mask, epsilon)| mask.into_parts().0 + epsilon)
+            .collect())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("SpdzInput")
+    }
+}
@@ -0,0 +1,120 @@
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::ring::Ring,
+    protocol::{
+        randomness::coin_toss::{commit_and_sum, CoinToss},
+        Error, Protocol, ProtocolId, RandEnvironment,
+    },
+    ss::authenticated::AuthenticatedSS,
+};
+
+/// The SPDZ MAC check: verifies a batch of opened values against the parties' MAC shares,
+/// aborting with [`Error::MacCheck`] if any of them was tampered with.
+///
+/// For opened values `a_1, …, a_m` with MAC shares `γ_i(a_j)`, the parties first agree on random
+/// coefficients `r_1, …, r_m`, expanded from a [`CoinToss`], and compress the batch into a single
+/// value `a = Σ r_j · a_j` with MAC shares `γ_i = Σ r_j · γ_i(a_j)`. Each party then computes
+/// `σ_i = γ_i - α_i · a`, commits to it, and opens it once every commitment is in; the check passes
+/// iff `Σ σ_i = 0`, which holds exactly when `Σ γ_i = α · a`. An adversary that shifted any opened
+/// value passes only by guessing `α`, or by hitting a zero in the random combination — each with
+/// probability `1 / |F|`.
+///
+/// Committing before opening is what keeps the last party to speak from adjusting its `σ_i` to the
+/// others'; both the coin toss and the `σ_i` use [`HashScheme`](crate::commitment::HashScheme)
+/// commitments bound to the session tag and the committer. A rushing party thus cannot echo
+/// another party's commitment and opening as its own — with two parties, that would cancel the
+/// coin toss and fix the coefficients in advance. A party that sends a commitment equal to another
+/// one, or opens a commitment to a different value, is caught and named in [`Error::Cheater`].
+///
+/// The check costs four rounds (commit and open the coin, commit and open `σ_i`) regardless of the
+/// batch size, so it is meant to run rarely — typically once, before outputs are revealed.
+///
+/// # Preconditions
+///
+/// Every party in the network must run this protocol with the same session tag and the same opened
+/// values in the same order, together with its own share of each. The session tag must be unique
+/// to the invocation, so that no commitment of one check can be replayed in another.
+///
+/// # Security model: active adversary, abort
+///
+/// Secure against an **active** adversary corrupting all but one party, with abort: a tampered
+/// opening is detected, not corrected, and the culprit is not identified. Opened values must not
+/// be relied upon until the check passes.
+pub struct MacCheck<F> {
+    /// The tag that binds the commitments to this invocation.
+    session: Vec<u8>,
+    /// The values opened during the session, as every party saw them.
+    opened: Vec<F>,
+    /// The local party's shares of the opened values, aligned with `opened`.
+    shares: Vec<AuthenticatedSS<F>>,
+}
+
+impl<F: Ring> MacCheck<F> {
+    /// Creates the protocol for the local party, which checks the values in `opened` using its
+    /// shares of them, `shares[j]` being the share that was opened to `opened[j]`, in the
+    /// invocation tagged `session`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `opened` and `shares` differ in length.
+    pub fn new(
+        session: &[u8],
+        opened: Vec<F>,
+        shares: Vec<AuthenticatedSS<F>>,
+    ) -> Result<Self, Error> {
+        if opened.len() != shares.len() {
+            return Err(Error::Input);
+        }
+        Ok(Self {
+            session: session.to_vec(),
+            opened,
+            shares,
+        })
+    }
+}
+
+impl<F, E> Protocol<E> for MacCheck<F>
+where
+    F: Ring + Abbreviate + Send + Sync + 'static,
+    E: RandEnvironment,
+{
+    type Output = ();
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        // Nothing was opened, so nothing can have been tampered with.
+        let Some(first) = self.shares.first() else {
+            return Ok(());
+        };
+        let key_share = *first.mac_key_share();
+
+        // Agree on a random seed for the combination coefficients.
+        let coins = CoinToss::<F>::new(&[&self.session, &b"/coins"[..]].concat(), 1)
+            .execute(env)
+            .await?;
+        let mut coefficients = ChaCha20Rng::from_seed(CoinToss::seed(&coins));
+
+        // Compress the batch, and compute my share of the (zero, if honest) MAC difference.
+        let mut value = F::ZERO;
+        let mut mac = F::ZERO;
+        for (opened, share) in self.opened.iter().zip(&self.shares) {
+            let r = F::random(&mut coefficients);
+            value = value + &(r * opened);
+            mac = mac + &(r * share.mac());
+        }
+        let sigma = mac - &(key_share * &value);
+
+        let total =
+            commit_and_sum(env, &[&self.session, &b"/sigma"[..]].concat(), vec![sigma]).await?;
+        if total[0] != F::ZERO {
+            return Err(Error::MacCheck);
+        }
+        Ok(())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("MacCheck")
+    }
+}
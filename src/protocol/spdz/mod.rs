@@ -0,0 +1,56 @@
+//! Actively secure computation over authenticated additive shares, following SPDZ (Damgård,
+//! Pastro, Smart and Zakarias, *Multiparty Computation from Somewhat Homomorphic Encryption*,
+//! CRYPTO 2012).
+//!
+//! Values are held as [`AuthenticatedSS`](crate::ss::authenticated::AuthenticatedSS) shares, each
+//! carrying a share of a MAC under a global key no party knows. Opening a value reveals only the
+//! value components, and is *unchecked*: a corrupted party may lie about its component. Rather than
+//! checking every opening on the spot, the parties keep the opened values together with their MAC
+//! shares and check a whole batch at once, typically before any output is revealed:
+//!
+//! - [`open`](crate::protocol::spdz::open) —
+//!   [`AuthenticatedOpen`](crate::protocol::spdz::open::AuthenticatedOpen): opens a batch of
+//!   values in one round, without checking them.
+//! - [`mac_check`](crate::protocol::spdz::mac_check) —
+//!   [`MacCheck`](crate::protocol::spdz::mac_check::MacCheck): verifies a batch of opened values
+//!   against their MACs, aborting with [`Error::MacCheck`](crate::protocol::Error::MacCheck) if any
+//!   was tampered with.
+//!
+//! On top of these, the online phase evaluates arithmetic circuits from preprocessed material:
+//!
+//! - [`preprocessing`](crate::protocol::spdz::preprocessing) —
+//!   [`SpdzPreprocessing`](crate::protocol::spdz::preprocessing::SpdzPreprocessing): one party's
+//!   store of authenticated triples and input masks, and
+//!   [`TrustedSpdzPreprocessing`](crate::protocol::spdz::preprocessing::TrustedSpdzPreprocessing),
+//!   a trusted dealer that generates it for simulations.
+//! - [`input`](crate::protocol::spdz::input) —
+//!   [`SpdzInput`](crate::protocol::spdz::input::SpdzInput): shares a party's inputs by opening
+//!   them masked with preprocessed random values.
+//! - [`mul`](crate::protocol::spdz::mul) — [`SpdzMul`](crate::protocol::spdz::mul::SpdzMul): Beaver
+//!   multiplication on authenticated shares, deferring the check of its openings.
+//! - [`output`](crate::protocol::spdz::output) —
+//!   [`SpdzOutput`](crate::protocol::spdz::output::SpdzOutput): MAC-checks everything opened
+//!   during the session, and only then opens the outputs and checks them too.
+//!
+//! Addition, subtraction and multiplication by public constants are local operations on
+//! [`AuthenticatedSS`](crate::ss::authenticated::AuthenticatedSS) and need no protocol.
+//!
+//! # Security model: active adversary, abort
+//!
+//! The protocols tolerate an **active (malicious) adversary** corrupting all but one party, with
+//! *security with abort*: a deviation is detected with overwhelming probability (in the size of
+//! the field) and ends the session with an error, but is not prevented. They assume every party
+//! eventually sends its messages; receive timeouts are the caller's concern.
+
+/// Input of private values through preprocessed masks.
+pub mod input;
+/// Batched MAC check of opened values, with commitments.
+pub mod mac_check;
+/// Beaver multiplication of authenticated shares.
+pub mod mul;
+/// Unchecked opening of authenticated shares, and the log of openings awaiting a check.
+pub mod open;
+/// Checked output of authenticated shares.
+pub mod output;
+/// Preprocessed material for the online phase, and a trusted dealer generating it.
+pub mod preprocessing;
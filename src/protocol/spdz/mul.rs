@@ -0,0 +1,101 @@
+use crate::{
+    abbreviate::Abbreviate,
+    math::ring::Ring,
+    protocol::{Environment, Error, Protocol, ProtocolId},
+    ss::authenticated::AuthenticatedSS,
+};
+
+use super::{
+    open::{AuthenticatedOpen, OpeningLog},
+    preprocessing::SpdzTriple,
+};
+
+/// Multiplies a batch of pairs of authenticated shares in **one round**, spending one
+/// [`SpdzTriple`] per pair (Beaver's technique).
+///
+/// For each pair `([x], [y])` and triple `([a], [b], [c = a · b])`, the parties open
+/// `d = x - a` and `e = y - b` — all pairs in a single [`AuthenticatedOpen`] — and set
+/// `[x · y] = [c] + d · [b] + e · [a] + d · e`. The openings are **not checked** here: they are
+/// returned in an [`OpeningLog`], which the caller must eventually pass to
+/// [`SpdzOutput`](super::output::SpdzOutput) (or [`MacCheck`](super::mac_check::MacCheck)), so
+/// that a whole circuit pays for a single check.
+///
+/// # Preconditions
+///
+/// Every party in the network must run this protocol with its shares of the same pairs and of the
+/// same triples, in the same order.
+pub struct SpdzMul<F> {
+    x: Vec<AuthenticatedSS<F>>,
+    y: Vec<AuthenticatedSS<F>>,
+    triples: Vec<SpdzTriple<F>>,
+}
+
+impl<F: Ring> SpdzMul<F> {
+    /// Creates the protocol for the local party, which multiplies `x[i]` by `y[i]` spending
+    /// `triples[i]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `x`, `y` and `triples` do not all have the same length.
+    pub fn new(
+        x: Vec<AuthenticatedSS<F>>,
+        y: Vec<AuthenticatedSS<F>>,
+        triples: Vec<SpdzTriple<F>>,
+    ) -> Result<Self, Error> {
+        if x.len() != y.len() || x.len() != triples.len() {
+            return Err(Error::Input);
+        }
+        Ok(Self { x, y, triples })
+    }
+}
+
+impl<F, E> Protocol<E> for SpdzMul<F>
+where
+    F: Ring + Abbreviate + Send + Sync + 'static,
+    E: Environment,
+{
+    /// The local party's shares of the products, and the log of the openings they took.
+    type Output = (Vec<AuthenticatedSS<F>>, OpeningLog<F>);
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let n = self.x.len();
+        let (a, b, c): (Vec<_>, Vec<_>, Vec<_>) = {
+            let mut a = Vec::with_capacity(n);
+            let mut b = Vec::with_capacity(n);
+            let mut c = Vec::with_capacity(n);
+            for triple in self.triples {
+                let (a_i, b_i, c_i) = triple.into_parts();
+                a.push(a_i);
+                b.push(b_i);
+                c.push(c_i);
+            }
+            (a, b, c)
+        };
+
+        // Mask both factors, `d` and `e` laid out back to back, and open them together.
+        let masked: Vec<AuthenticatedSS<F>> = self
+            .x
+            .into_iter()
+            .zip(&a)
+            .chain(self.y.into_iter().zip(&b))
+            .map(|(factor, mask)| factor - mask)
+            .collect();
+        let opened = AuthenticatedOpen::new(masked.clone()).execute(env).await?;
+        let (d, e) = opened.split_at(n);
+
+        let products = c
+            .into_iter()
+            .zip(a.into_iter().zip(b))
+            .zip(d.iter().zip(e))
+            .map(|((c, (a, b)), (d, e))| c + &(b * d) + &(a * e) + &(*d * e))
+            .collect();
+
+        let mut log = OpeningLog::new();
+        log.record(opened, masked)?;
+        Ok((products, log))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("SpdzMul")
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    abbreviate::Abbreviate,
+    math::ring::Ring,
+    net::{Network, Packet},
+    protocol::{Environment, Error, Protocol, ProtocolId},
+    ss::authenticated::AuthenticatedSS,
+};
+
+/// Opens a batch of authenticated shares in **one round**, revealing the values but **not checking
+/// them**.
+///
+/// Each party sends the value components of its shares — never the MAC shares or its key share —
+/// to every other party, and sums the components it receives. A corrupted party can make the
+/// result wrong by lying about its component; the parties must therefore keep every opened value
+/// with their share of it and pass them to [`MacCheck`](super::mac_check::MacCheck) before relying
+/// on the result (in particular, before revealing any output that depends on it).
+///
+/// # Preconditions
+///
+/// Every party in the network must run this protocol with its shares of the same values, in the
+/// same order.
+pub struct AuthenticatedOpen<F> {
+    /// The local party's shares, one per value being opened.
+    shares: Vec<AuthenticatedSS<F>>,
+}
+
+impl<F: Ring> AuthenticatedOpen<F> {
+    /// Creates the protocol instance for the local party holding `shares`.
+    pub fn new(shares: Vec<AuthenticatedSS<F>>) -> Self {
+        Self { shares }
+    }
+}
+
+impl<F, E> Protocol<E> for AuthenticatedOpen<F>
+where
+    F: Ring + Abbreviate + Send + Sync + 'static,
+    E: Environment,
+{
+    /// The opened values, unchecked.
+    type Output = Vec<F>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let parties = env.network().party_ids();
+        let mut values: Vec<F> = self.shares.iter().map(|share| *share.share()).collect();
+
+        let mut pkt = Packet::empty();
+        pkt.write_many_labeled(&values)?;
+        let messages: Vec<_> = parties
+            .iter()
+            .filter(|&party| *party != me)
+            .map(|party| (*party, pkt.clone()))
+            .collect();
+        env.network_mut().send_many(&messages).await?;
+
+        for party in parties.iter().filter(|&party| *party != me) {
+            let pkt = env.network_mut().recv_from(*party).await?;
+            for (i, value) in values.iter_mut().enumerate() {
+                *value = *value + &pkt.read::<F>(i)?;
+            }
+        }
+        Ok(values)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("AuthenticatedOpen")
+    }
+}
+
+/// The values opened during a SPDZ session, each with the local party's share of it, kept until
+/// they are checked by [`MacCheck`](super::mac_check::MacCheck).
+///
+/// Protocols that open values internally, such as [`SpdzMul`](super::mul::SpdzMul), hand back a
+/// log of their openings; the caller accumulates them and passes the whole log to
+/// [`SpdzOutput`](super::output::SpdzOutput), which checks it before revealing anything.
+pub struct OpeningLog<F> {
+    opened: Vec<F>,
+    shares: Vec<AuthenticatedSS<F>>,
+}
+
+impl<F> OpeningLog<F> {
+    /// Creates an empty log.
+    pub fn new() -> Self {
+        Self {
+            opened: Vec::new(),
+            shares: Vec::new(),
+        }
+    }
+
+    /// Records the openings of `shares` to `opened`, aligned one to one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `opened` and `shares` differ in length.
+    pub fn record(&mut self, opened: Vec<F>, shares: Vec<AuthenticatedSS<F>>) -> Result<(), Error> {
+        if opened.len() != shares.len() {
+            return Err(Error::Input);
+        }
+        self.opened.extend(opened);
+        self.shares.extend(shares);
+        Ok(())
+    }
+
+    /// Appends every opening recorded in `other`.
+    pub fn append(&mut self, other: OpeningLog<F>) {
+        self.opened.extend(other.opened);
+        self.shares.extend(other.shares);
+    }
+
+    /// Returns the number of recorded openings.
+    pub fn len(&self) -> usize {
+        self.opened.len()
+    }
+
+    /// Returns `true` if nothing was opened.
+    pub fn is_empty(&self) -> bool {
+        self.opened.is_empty()
+    }
+
+    /// Splits the log into the opened values and the local shares they were opened from.
+    pub fn into_parts(self) -> (Vec<F>, Vec<AuthenticatedSS<F>>) {
+        (self.opened, self.shares)
+    }
+}
+
+impl<F> Default for OpeningLog<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
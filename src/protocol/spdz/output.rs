@@ -0,0 +1,76 @@
+use crate::{
+    abbreviate::Abbreviate,
+    math::ring::Ring,
+    protocol::{Error, Protocol, ProtocolId, RandEnvironment},
+    ss::authenticated::AuthenticatedSS,
+};
+
+use super::{
+    mac_check::MacCheck,
+    open::{AuthenticatedOpen, OpeningLog},
+};
+
+/// Reveals a batch of outputs, after checking **every** value opened during the session.
+///
+/// The parties first run a [`MacCheck`] over the session's [`OpeningLog`] — the intermediate
+/// openings of every [`SpdzMul`](super::mul::SpdzMul) included — and only if it passes open the
+/// outputs, which a second [`MacCheck`] then verifies. The outputs are returned only if both checks
+/// pass; otherwise every honest party aborts with [`Error::MacCheck`]. Checking the log first
+/// matters: an output computed after a tampered opening may leak more than the output itself, so
+/// it must never be opened. A tampered triple leaves the log intact and is caught by the second
+/// check.
+///
+/// # Preconditions
+///
+/// Every party in the network must run this protocol with the same session tag, unique to the
+/// invocation, and with its shares of the same outputs and a log of the same openings, in the same
+/// order.
+pub struct SpdzOutput<F> {
+    session: Vec<u8>,
+    shares: Vec<AuthenticatedSS<F>>,
+    log: OpeningLog<F>,
+}
+
+impl<F: Ring> SpdzOutput<F> {
+    /// Creates the protocol for the local party, which checks every opening recorded in `log` and
+    /// then reveals `shares`, tagging the checks' commitments with `session` (see [`MacCheck`]).
+    pub fn new(session: &[u8], shares: Vec<AuthenticatedSS<F>>, log: OpeningLog<F>) -> Self {
+        Self {
+            session: session.to_vec(),
+            shares,
+            log,
+        }
+    }
+}
+
+impl<F, E> Protocol<E> for SpdzOutput<F>
+where
+    F: Ring + Abbreviate + Send + Sync + 'static,
+    E: RandEnvironment,
+{
+    /// The revealed outputs, checked.
+    type Output = Vec<F>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let (opened, shares) = self.log.into_parts();
+        MacCheck::new(&[&self.session, &b"/log"[..]].concat(), opened, shares)?
+            .execute(env)
+            .await?;
+
+        let outputs = AuthenticatedOpen::new(self.shares.clone())
+            .execute(env)
+            .await?;
+        MacCheck::new(
+            &[&self.session, &b"/outputs"[..]].concat(),
+            outputs.clone(),
+            self.shares,
+        )?
+        .execute(env)
+        .await?;
+        Ok(outputs)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("SpdzOutput")
+    }
+}
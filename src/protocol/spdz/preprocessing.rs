@@ -0,0 +1,99 @@
+use std::marker::PhantomData;
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::ring::Ring,
+    net::{Network, PartyId},
+    protocol::{
+        dealer::{self, BeaverTriple, DealerRequest, DealtPreprocessing, TrustedDealer},
+        Error, Protocol, ProtocolId, RandEnvironment,
+    },
+    ss::authenticated::{AuthenticatedSS, MacKey},
+};
+
+/// An authenticated multiplication triple: sharings of random `a`, `b` and of `a · b`.
+pub type SpdzTriple<F> = BeaverTriple<AuthenticatedSS<F>>;
+
+/// An input mask: an authenticated sharing of a random `r` that one party, its **owner**, knows in
+/// the clear. The owner spends it to input one value (see [`SpdzInput`](super::input::SpdzInput)).
+pub type InputMask<F> = dealer::InputMask<AuthenticatedSS<F>, F>;
+
+/// One party's preprocessed material for the SPDZ online phase: multiplication triples, and input
+/// masks for every party that provides inputs.
+///
+/// Each item is single-use: spending a triple or a mask twice breaks privacy. The `take_*` methods
+/// therefore hand items out by value and remove them from the store.
+pub type SpdzPreprocessing<F> = DealtPreprocessing<AuthenticatedSS<F>>;
+
+/// Protocol in which a **trusted dealer** generates the SPDZ preprocessing for a simulated
+/// session: a fresh MAC key, `n_triples` authenticated triples, and `masks_per_party` input masks
+/// for every party, all delivered in one round.
+///
+/// The dealer samples the MAC key with [`MacKey::random`] and deals everything under it with a
+/// [`TrustedDealer`] over [`AuthenticatedSS`]: each party receives its shares in a single packet,
+/// together with the clear value of the masks it owns. The dealer may be one of `parties` or a
+/// separate party, whose output is then empty.
+///
+/// # Trust model: trusted dealer
+///
+/// The dealer knows the MAC key and every preprocessed value, so it must not collude with any
+/// computing party. It stands in for SPDZ's real offline phase (somewhat homomorphic encryption or
+/// oblivious transfer), which is what removes this assumption in production.
+pub struct TrustedSpdzPreprocessing<F> {
+    dealer: PartyId,
+    parties: Vec<PartyId>,
+    n_triples: usize,
+    masks_per_party: usize,
+    field: PhantomData<F>,
+}
+
+impl<F> TrustedSpdzPreprocessing<F> {
+    /// Creates the protocol for the local party. The `dealer` and every party in `parties` must run
+    /// it with the same arguments; each party in `parties` outputs its [`SpdzPreprocessing`].
+    pub fn new(
+        dealer: PartyId,
+        parties: Vec<PartyId>,
+        n_triples: usize,
+        masks_per_party: usize,
+    ) -> Self {
+        Self {
+            dealer,
+            parties,
+            n_triples,
+            masks_per_party,
+            field: PhantomData,
+        }
+    }
+}
+
+impl<F, E> Protocol<E> for TrustedSpdzPreprocessing<F>
+where
+    F: Ring + Abbreviate + Send + Sync + 'static,
+    E: RandEnvironment,
+{
+    type Output = SpdzPreprocessing<F>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        if me != self.dealer && !self.parties.contains(&me) {
+            return Err(Error::Input);
+        }
+
+        let request = DealerRequest {
+            triples: self.n_triples,
+            input_masks: self.masks_per_party,
+            ..DealerRequest::default()
+        };
+        let dealer = if me == self.dealer {
+            let key = MacKey::<F>::random(self.parties.len(), env.rng_mut());
+            TrustedDealer::dealer(self.dealer, self.parties, key.clone(), key, request)
+        } else {
+            TrustedDealer::receiver(self.dealer, request)
+        };
+        dealer.execute(env).await
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("TrustedSpdzPreprocessing")
+    }
+}
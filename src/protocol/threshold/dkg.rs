@@ -0,0 +1,364 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{ec::EllipticCurve, ring::Ring},
+    net::{Network, Packet, PartyId},
+    protocol::{Error, Protocol, ProtocolId, RandEnvironment},
+    ss::{exponent::ExponentSS, feldman::FeldmanSS, shamir::ShamirSS},
+};
+
+/// Distributed generation of a Shamir-shared secret key over an elliptic curve, following the
+/// Feldman-VSS based DKG of Pedersen (*A Threshold Cryptosystem without a Trusted Party*,
+/// EUROCRYPT 1991), with the complaint handling of Gennaro, Jarecki, Krawczyk and Rabin.
+///
+/// Every party `i` samples a random scalar `a_i` and deals it with [`FeldmanSS`] at degree `t`:
+/// party `j` receives `f_i(j)` together with the commitments `C_{i,k} = a_{i,k} · G` to the
+/// coefficients of `f_i`. The protocol then runs in up to three rounds:
+///
+/// 1. **Deal.** Each party sends every other party its share and the commitments.
+/// 2. **Complain.** Each party checks the shares it received against their commitments and sends
+///    everyone the list of dealers whose share did not verify. A list that does not parse counts
+///    as no complaints.
+/// 3. **Answer.** A dealer accused by at most `t` parties reveals the accusers' shares to everyone.
+///    This round is skipped when no one complained.
+///
+/// A dealer is disqualified if its commitments do not parse, if more than `t` parties accuse it, or
+/// if any share it reveals does not match its commitments. The remaining dealers form the qualified
+/// set `QUAL`; the secret key is `x = Σ_{i ∈ QUAL} a_i`, party `j` outputs the share
+/// `Σ_{i ∈ QUAL} f_i(j)` (taking the revealed share in place of one it complained about), and the
+/// public key is `x · G = Σ_{i ∈ QUAL} C_{i,0}`. No party ever learns `x`.
+///
+/// # Preconditions
+///
+/// Every party in `parties` must run this protocol with the same party list and threshold.
+///
+/// # Security model: active adversary, broadcast channel
+///
+/// The protocol tolerates up to `t` **actively** corrupted parties when `n >= 2t + 1`: a cheating
+/// dealer is either disqualified or forced to reveal shares that verify, so every honest party ends
+/// up with a share of the same key. It assumes that the commitments and the complaints reach every
+/// party identically — a broadcast channel. Over a point-to-point network a dealer could send
+/// different commitments to different parties, so the caller must provide consistent broadcast (for
+/// example by echoing the messages) when that matters. As in Pedersen's original protocol, a rushing
+/// adversary can bias the distribution of the public key, which is harmless for threshold Schnorr
+/// signatures and ElGamal decryption. Every party is assumed to eventually send its messages;
+/// receive timeouts are the caller's concern.
+pub struct FeldmanDkg<const LIMBS: usize, C> {
+    parties: Vec<PartyId>,
+    threshold: usize,
+    curve: std::marker::PhantomData<C>,
+}
+
+impl<const LIMBS: usize, C> FeldmanDkg<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    /// Creates the protocol for a run among `parties` that outputs a degree-`threshold` sharing of
+    /// the key: any `threshold + 1` parties can use it, and `threshold` of them learn nothing.
+    ///
+    /// The parties are held sorted, so the output lists them in the same order at every party.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `parties` lists a party twice, or if `2 · threshold + 1` exceeds
+    /// the number of parties, so the honest parties could not outvote the corrupted ones.
+    pub fn new(mut parties: Vec<PartyId>, threshold: usize) -> Result<Self, Error> {
+        let n = parties.len();
+        parties.sort();
+        parties.dedup();
+        if parties.len() != n || 2 * threshold + 1 > n {
+            return Err(Error::Input);
+        }
+        Ok(Self {
+            parties,
+            threshold,
+            curve: std::marker::PhantomData,
+        })
+    }
+}
+
+/// One party's result of a [`FeldmanDkg`] run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct DkgOutput<const LIMBS: usize, C: EllipticCurve<LIMBS>> {
+    /// The local party's share of the secret key.
+    key_share: ShamirSS<LIMBS, C::ScalarField>,
+    /// The joint public key `x · G`.
+    public_key: C,
+    /// `verification_keys[j]` is `x_j · G` for the key share `x_j` of `parties[j]`.
+    verification_keys: Vec<C>,
+    /// Every party of the run, sorted.
+    parties: Vec<PartyId>,
+    /// The dealers whose sharings make up the key, sorted.
+    qualified: Vec<PartyId>,
+}
+
+impl<const LIMBS: usize, C> DkgOutput<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    /// Returns the local party's share of the secret key.
+    pub fn key_share(&self) -> &ShamirSS<LIMBS, C::ScalarField> {
+        &self.key_share
+    }
+
+    /// Returns the joint public key `x · G`.
+    pub fn public_key(&self) -> &C {
+        &self.public_key
+    }
+
+    /// Returns the public verification key `x_j · G` of `party`'s key share, or `None` if `party`
+    /// did not take part in the run.
+    ///
+    /// Anyone can check a value computed from a key share against it — a partial signature or a
+    /// decryption share — without learning the share.
+    pub fn verification_key(&self, party: PartyId) -> Option<&C> {
+        self.parties
+            .binary_search(&party)
+            .ok()
+            .map(|idx| &self.verification_keys[idx])
+    }
+
+    /// Returns every party of the run, sorted.
+    pub fn parties(&self) -> &[PartyId] {
+        &self.parties
+    }
+
+    /// Returns the dealers whose sharings make up the key, sorted. Every other party was
+    /// disqualified for dealing inconsistent shares.
+    pub fn qualified(&self) -> &[PartyId] {
+        &self.qualified
+    }
+}
+
+impl<const LIMBS: usize, C> FeldmanDkg<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+    C::ScalarField: From<u64> + Send + Sync,
+{
+    /// Checks `share` as the evaluation at `owner` of the degree-`t` polynomial committed to by
+    /// `commitments`.
+    fn verifies(
+        &self,
+        share: &ShamirSS<LIMBS, C::ScalarField>,
+        commitments: &[C],
+        owner: PartyId,
+    ) -> bool {
+        share.degree() == self.threshold
+            && FeldmanSS::new(share.clone(), commitments.to_vec())
+                .is_valid(ExponentSS::<LIMBS, C>::encode_party(owner))
+    }
+}
+
+impl<const LIMBS: usize, C, E> Protocol<E> for FeldmanDkg<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS> + Abbreviate + Send + Sync + 'static,
+    C::ScalarField: From<u64> + Send + Sync + 'static,
+    E: RandEnvironment,
+{
+    type Output = DkgOutput<LIMBS, C>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let n = self.parties.len();
+        let t = self.threshold;
+        let my_idx = self.parties.binary_search(&me).map_err(|_| Error::Input)?;
+        let points: Vec<C::ScalarField> = self
+            .parties
+            .iter()
+            .copied()
+            .map(ExponentSS::<LIMBS, C>::encode_party)
+            .collect();
+
+        // Round 1: deal a Feldman sharing of a random scalar.
+        let secret = C::ScalarField::random(env.rng_mut());
+        let dealt: Vec<FeldmanSS<LIMBS, C>> =
+            FeldmanSS::shares_from_secret(secret, t, &points, env.rng_mut());
+        let my_commitments = dealt[my_idx].commitments().to_vec();
+        let my_shares: Vec<ShamirSS<LIMBS, C::ScalarField>> = dealt
+            .into_iter()
+            .map(|share| share.shamir_share().clone())
+            .collect();
+
+        let mut messages = Vec::with_capacity(n - 1);
+        for (party, share) in self.parties.iter().zip(&my_shares) {
+            if *party == me {
+                continue;
+            }
+            let mut pkt = Packet::empty();
+            pkt.write_labeled(share)?;
+            pkt.write_many_labeled(&my_commitments)?;
+            messages.push((*party, pkt));
+        }
+        env.network_mut().send_many(&messages).await?;
+
+        // `shares[i]` and `commitments[i]` are what dealer `parties[i]` sent us. A share that does
+        // not parse counts as one that does not verify, so the dealer is accused rather than the
+        // run aborted. Commitments that do not parse reject the dealer outright: they reach every
+        // party identically, so every honest party rejects it.
+        let mut shares: Vec<Option<ShamirSS<LIMBS, C::ScalarField>>> = Vec::with_capacity(n);
+        let mut commitments: Vec<Option<Vec<C>>> = Vec::with_capacity(n);
+        for party in &self.parties {
+            if *party == me {
+                shares.push(Some(my_shares[my_idx].clone()));
+                commitments.push(Some(my_commitments.clone()));
+                continue;
+            }
+            let pkt = env.network_mut().recv_from(*party).await?;
+            shares.push(pkt.read(0).ok());
+            commitments.push(
+                (1..=t + 1)
+                    .map(|k| pkt.read(k))
+                    .collect::<Result<_, _>>()
+                    .ok(),
+            );
+        }
+
+        // Round 2: accuse every dealer with well-formed commitments whose share does not match
+        // them.
+        let my_complaints: Vec<PartyId> = self
+            .parties
+            .iter()
+            .zip(shares.iter().zip(&commitments))
+            .filter(|(_, (share, commitments))| match (share, commitments) {
+                (Some(share), Some(commitments)) => !self.verifies(share, commitments, me),
+                (None, Some(_)) => true,
+                (_, None) => false,
+            })
+            .map(|(dealer, _)| *dealer)
+            .collect();
+
+        let mut pkt = Packet::empty();
+        pkt.write(&my_complaints)?;
+        let messages: Vec<_> = self
+            .parties
+            .iter()
+            .filter(|party| **party != me)
+            .map(|party| (*party, pkt.clone()))
+            .collect();
+        env.network_mut().send_many(&messages).await?;
+
+        // `accusers[i]` lists, in party order, the parties that accused dealer `parties[i]`.
+        let mut accusers: Vec<Vec<PartyId>> = vec![Vec::new(); n];
+        for party in &self.parties {
+            let complaints: Vec<PartyId> = if *party == me {
+                my_complaints.clone()
+            } else {
+                // A complaint list that does not parse reaches every party identically, so every
+                // honest party reads it as no complaints rather than letting the sender abort.
+                let pkt = env.network_mut().recv_from(*party).await?;
+                pkt.read(0).unwrap_or_default()
+            };
+            for dealer in complaints {
+                if let Ok(idx) = self.parties.binary_search(&dealer) {
+                    if !accusers[idx].contains(party) {
+                        accusers[idx].push(*party);
+                    }
+                }
+            }
+        }
+        for list in &mut accusers {
+            list.sort();
+        }
+
+        // More than `t` accusers include an honest one, so the dealer cheated for sure.
+        let mut qualified: Vec<bool> = accusers
+            .iter()
+            .zip(&commitments)
+            .map(|(list, commitments)| commitments.is_some() && list.len() <= t)
+            .collect();
+        let answering: Vec<usize> = (0..n)
+            .filter(|&i| qualified[i] && !accusers[i].is_empty())
+            .collect();
+
+        // Round 3: accused dealers reveal the shares of their accusers.
+        if answering.contains(&my_idx) {
+            let revealed: Vec<_> = accusers[my_idx]
+                .iter()
+                .map(|accuser| {
+                    let idx = self
+                        .parties
+                        .binary_search(accuser)
+                        .expect("accusers are taken from the party list");
+                    my_shares[idx].clone()
+                })
+                .collect();
+            let mut pkt = Packet::empty();
+            pkt.write_many_labeled(&revealed)?;
+            let messages: Vec<_> = self
+                .parties
+                .iter()
+                .filter(|party| **party != me)
+                .map(|party| (*party, pkt.clone()))
+                .collect();
+            env.network_mut().send_many(&messages).await?;
+        }
+        for &dealer_idx in &answering {
+            let dealer = self.parties[dealer_idx];
+            if dealer == me {
+                continue;
+            }
+            let pkt = env.network_mut().recv_from(dealer).await?;
+            for (k, accuser) in accusers[dealer_idx].iter().enumerate() {
+                let revealed: Option<ShamirSS<LIMBS, C::ScalarField>> = pkt.read(k).ok();
+                let dealer_commitments = commitments[dealer_idx].as_deref().unwrap_or_default();
+                match revealed {
+                    Some(revealed) if self.verifies(&revealed, dealer_commitments, *accuser) => {
+                        if *accuser == me {
+                            shares[dealer_idx] = Some(revealed);
+                        }
+                    }
+                    _ => qualified[dealer_idx] = false,
+                }
+            }
+        }
+
+        // Sum the qualified sharings: the key share, and the commitments to the key polynomial,
+        // from which the public key and every party's verification key follow.
+        let mut key_share = ShamirSS::new(C::ScalarField::ZERO, t);
+        let mut key_commitments = vec![C::ZERO; t + 1];
+        for (i, (share, dealer_commitments)) in shares.iter().zip(&commitments).enumerate() {
+            if !qualified[i] {
+                continue;
+            }
+            let share = share.as_ref().ok_or(Error::Cheater(self.parties[i]))?;
+            let dealer_commitments = dealer_commitments
+                .as_ref()
+                .ok_or(Error::Cheater(self.parties[i]))?;
+            key_share = key_share + share;
+            for (sum, commitment) in key_commitments.iter_mut().zip(dealer_commitments) {
+                *sum = sum.add(commitment);
+            }
+        }
+        let verification_keys = points
+            .iter()
+            .map(|point| {
+                key_commitments
+                    .iter()
+                    .rev()
+                    .fold(C::ZERO, |acc, commitment| {
+                        acc.scalar_mul(point).add(commitment)
+                    })
+            })
+            .collect();
+
+        Ok(DkgOutput {
+            key_share,
+            public_key: key_commitments[0],
+            verification_keys,
+            qualified: self
+                .parties
+                .iter()
+                .zip(&qualified)
+                .filter(|(_, ok)| **ok)
+                .map(|(party, _)| *party)
+                .collect(),
+            parties: self.parties,
+        })
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("FeldmanDkg")
+    }
+}
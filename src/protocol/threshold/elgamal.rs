@@ -0,0 +1,315 @@
+use rand::CryptoRng;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{ec::EllipticCurve, ring::Ring},
+    net::{Network, Packet, PartyId},
+    protocol::{threshold::dkg::DkgOutput, Error, Protocol, ProtocolId, RandEnvironment},
+    sigma::{dleq::Dleq, fiat_shamir::NonInteractiveProof},
+    ss::{exponent::ExponentSS, shamir::ShamirSS},
+    transcript::{ChallengeField, Transcript},
+};
+
+/// An ElGamal ciphertext `(C_1, C_2) = (r · G, M + r · Y)` of a point `M` under the public key
+/// `Y = x · G`.
+///
+/// Anyone can encrypt to the public key of a [`FeldmanDkg`](super::dkg::FeldmanDkg) run; the
+/// holders of the key shares decrypt together with [`ThresholdDecrypt`], and no single party can
+/// decrypt alone. To encrypt a scalar `m`, encrypt `m · G`; recovering `m` from the decrypted point
+/// then requires `m` to come from a small, searchable range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ElGamalCiphertext<const LIMBS: usize, C: EllipticCurve<LIMBS>> {
+    /// The ephemeral key `r · G`.
+    c1: C,
+    /// The masked message `M + r · Y`.
+    c2: C,
+}
+
+impl<const LIMBS: usize, C> Abbreviate for ElGamalCiphertext<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    const ABBREVIATION: &'static str = "ElGamal ctxt.";
+}
+
+impl<const LIMBS: usize, C> ElGamalCiphertext<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    /// Encrypts the point `message` under `public_key`, with fresh randomness from `rng`.
+    pub fn encrypt(public_key: &C, message: &C, rng: &mut impl CryptoRng) -> Self {
+        let r = C::ScalarField::random_non_zero(rng);
+        Self {
+            c1: C::gen().scalar_mul(&r),
+            c2: message.add(&public_key.scalar_mul(&r)),
+        }
+    }
+
+    /// Decrypts with the whole secret key `x`: `M = C_2 - x · C_1`. Only meaningful where a single
+    /// party holds the key; a shared key decrypts with [`ThresholdDecrypt`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `C_1` or `C_2` is not on the curve.
+    pub fn decrypt(&self, secret_key: &C::ScalarField) -> Result<C, Error> {
+        if !self.is_on_curve() {
+            return Err(Error::Input);
+        }
+        Ok(self.c2.sub(&self.c1.scalar_mul(secret_key)))
+    }
+
+    /// Returns whether both `C_1` and `C_2` are on the curve. A ciphertext from an untrusted source
+    /// may not be; multiplying an off-curve `C_1` by a key share would leak information about it.
+    pub fn is_on_curve(&self) -> bool {
+        self.c1.is_on_curve() && self.c2.is_on_curve()
+    }
+
+    /// Returns the ephemeral key `C_1 = r · G`.
+    pub fn c1(&self) -> &C {
+        &self.c1
+    }
+
+    /// Returns the masked message `C_2 = M + r · Y`.
+    pub fn c2(&self) -> &C {
+        &self.c2
+    }
+}
+
+/// A non-interactive Chaum–Pedersen proof that two points have the same discrete logarithm:
+/// `X = x · G` and `D = x · H` for some `x` the prover knows, without revealing `x`.
+///
+/// This is the [`Dleq`] Σ-protocol made non-interactive by the Fiat–Shamir transform, as a
+/// [`NonInteractiveProof`]: the challenge is squeezed with
+/// [`Transcript::challenge_field`] from a transcript of the statement and the commitments, in the
+/// domain `scl-rs/chaum-pedersen`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct ChaumPedersenProof<const LIMBS: usize, C: EllipticCurve<LIMBS>>(
+    NonInteractiveProof<(C, C), C::ScalarField>,
+);
+
+impl<const LIMBS: usize, C> Abbreviate for ChaumPedersenProof<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    const ABBREVIATION: &'static str = "CP proof";
+}
+
+impl<const LIMBS: usize, C> ChaumPedersenProof<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS> + Abbreviate,
+    C::ScalarField: ChallengeField,
+{
+    /// Proves that `x · g` and `x · h` share the discrete logarithm `x`.
+    pub fn prove(x: &C::ScalarField, g: &C, h: &C, rng: &mut impl CryptoRng) -> Self {
+        let statement = Dleq::new(*g, *h, g.scalar_mul(x), h.scalar_mul(x));
+        Self(NonInteractiveProof::prove(
+            &statement,
+            x,
+            &mut Self::transcript(),
+            rng,
+        ))
+    }
+
+    /// Verifies the proof that `x = log_g(big_x) = log_h(big_d)`.
+    pub fn verify(&self, g: &C, h: &C, big_x: &C, big_d: &C) -> bool {
+        self.0
+            .verify(&Dleq::new(*g, *h, *big_x, *big_d), &mut Self::transcript())
+    }
+
+    /// The transcript every proof starts from.
+    fn transcript() -> Transcript {
+        Transcript::new(b"scl-rs/chaum-pedersen")
+    }
+}
+
+/// Threshold ElGamal decryption: the holders of a Shamir-shared secret key `x` decrypt a batch of
+/// [`ElGamalCiphertext`]s in **one round**, without reconstructing `x`.
+///
+/// Each party publishes its decryption shares `x_i · C_1` — shares in the exponent of `x · C_1`,
+/// see [`ExponentSS`] — together with a [`ChaumPedersenProof`] that each was computed with the
+/// same `x_i` as its verification key `Y_i = x_i · G`. Every party checks the proofs, combines the
+/// valid shares by Lagrange interpolation in the exponent into `x · C_1`, and outputs
+/// `M = C_2 - x · C_1`.
+///
+/// # Preconditions
+///
+/// Every party in `parties` must run this protocol with the same ciphertexts, in the same order,
+/// and with its share of the same key. `verification_keys[j]` must be `x_j · G` for the share of
+/// `parties[j]`, as output by [`FeldmanDkg`](super::dkg::FeldmanDkg).
+///
+/// # Security model: active adversary
+///
+/// The decryption is **robust**: a party whose share is off the curve or whose proof does not
+/// verify is left out, and decryption goes ahead with the others as long as `t + 1` valid shares
+/// remain. With `n >= 2t + 1` and at most `t` corrupted parties this always succeeds. The
+/// plaintexts are revealed to every party. Every party is assumed to eventually send its messages;
+/// receive timeouts are the caller's concern.
+pub struct ThresholdDecrypt<const LIMBS: usize, C: EllipticCurve<LIMBS>> {
+    key_share: ShamirSS<LIMBS, C::ScalarField>,
+    verification_keys: Vec<C>,
+    parties: Vec<PartyId>,
+    ciphertexts: Vec<ElGamalCiphertext<LIMBS, C>>,
+}
+
+impl<const LIMBS: usize, C> ThresholdDecrypt<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    /// Creates the protocol for the local party, which decrypts `ciphertexts` with its
+    /// `key_share` together with the other parties in `parties`; `verification_keys[j]` is the
+    /// verification key of `parties[j]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `parties` and `verification_keys` differ in length, if there
+    /// are fewer than `t + 1` parties for a key shared at degree `t`, or if a verification key or
+    /// a ciphertext point is not on the curve.
+    pub fn new(
+        key_share: ShamirSS<LIMBS, C::ScalarField>,
+        verification_keys: Vec<C>,
+        parties: Vec<PartyId>,
+        ciphertexts: Vec<ElGamalCiphertext<LIMBS, C>>,
+    ) -> Result<Self, Error> {
+        if parties.len() != verification_keys.len()
+            || parties.len() <= key_share.degree()
+            || !verification_keys.iter().all(C::is_on_curve)
+            || !ciphertexts.iter().all(ElGamalCiphertext::is_on_curve)
+        {
+            return Err(Error::Input);
+        }
+        Ok(Self {
+            key_share,
+            verification_keys,
+            parties,
+            ciphertexts,
+        })
+    }
+
+    /// Creates the protocol from the local party's output of a
+    /// [`FeldmanDkg`](super::dkg::FeldmanDkg) run, with every party of that run taking part.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if a ciphertext point is not on the curve.
+    pub fn from_dkg(
+        key: &DkgOutput<LIMBS, C>,
+        ciphertexts: Vec<ElGamalCiphertext<LIMBS, C>>,
+    ) -> Result<Self, Error> {
+        let parties = key.parties().to_vec();
+        let verification_keys = parties
+            .iter()
+            .filter_map(|party| key.verification_key(*party).copied())
+            .collect();
+        Self::new(
+            key.key_share().clone(),
+            verification_keys,
+            parties,
+            ciphertexts,
+        )
+    }
+}
+
+impl<const LIMBS: usize, C, E> Protocol<E> for ThresholdDecrypt<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS> + Abbreviate + Send + Sync + 'static,
+    C::ScalarField: ChallengeField + From<u64> + Send + Sync + 'static,
+    E: RandEnvironment,
+{
+    /// The decrypted points, one per ciphertext.
+    type Output = Vec<C>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let t = self.key_share.degree();
+        let my_idx = self
+            .parties
+            .iter()
+            .position(|party| *party == me)
+            .ok_or(Error::Input)?;
+        let g = C::gen();
+
+        let my_shares: Vec<ExponentSS<LIMBS, C>> = self
+            .ciphertexts
+            .iter()
+            .map(|ct| ExponentSS::from_share(&self.key_share, ct.c1()))
+            .collect();
+        let my_proofs: Vec<ChaumPedersenProof<LIMBS, C>> = self
+            .ciphertexts
+            .iter()
+            .map(|ct| ChaumPedersenProof::prove(self.key_share.share(), &g, ct.c1(), env.rng_mut()))
+            .collect();
+
+        let mut pkt = Packet::empty();
+        pkt.write_many_labeled(&my_shares)?;
+        pkt.write_many_labeled(&my_proofs)?;
+        let messages: Vec<_> = self
+            .parties
+            .iter()
+            .filter(|party| **party != me)
+            .map(|party| (*party, pkt.clone()))
+            .collect();
+        env.network_mut().send_many(&messages).await?;
+
+        // Keep the shares of every party whose proofs all verify.
+        let m = self.ciphertexts.len();
+        let mut valid_parties = Vec::with_capacity(self.parties.len());
+        let mut valid_shares: Vec<Vec<ExponentSS<LIMBS, C>>> = vec![Vec::new(); m];
+        let mut first_cheater = None;
+        for (j, party) in self.parties.iter().enumerate() {
+            let shares = if j == my_idx {
+                my_shares.clone()
+            } else {
+                let pkt = env.network_mut().recv_from(*party).await?;
+                let shares: Option<Vec<ExponentSS<LIMBS, C>>> =
+                    (0..m).map(|k| pkt.read(k).ok()).collect();
+                let proofs: Option<Vec<ChaumPedersenProof<LIMBS, C>>> =
+                    (m..2 * m).map(|k| pkt.read(k).ok()).collect();
+                let valid = match (&shares, &proofs) {
+                    (Some(shares), Some(proofs)) => {
+                        self.ciphertexts.iter().zip(shares).zip(proofs).all(
+                            |((ct, share), proof)| {
+                                share.degree() == t
+                                    && share.point().is_on_curve()
+                                    && proof.verify(
+                                        &g,
+                                        ct.c1(),
+                                        &self.verification_keys[j],
+                                        share.point(),
+                                    )
+                            },
+                        )
+                    }
+                    _ => false,
+                };
+                match shares {
+                    Some(shares) if valid => shares,
+                    _ => {
+                        first_cheater.get_or_insert(*party);
+                        continue;
+                    }
+                }
+            };
+            valid_parties.push(*party);
+            for (column, share) in valid_shares.iter_mut().zip(shares) {
+                column.push(share);
+            }
+        }
+        if valid_parties.len() <= t {
+            return Err(Error::Cheater(first_cheater.ok_or(Error::Input)?));
+        }
+
+        let mut plaintexts = Vec::with_capacity(m);
+        for (ct, column) in self.ciphertexts.iter().zip(&valid_shares) {
+            let mask = ExponentSS::secret_from_shares(column, &valid_parties)?;
+            plaintexts.push(ct.c2().sub(&mask));
+        }
+        Ok(plaintexts)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("ThresholdDecrypt")
+    }
+}
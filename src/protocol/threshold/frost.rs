@@ -0,0 +1,293 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    math::{
+        ec::{secp256k1::Secp256k1, EllipticCurve},
+        field::secp256k1_scalar::Secp256k1ScalarField,
+        poly::compute_lagrange_basis,
+        ring::Ring,
+    },
+    net::{Network, Packet, PartyId},
+    protocol::{threshold::dkg::DkgOutput, Error, Protocol, ProtocolId, RandEnvironment},
+    ss::exponent::ExponentSS,
+};
+
+type Scalar = Secp256k1ScalarField;
+
+/// A BIP-340 Schnorr signature: the `x`-coordinate of the nonce point `R` followed by the scalar
+/// `s`, both as 32 big-endian bytes.
+///
+/// The signature verifies against the 32-byte `x`-only public key of
+/// [`x_only_public_key`], with any BIP-340 implementation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Bip340Signature {
+    /// The `x`-coordinate of `R`.
+    r: [u8; 32],
+    /// The scalar `s`.
+    s: [u8; 32],
+}
+
+impl Bip340Signature {
+    /// Returns the 64-byte encoding `r || s`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0; 64];
+        bytes[..32].copy_from_slice(&self.r);
+        bytes[32..].copy_from_slice(&self.s);
+        bytes
+    }
+
+    /// Decodes the 64-byte encoding `r || s`. Any 64 bytes decode; a malformed signature simply
+    /// fails to [`verify`](Self::verify).
+    pub fn from_bytes(bytes: &[u8; 64]) -> Self {
+        let mut r = [0; 32];
+        let mut s = [0; 32];
+        r.copy_from_slice(&bytes[..32]);
+        s.copy_from_slice(&bytes[32..]);
+        Self { r, s }
+    }
+
+    /// Verifies the signature on `message` under the `x`-only `public_key`, as specified by BIP-340.
+    pub fn verify(&self, public_key: &[u8; 32], message: &[u8]) -> bool {
+        let Some(p) = Secp256k1::lift_x(public_key) else {
+            return false;
+        };
+        let Some(s) = Scalar::from_be_bytes(&self.s) else {
+            return false;
+        };
+        let e = challenge(&self.r, public_key, message);
+        let r = Secp256k1::gen().scalar_mul(&s).sub(&p.scalar_mul(&e));
+        let encoded = r.to_compressed();
+        !r.is_point_at_infinity() && encoded[0] == 0x02 && encoded[1..] == self.r
+    }
+}
+
+/// Returns the 32-byte `x`-only encoding of `public_key` that BIP-340 signatures verify against.
+///
+/// It identifies the point with an even `y`-coordinate among `±public_key`; a signature produced
+/// by [`FrostSign`] for a key with an odd `y` verifies against this encoding.
+pub fn x_only_public_key(public_key: &Secp256k1) -> [u8; 32] {
+    let mut x = [0; 32];
+    x.copy_from_slice(&public_key.to_compressed()[1..]);
+    x
+}
+
+/// `SHA-256(SHA-256(tag) || SHA-256(tag) || parts...)`, the tagged hash of BIP-340.
+fn tagged_hash(tag: &str, parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag.as_bytes());
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// The BIP-340 challenge `e = H_challenge(r || P || m) mod n`.
+fn challenge(r: &[u8; 32], public_key: &[u8; 32], message: &[u8]) -> Scalar {
+    Scalar::from_be_bytes_reduced(&tagged_hash("BIP0340/challenge", &[r, public_key, message]))
+}
+
+/// Whether `point` has an odd `y`-coordinate, so BIP-340 uses its negation.
+fn has_odd_y(point: &Secp256k1) -> bool {
+    point.to_compressed()[0] == 0x03
+}
+
+/// Two-round threshold Schnorr signing, following FROST (Komlo and Goldberg, *FROST: Flexible
+/// Round-Optimized Schnorr Threshold Signatures*, SAC 2020), with output in the BIP-340 format.
+///
+/// The signers hold shares of a key generated by [`FeldmanDkg`](super::dkg::FeldmanDkg); any
+/// `t + 1` of them can sign, and the secret key is never reconstructed.
+///
+/// 1. **Commit.** Each signer samples two nonces `d_i, e_i` and sends the other signers
+///    `D_i = d_i · G` and `E_i = e_i · G`.
+/// 2. **Sign.** Each signer derives the binding factors `ρ_j` by hashing the key, the message and
+///    every commitment, and the group commitment `R = Σ D_j + ρ_j · E_j`. With the BIP-340
+///    challenge `c` of `R`, the key and the message, and its Lagrange coefficient `λ_i` within the
+///    signer set, it sends the partial signature `z_i = d_i + ρ_i · e_i + λ_i · c · x_i`.
+///
+/// Every signer checks each partial signature against the sender's commitments and verification
+/// key, `z_j · G = D_j + ρ_j · E_j + λ_j · c · Y_j`, and aborts naming the sender if one fails.
+/// The signature is `(R, Σ z_j)`. The binding factors tie each nonce to the whole commitment list,
+/// so a signer cannot choose its commitments after seeing the others' to bias `R`.
+///
+/// BIP-340 identifies a point with the one of `±P` that has an even `y`-coordinate. When the public
+/// key or `R` has an odd `y`, the signers negate their key shares or nonces to match, so the result
+/// verifies against [`x_only_public_key`] of the DKG's public key.
+///
+/// # Preconditions
+///
+/// Every signer must run this protocol with the same message and signer set, and its output of the
+/// same [`FeldmanDkg`](super::dkg::FeldmanDkg) run. The nonces are fresh for every run and never
+/// leave it, so a nonce cannot be reused across signatures.
+///
+/// # Security model: active adversary, abort
+///
+/// A signer that sends an inconsistent partial signature, or a message that does not parse, is
+/// identified with [`Error::Cheater`]. Signing aborts rather than completes in that case; the
+/// remaining signers can retry without it. Every signer is assumed to eventually send its messages;
+/// receive timeouts are the caller's concern.
+pub struct FrostSign {
+    key: DkgOutput<4, Secp256k1>,
+    signers: Vec<PartyId>,
+    message: Vec<u8>,
+}
+
+impl FrostSign {
+    /// Creates the protocol for the local party, which signs `message` with its `key` together
+    /// with the other parties in `signers`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Input`] if `signers` lists a party twice, names a party outside the key's
+    /// parties, or has fewer than `t + 1` members for a key shared at degree `t`.
+    pub fn new(
+        key: DkgOutput<4, Secp256k1>,
+        mut signers: Vec<PartyId>,
+        message: Vec<u8>,
+    ) -> Result<Self, Error> {
+        let n = signers.len();
+        signers.sort();
+        signers.dedup();
+        if signers.len() != n
+            || n <= key.key_share().degree()
+            || signers.iter().any(|s| key.verification_key(*s).is_none())
+        {
+            return Err(Error::Input);
+        }
+        Ok(Self {
+            key,
+            signers,
+            message,
+        })
+    }
+}
+
+impl<E: RandEnvironment> Protocol<E> for FrostSign {
+    type Output = Bip340Signature;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let my_idx = self.signers.binary_search(&me).map_err(|_| Error::Input)?;
+        let others: Vec<PartyId> = self.signers.iter().copied().filter(|p| *p != me).collect();
+
+        // BIP-340 signs with the key of even `y`: negate the key shares, and with them the
+        // verification keys, if the public key has an odd one.
+        let public_key = *self.key.public_key();
+        let key_sign = if has_odd_y(&public_key) {
+            Scalar::ONE.negate()
+        } else {
+            Scalar::ONE
+        };
+        let public_key_x = x_only_public_key(&public_key);
+
+        // Round 1: commit to two fresh nonces.
+        let d = Scalar::random_non_zero(env.rng_mut());
+        let e = Scalar::random_non_zero(env.rng_mut());
+        let my_commitments = (
+            Secp256k1::gen().scalar_mul(&d),
+            Secp256k1::gen().scalar_mul(&e),
+        );
+
+        let mut pkt = Packet::empty();
+        pkt.write_labeled(&my_commitments.0)?;
+        pkt.write_labeled(&my_commitments.1)?;
+        let messages: Vec<_> = others.iter().map(|p| (*p, pkt.clone())).collect();
+        env.network_mut().send_many(&messages).await?;
+
+        let mut commitments = Vec::with_capacity(self.signers.len());
+        for signer in &self.signers {
+            if *signer == me {
+                commitments.push(my_commitments);
+                continue;
+            }
+            let pkt = env.network_mut().recv_from(*signer).await?;
+            let read = |i| pkt.read(i).map_err(|_| Error::Cheater(*signer));
+            commitments.push((read(0)?, read(1)?));
+        }
+
+        // Binding factors over the encoded commitment list, and the group commitment.
+        let mut encoded_list = Vec::with_capacity(self.signers.len() * 74);
+        for (signer, (d_j, e_j)) in self.signers.iter().zip(&commitments) {
+            encoded_list.extend_from_slice(&(signer.as_usize() as u64).to_be_bytes());
+            encoded_list.extend_from_slice(&d_j.to_compressed());
+            encoded_list.extend_from_slice(&e_j.to_compressed());
+        }
+        let message_hash: [u8; 32] = Sha256::digest(&self.message).into();
+        let binding: Vec<Scalar> = self
+            .signers
+            .iter()
+            .map(|signer| {
+                Scalar::from_be_bytes_reduced(&tagged_hash(
+                    "FROST/secp256k1/rho",
+                    &[
+                        &public_key_x,
+                        &message_hash,
+                        &encoded_list,
+                        &(signer.as_usize() as u64).to_be_bytes(),
+                    ],
+                ))
+            })
+            .collect();
+        let nonce_points: Vec<Secp256k1> = commitments
+            .iter()
+            .zip(&binding)
+            .map(|((d_j, e_j), rho)| d_j.add(&e_j.scalar_mul(rho)))
+            .collect();
+        let group_commitment = nonce_points
+            .iter()
+            .fold(Secp256k1::ZERO, |acc, point| acc.add(point));
+
+        // As for the key, BIP-340 uses the nonce point of even `y`.
+        let nonce_sign = if has_odd_y(&group_commitment) {
+            Scalar::ONE.negate()
+        } else {
+            Scalar::ONE
+        };
+        let r = x_only_public_key(&group_commitment);
+        let c = challenge(&r, &public_key_x, &self.message);
+        let nodes: Vec<Scalar> = self
+            .signers
+            .iter()
+            .copied()
+            .map(ExponentSS::<4, Secp256k1>::encode_party)
+            .collect();
+        let lambdas = compute_lagrange_basis(&nodes, &Scalar::ZERO).map_err(|_| Error::Input)?;
+
+        // Round 2: partial signatures, each checked against its signer's public values.
+        let z = nonce_sign * &(d + &(e * &binding[my_idx]))
+            + &(lambdas[my_idx] * &c * &key_sign * self.key.key_share().share());
+        let mut pkt = Packet::empty();
+        pkt.write_labeled(&z)?;
+        let messages: Vec<_> = others.iter().map(|p| (*p, pkt.clone())).collect();
+        env.network_mut().send_many(&messages).await?;
+
+        let mut s = Scalar::ZERO;
+        for (j, signer) in self.signers.iter().enumerate() {
+            let z_j: Scalar = if *signer == me {
+                z
+            } else {
+                let pkt = env.network_mut().recv_from(*signer).await?;
+                pkt.read(0).map_err(|_| Error::Cheater(*signer))?
+            };
+            let verification_key = self.key.verification_key(*signer).ok_or(Error::Input)?;
+            let expected = nonce_points[j]
+                .scalar_mul(&nonce_sign)
+                .add(&verification_key.scalar_mul(&(lambdas[j] * &c * &key_sign)));
+            if Secp256k1::gen().scalar_mul(&z_j) != expected {
+                return Err(Error::Cheater(*signer));
+            }
+            s = s + &z_j;
+        }
+
+        Ok(Bip340Signature {
+            r,
+            s: s.to_be_bytes(),
+        })
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("FrostSign")
+    }
+}
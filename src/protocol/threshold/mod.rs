@@ -0,0 +1,31 @@
+//! Threshold cryptography over elliptic curves: a secret key that exists only as a Shamir sharing
+//! among the parties, and protocols that use it without ever reconstructing it.
+//!
+//! - [`dkg`](crate::protocol::threshold::dkg) —
+//!   [`FeldmanDkg`](crate::protocol::threshold::dkg::FeldmanDkg): distributed key generation.
+//!   Every party deals a Feldman sharing of a random scalar; the sharings that survive the
+//!   complaint round add up to a sharing of the secret key, and their commitments to the public
+//!   key and to every party's verification key.
+//! - [`frost`](crate::protocol::threshold::frost) —
+//!   [`FrostSign`](crate::protocol::threshold::frost::FrostSign): two-round threshold Schnorr
+//!   signing with a DKG'd key, producing
+//!   [`Bip340Signature`](crate::protocol::threshold::frost::Bip340Signature)s that verify with any
+//!   BIP-340 implementation.
+//! - [`elgamal`](crate::protocol::threshold::elgamal) —
+//!   [`ElGamalCiphertext`](crate::protocol::threshold::elgamal::ElGamalCiphertext)s that anyone can
+//!   encrypt to the shared key, and
+//!   [`ThresholdDecrypt`](crate::protocol::threshold::elgamal::ThresholdDecrypt), which decrypts
+//!   them from decryption shares carrying
+//!   [`ChaumPedersenProof`](crate::protocol::threshold::elgamal::ChaumPedersenProof)s.
+//!
+//! The key shares are [`ShamirSS`](crate::ss::shamir::ShamirSS) shares over the curve's scalar
+//! field, so they can be opened in the exponent with
+//! [`PassiveOpenInExponent`](crate::protocol::passive_shamir::open_exponent::PassiveOpenInExponent)
+//! or fed to the other Shamir protocols of the crate.
+
+/// Distributed key generation from Feldman verifiable secret sharing.
+pub mod dkg;
+/// ElGamal encryption to a shared key, and its threshold decryption.
+pub mod elgamal;
+/// FROST threshold Schnorr signatures in the BIP-340 format.
+pub mod frost;
@@ -0,0 +1,389 @@
+use std::{fs, io, path::Path};
+
+use thiserror::Error;
+
+/// Errors that occur when loading or evaluating a [`BooleanCircuit`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum CircuitError {
+    /// Reading the circuit file failed.
+    #[error("IO error: {0:?}")]
+    Io(#[from] io::Error),
+    /// A line of a Bristol Fashion circuit is malformed, or breaks the format's wiring rules.
+    #[error("line {line}: {message}")]
+    Parse {
+        /// The 1-based number of the offending line.
+        line: usize,
+        /// What is wrong with it.
+        message: String,
+    },
+    /// The circuit was given a different number of input values than it takes.
+    #[error("expected {expected} input values, found {found}")]
+    InputCount {
+        /// The number of input values of the circuit.
+        expected: usize,
+        /// The number of input values given.
+        found: usize,
+    },
+    /// An input value has a different number of bits than the circuit takes for it.
+    #[error("input value {index} should have {expected} bits, found {found}")]
+    InputLength {
+        /// The position of the input value.
+        index: usize,
+        /// The number of bits the circuit takes for it.
+        expected: usize,
+        /// The number of bits given.
+        found: usize,
+    },
+}
+
+/// A gate of a [`BooleanCircuit`]. Wires are indices into the circuit's wires.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gate {
+    /// `out = a ⊕ b`.
+    Xor {
+        /// The first input wire.
+        a: usize,
+        /// The second input wire.
+        b: usize,
+        /// The output wire.
+        out: usize,
+    },
+    /// `out = a ∧ b`.
+    And {
+        /// The first input wire.
+        a: usize,
+        /// The second input wire.
+        b: usize,
+        /// The output wire.
+        out: usize,
+    },
+    /// `out = ¬a`.
+    Inv {
+        /// The input wire.
+        a: usize,
+        /// The output wire.
+        out: usize,
+    },
+    /// `out = a`.
+    Eqw {
+        /// The input wire.
+        a: usize,
+        /// The output wire.
+        out: usize,
+    },
+    /// `out = value`, a constant.
+    Eq {
+        /// The constant.
+        value: bool,
+        /// The output wire.
+        out: usize,
+    },
+}
+
+/// A boolean circuit of XOR, AND, INV, EQW and EQ gates, in the form of the Bristol Fashion
+/// format (<https://nigelsmart.github.io/MPC-Circuits/>).
+///
+/// The circuit takes a list of input **values**, each a fixed number of bits, and produces a list
+/// of output values. The bits of the input values sit on the first wires, in order, and those of
+/// the output values on the last wires; gates come in topological order, every wire is written
+/// once, and no wire is read before it is written. A Bristol Fashion file is
+///
+/// ```text
+/// <gates> <wires>
+/// <input values> <bits of value 1> ... <bits of value n>
+/// <output values> <bits of value 1> ... <bits of value m>
+///
+/// 2 1 <a> <b> <out> XOR
+/// 2 1 <a> <b> <out> AND
+/// 1 1 <a> <out> INV
+/// 1 1 <a> <out> EQW
+/// 1 1 <0 or 1> <out> EQ
+/// ```
+///
+/// with one line per gate. The `MAND` gate, `2k k <a_1> ... <a_k> <b_1> ... <b_k> <out_1> ...
+/// <out_k> MAND`, is read as `k` AND gates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BooleanCircuit {
+    /// The number of wires.
+    wires: usize,
+    /// The number of bits of every input value.
+    inputs: Vec<usize>,
+    /// The number of bits of every output value.
+    outputs: Vec<usize>,
+    /// The gates, in topological order.
+    gates: Vec<Gate>,
+}
+
+impl BooleanCircuit {
+    /// Parses a circuit in the Bristol Fashion format, checking that it is well formed: every
+    /// wire in range, written once, and written before it is read. The header may not declare more
+    /// gates than the text has lines, nor more wires past the inputs than it has bytes, so a
+    /// malicious header cannot make the parser allocate more than the text's size.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CircuitError::Parse`] with the offending line if the circuit is not well formed.
+    pub fn from_bristol(text: &str) -> Result<Self, CircuitError> {
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.split_whitespace().collect::<Vec<_>>()))
+            .filter(|(_, tokens)| !tokens.is_empty());
+        let mut next_line = |what: &str| {
+            lines.next().ok_or_else(|| CircuitError::Parse {
+                line: text.lines().count() + 1,
+                message: format!("missing {what}"),
+            })
+        };
+
+        let (line, header) = next_line("header")?;
+        let [gate_count, wires] = numbers(line, &header)?[..] else {
+            return Err(error(line, "the header should be `<gates> <wires>`"));
+        };
+        let (inputs, input_bits) = sizes(next_line("input values")?)?;
+        let (outputs, output_bits) = sizes(next_line("output values")?)?;
+        if input_bits > wires || output_bits > wires {
+            return Err(error(line, "more input or output bits than wires"));
+        }
+        // Every gate takes a line, and every wire past the inputs is written by a gate, so neither
+        // can outnumber what the text holds. This bounds what a header can make us allocate.
+        if gate_count > text.lines().count() || wires - input_bits > text.len() {
+            return Err(error(line, "more gates or wires than the circuit can hold"));
+        }
+        // Whether each wire past the inputs is written; the inputs are from the start.
+        let mut written = vec![false; wires - input_bits];
+        let is_written =
+            |written: &[bool], wire: usize| wire < input_bits || written[wire - input_bits];
+
+        let mut gates = Vec::with_capacity(gate_count);
+        for _ in 0..gate_count {
+            let (line, tokens) = next_line("gate")?;
+            let (operation, arguments) = tokens.split_last().expect("the line is not empty");
+            let arguments = numbers(line, arguments)?;
+            let [inputs, outputs, ref wiring @ ..] = arguments[..] else {
+                return Err(error(line, "a gate needs input and output counts"));
+            };
+            if inputs.checked_add(outputs) != Some(wiring.len()) {
+                return Err(error(
+                    line,
+                    "the wire count does not match the gate's arity",
+                ));
+            }
+            let (ins, outs) = wiring.split_at(inputs);
+
+            let read = |wire: usize| {
+                if wire < wires && is_written(&written, wire) {
+                    Ok(wire)
+                } else {
+                    Err(error(
+                        line,
+                        format!("wire {wire} is read before it is written"),
+                    ))
+                }
+            };
+            let new_gates = match (*operation, inputs, outputs) {
+                ("XOR", 2, 1) => vec![Gate::Xor {
+                    a: read(ins[0])?,
+                    b: read(ins[1])?,
+                    out: outs[0],
+                }],
+                ("AND", 2, 1) => vec![Gate::And {
+                    a: read(ins[0])?,
+                    b: read(ins[1])?,
+                    out: outs[0],
+                }],
+                ("MAND", _, _) if inputs == 2 * outputs => {
+                    let (a, b) = ins.split_at(outputs);
+                    let mut ands = Vec::with_capacity(outputs);
+                    for ((a, b), out) in a.iter().zip(b).zip(outs) {
+                        ands.push(Gate::And {
+                            a: read(*a)?,
+                            b: read(*b)?,
+                            out: *out,
+                        });
+                    }
+                    ands
+                }
+                ("INV", 1, 1) => vec![Gate::Inv {
+                    a: read(ins[0])?,
+                    out: outs[0],
+                }],
+                ("EQW", 1, 1) => vec![Gate::Eqw {
+                    a: read(ins[0])?,
+                    out: outs[0],
+                }],
+                ("EQ", 1, 1) if ins[0] <= 1 => vec![Gate::Eq {
+                    value: ins[0] == 1,
+                    out: outs[0],
+                }],
+                _ => {
+                    return Err(error(
+                        line,
+                        format!("unsupported gate `{}`", tokens.join(" ")),
+                    ))
+                }
+            };
+            for out in outs {
+                if *out >= wires || is_written(&written, *out) {
+                    return Err(error(line, format!("wire {out} is written twice")));
+                }
+                written[*out - input_bits] = true;
+            }
+            gates.extend(new_gates);
+        }
+
+        if let Some((line, _)) = lines.next() {
+            return Err(error(line, "more gates than the header declares"));
+        }
+        if let Some(wire) = (wires - output_bits..wires).find(|wire| !is_written(&written, *wire)) {
+            return Err(error(line, format!("output wire {wire} is never written")));
+        }
+        Ok(Self {
+            wires,
+            inputs,
+            outputs,
+            gates,
+        })
+    }
+
+    /// Loads a circuit from the Bristol Fashion file at `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CircuitError::Io`] if the file cannot be read, and [`CircuitError::Parse`] if it
+    /// is not a well-formed circuit.
+    pub fn load_bristol(path: &Path) -> Result<Self, CircuitError> {
+        Self::from_bristol(&fs::read_to_string(path)?)
+    }
+
+    /// Returns the number of wires.
+    pub fn wires(&self) -> usize {
+        self.wires
+    }
+
+    /// Returns the number of bits of every input value.
+    pub fn inputs(&self) -> &[usize] {
+        &self.inputs
+    }
+
+    /// Returns the number of bits of every output value.
+    pub fn outputs(&self) -> &[usize] {
+        &self.outputs
+    }
+
+    /// Returns the gates, in topological order.
+    pub fn gates(&self) -> &[Gate] {
+        &self.gates
+    }
+
+    /// Returns the number of AND gates, the ones that are not free to evaluate securely.
+    pub fn and_count(&self) -> usize {
+        self.gates
+            .iter()
+            .filter(|gate| matches!(gate, Gate::And { .. }))
+            .count()
+    }
+
+    /// Evaluates the circuit in the clear on `inputs`, one bit vector per input value, and
+    /// returns one bit vector per output value.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CircuitError::InputCount`] or [`CircuitError::InputLength`] if `inputs` does not
+    /// match the circuit's input values.
+    pub fn evaluate(&self, inputs: &[Vec<bool>]) -> Result<Vec<Vec<bool>>, CircuitError> {
+        self.check_inputs(inputs)?;
+        let mut values = vec![false; self.wires];
+        for (wire, bit) in inputs.iter().flatten().enumerate() {
+            values[wire] = *bit;
+        }
+        for gate in &self.gates {
+            match *gate {
+                Gate::Xor { a, b, out } => values[out] = values[a] ^ values[b],
+                Gate::And { a, b, out } => values[out] = values[a] & values[b],
+                Gate::Inv { a, out } => values[out] = !values[a],
+                Gate::Eqw { a, out } => values[out] = values[a],
+                Gate::Eq { value, out } => values[out] = value,
+            }
+        }
+        Ok(self.split_outputs(&values[self.output_wires()]))
+    }
+
+    /// Checks that `inputs` holds one bit vector of the right length per input value.
+    pub(crate) fn check_inputs(&self, inputs: &[Vec<bool>]) -> Result<(), CircuitError> {
+        if inputs.len() != self.inputs.len() {
+            return Err(CircuitError::InputCount {
+                expected: self.inputs.len(),
+                found: inputs.len(),
+            });
+        }
+        for (index, (input, expected)) in inputs.iter().zip(&self.inputs).enumerate() {
+            if input.len() != *expected {
+                return Err(CircuitError::InputLength {
+                    index,
+                    expected: *expected,
+                    found: input.len(),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the output wires, the last ones of the circuit, with the bits of every output value
+    /// in order.
+    pub fn output_wires(&self) -> std::ops::Range<usize> {
+        self.wires - self.outputs.iter().sum::<usize>()..self.wires
+    }
+
+    /// Splits the bits of the output wires into one vector per output value.
+    pub(crate) fn split_outputs(&self, bits: &[bool]) -> Vec<Vec<bool>> {
+        let mut rest = bits;
+        self.outputs
+            .iter()
+            .map(|size| {
+                let (value, tail) = rest.split_at(*size);
+                rest = tail;
+                value.to_vec()
+            })
+            .collect()
+    }
+}
+
+/// Returns a parse error for `line`.
+fn error(line: usize, message: impl Into<String>) -> CircuitError {
+    CircuitError::Parse {
+        line,
+        message: message.into(),
+    }
+}
+
+/// Parses every token of `line` as a non-negative integer.
+fn numbers(line: usize, tokens: &[&str]) -> Result<Vec<usize>, CircuitError> {
+    tokens
+        .iter()
+        .map(|token| {
+            token
+                .parse()
+                .map_err(|_| error(line, format!("`{token}` is not a number")))
+        })
+        .collect()
+}
+
+/// Parses a line `<values> <bits of value 1> ... <bits of value n>` into the bit counts, and
+/// their total.
+fn sizes((line, tokens): (usize, Vec<&str>)) -> Result<(Vec<usize>, usize), CircuitError> {
+    match numbers(line, &tokens)?.split_first() {
+        Some((count, sizes)) if *count == sizes.len() => {
+            let total = sizes
+                .iter()
+                .try_fold(0usize, |total, size| total.checked_add(*size))
+                .ok_or_else(|| error(line, "the value sizes overflow"))?;
+            Ok((sizes.to_vec(), total))
+        }
+        _ => Err(error(
+            line,
+            "the value count does not match the sizes listed",
+        )),
+    }
+}
@@ -0,0 +1,26 @@
+//! Two-party computation of boolean circuits with Yao's garbled circuits.
+//!
+//! One party, the **garbler**, encrypts a boolean circuit gate by gate; the other, the
+//! **evaluator**, obtains the encrypted labels of its input bits by oblivious transfer and runs the
+//! encrypted circuit, learning nothing but the outputs. Unlike [GMW](crate::protocol::gmw), the
+//! number of rounds does not grow with the circuit's depth, at the price of sending the whole
+//! garbled circuit.
+//!
+//! - [`circuit`](crate::protocol::yao::circuit) —
+//!   [`BooleanCircuit`](crate::protocol::yao::circuit::BooleanCircuit), loaded from the Bristol
+//!   Fashion format in which standard circuits (adders, comparators, AES, SHA-256) are distributed,
+//!   and evaluable in the clear for testing.
+//! - [`garble`](crate::protocol::yao::garble) —
+//!   [`YaoGarbledCircuit`](crate::protocol::yao::garble::YaoGarbledCircuit), the two-party
+//!   protocol, with free XOR and half gates, and the evaluator's inputs transferred with
+//!   [`IknpOtExtension`](crate::protocol::ot::extension::IknpOtExtension).
+//!
+//! # Security model
+//!
+//! The protocol assumes a **passive (semi-honest) adversary**: a garbler that garbles a different
+//! circuit than agreed goes undetected.
+
+/// Boolean circuits and the Bristol Fashion format.
+pub mod circuit;
+/// Yao's garbled-circuit protocol with free XOR and half gates.
+pub mod garble;
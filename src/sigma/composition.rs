@@ -0,0 +1,288 @@
+use rand::CryptoRng;
+use serde::{Deserialize, Serialize};
+
+use crate::{math::ring::Ring, sigma::SigmaProtocol, transcript::Transcript};
+
+/// The conjunction of two statements: the prover knows a witness for both.
+///
+/// Both proofs run side by side on the **same** challenge; the commitment, the witness and the
+/// response are pairs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct And<P, Q> {
+    left: P,
+    right: Q,
+}
+
+impl<P, Q> And<P, Q> {
+    /// Creates the statement "`left` and `right`".
+    pub fn new(left: P, right: Q) -> Self {
+        Self { left, right }
+    }
+}
+
+impl<P, Q> SigmaProtocol for And<P, Q>
+where
+    P: SigmaProtocol,
+    Q: SigmaProtocol<Challenge = P::Challenge>,
+{
+    type Witness = (P::Witness, Q::Witness);
+    type Commitment = (P::Commitment, Q::Commitment);
+    type Challenge = P::Challenge;
+    type Response = (P::Response, Q::Response);
+    type State = (P::State, Q::State);
+
+    fn commit<R: CryptoRng>(
+        &self,
+        (left, right): &Self::Witness,
+        rng: &mut R,
+    ) -> (Self::State, Self::Commitment) {
+        let (left_state, left_commitment) = self.left.commit(left, rng);
+        let (right_state, right_commitment) = self.right.commit(right, rng);
+        (
+            (left_state, right_state),
+            (left_commitment, right_commitment),
+        )
+    }
+
+    fn respond(
+        &self,
+        (left, right): &Self::Witness,
+        (left_state, right_state): Self::State,
+        challenge: &Self::Challenge,
+    ) -> Self::Response {
+        (
+            self.left.respond(left, left_state, challenge),
+            self.right.respond(right, right_state, challenge),
+        )
+    }
+
+    fn verify(
+        &self,
+        (left_commitment, right_commitment): &Self::Commitment,
+        challenge: &Self::Challenge,
+        (left_response, right_response): &Self::Response,
+    ) -> bool {
+        self.left.verify(left_commitment, challenge, left_response)
+            && self
+                .right
+                .verify(right_commitment, challenge, right_response)
+    }
+
+    fn simulate<R: CryptoRng>(
+        &self,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Response) {
+        let (left_commitment, left_response) = self.left.simulate(challenge, rng);
+        let (right_commitment, right_response) = self.right.simulate(challenge, rng);
+        (
+            (left_commitment, right_commitment),
+            (left_response, right_response),
+        )
+    }
+
+    fn append_statement(&self, transcript: &mut Transcript) {
+        transcript.append_message(b"sigma", b"and");
+        self.left.append_statement(transcript);
+        self.right.append_statement(transcript);
+    }
+
+    fn append_commitment(&self, (left, right): &Self::Commitment, transcript: &mut Transcript) {
+        self.left.append_commitment(left, transcript);
+        self.right.append_commitment(right, transcript);
+    }
+
+    fn squeeze_challenge(&self, transcript: &mut Transcript) -> Self::Challenge {
+        self.left.squeeze_challenge(transcript)
+    }
+}
+
+/// The disjunction of two statements: the prover knows a witness for one of them, and the proof
+/// does not reveal which, following Cramer, Damgård and Schoenmakers (*Proofs of Partial Knowledge
+/// and Simplified Design of Witness Hiding Protocols*, CRYPTO 1994).
+///
+/// The prover simulates the side it has no witness for on a challenge of its own choosing, and
+/// proves the other side honestly on what the verifier's challenge `c` leaves over: the two
+/// challenges must add up to `c`, so the prover can pick only one of them. The response carries
+/// the first side's challenge; the verifier derives the second's and checks both conversations.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Or<P, Q> {
+    left: P,
+    right: Q,
+}
+
+impl<P, Q> Or<P, Q> {
+    /// Creates the statement "`left` or `right`".
+    pub fn new(left: P, right: Q) -> Self {
+        Self { left, right }
+    }
+}
+
+/// The witness of an [`Or`]: one for either side.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OrWitness<L, R> {
+    /// A witness for the left statement.
+    Left(L),
+    /// A witness for the right statement.
+    Right(R),
+}
+
+/// The prover's state in an [`Or`]: the state of the side it proves, and the simulated
+/// conversation of the other.
+pub struct OrState<P: SigmaProtocol, Q: SigmaProtocol>(OrSide<P, Q>);
+
+enum OrSide<P: SigmaProtocol, Q: SigmaProtocol> {
+    /// The left side is proved; the right one was simulated on `challenge`.
+    Left {
+        state: P::State,
+        challenge: P::Challenge,
+        response: Q::Response,
+    },
+    /// The right side is proved; the left one was simulated on `challenge`.
+    Right {
+        state: Q::State,
+        challenge: P::Challenge,
+        response: P::Response,
+    },
+}
+
+/// The response of an [`Or`]: the left side's challenge, and both sides' responses.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrResponse<C, L, R> {
+    /// The left side's challenge; the right side's is the verifier's challenge minus this one.
+    pub challenge: C,
+    /// The left side's response.
+    pub left: L,
+    /// The right side's response.
+    pub right: R,
+}
+
+impl<P, Q> SigmaProtocol for Or<P, Q>
+where
+    P: SigmaProtocol,
+    Q: SigmaProtocol<Challenge = P::Challenge>,
+{
+    type Witness = OrWitness<P::Witness, Q::Witness>;
+    type Commitment = (P::Commitment, Q::Commitment);
+    type Challenge = P::Challenge;
+    type Response = OrResponse<P::Challenge, P::Response, Q::Response>;
+    type State = OrState<P, Q>;
+
+    fn commit<R: CryptoRng>(
+        &self,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> (Self::State, Self::Commitment) {
+        let challenge = P::Challenge::random(rng);
+        match witness {
+            OrWitness::Left(witness) => {
+                let (state, left) = self.left.commit(witness, rng);
+                let (right, response) = self.right.simulate(&challenge, rng);
+                let side = OrSide::Left {
+                    state,
+                    challenge,
+                    response,
+                };
+                (OrState(side), (left, right))
+            }
+            OrWitness::Right(witness) => {
+                let (state, right) = self.right.commit(witness, rng);
+                let (left, response) = self.left.simulate(&challenge, rng);
+                let side = OrSide::Right {
+                    state,
+                    challenge,
+                    response,
+                };
+                (OrState(side), (left, right))
+            }
+        }
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `witness` is not for the side `state` was committed with.
+    fn respond(
+        &self,
+        witness: &Self::Witness,
+        OrState(side): Self::State,
+        challenge: &Self::Challenge,
+    ) -> Self::Response {
+        match (witness, side) {
+            (
+                OrWitness::Left(witness),
+                OrSide::Left {
+                    state,
+                    challenge: simulated,
+                    response,
+                },
+            ) => {
+                let left_challenge = *challenge - &simulated;
+                OrResponse {
+                    left: self.left.respond(witness, state, &left_challenge),
+                    challenge: left_challenge,
+                    right: response,
+                }
+            }
+            (
+                OrWitness::Right(witness),
+                OrSide::Right {
+                    state,
+                    challenge: simulated,
+                    response,
+                },
+            ) => OrResponse {
+                right: self
+                    .right
+                    .respond(witness, state, &(*challenge - &simulated)),
+                challenge: simulated,
+                left: response,
+            },
+            _ => panic!("the witness is not for the side that was committed to"),
+        }
+    }
+
+    fn verify(
+        &self,
+        (left, right): &Self::Commitment,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool {
+        self.left.verify(left, &response.challenge, &response.left)
+            && self
+                .right
+                .verify(right, &(*challenge - &response.challenge), &response.right)
+    }
+
+    fn simulate<R: CryptoRng>(
+        &self,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Response) {
+        let left_challenge = P::Challenge::random(rng);
+        let (left, left_response) = self.left.simulate(&left_challenge, rng);
+        let (right, right_response) = self.right.simulate(&(*challenge - &left_challenge), rng);
+        (
+            (left, right),
+            OrResponse {
+                challenge: left_challenge,
+                left: left_response,
+                right: right_response,
+            },
+        )
+    }
+
+    fn append_statement(&self, transcript: &mut Transcript) {
+        transcript.append_message(b"sigma", b"or");
+        self.left.append_statement(transcript);
+        self.right.append_statement(transcript);
+    }
+
+    fn append_commitment(&self, (left, right): &Self::Commitment, transcript: &mut Transcript) {
+        self.left.append_commitment(left, transcript);
+        self.right.append_commitment(right, transcript);
+    }
+
+    fn squeeze_challenge(&self, transcript: &mut Transcript) -> Self::Challenge {
+        self.left.squeeze_challenge(transcript)
+    }
+}
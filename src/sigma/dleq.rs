@@ -0,0 +1,102 @@
+use rand::CryptoRng;
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{ec::EllipticCurve, ring::Ring},
+    sigma::SigmaProtocol,
+    transcript::{ChallengeField, Transcript},
+};
+
+/// The Chaum–Pedersen proof that two points share a discrete logarithm: knowledge of an `x` with
+/// `X = x · G` and `Y = x · H`.
+///
+/// The prover commits to `(A, B) = (k · G, k · H)` for one random `k`, and answers the challenge
+/// `c` with `z = k + c · x`; the verifier checks `z · G = A + c · X` and `z · H = B + c · Y`.
+/// [`ChaumPedersenProof`](crate::protocol::threshold::elgamal::ChaumPedersenProof) is its
+/// Fiat–Shamir form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dleq<const LIMBS: usize, C> {
+    /// The first base `G`.
+    g: C,
+    /// The second base `H`.
+    h: C,
+    /// The point `X = x · G`.
+    x: C,
+    /// The point `Y = x · H`.
+    y: C,
+}
+
+impl<const LIMBS: usize, C> Dleq<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    /// Creates the statement "`x` to the base `g` and `y` to the base `h` have the same discrete
+    /// logarithm, and I know it".
+    pub fn new(g: C, h: C, x: C, y: C) -> Self {
+        Self { g, h, x, y }
+    }
+}
+
+impl<const LIMBS: usize, C> SigmaProtocol for Dleq<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS> + Abbreviate,
+    C::ScalarField: ChallengeField,
+{
+    type Witness = C::ScalarField;
+    type Commitment = (C, C);
+    type Challenge = C::ScalarField;
+    type Response = C::ScalarField;
+    type State = C::ScalarField;
+
+    fn commit<R: CryptoRng>(&self, _: &Self::Witness, rng: &mut R) -> (Self::State, (C, C)) {
+        let k = C::ScalarField::random(rng);
+        (k, (self.g.scalar_mul(&k), self.h.scalar_mul(&k)))
+    }
+
+    fn respond(
+        &self,
+        witness: &Self::Witness,
+        state: Self::State,
+        challenge: &Self::Challenge,
+    ) -> Self::Response {
+        state + &(*challenge * witness)
+    }
+
+    fn verify(
+        &self,
+        (a, b): &(C, C),
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool {
+        self.g.scalar_mul(response) == a.add(&self.x.scalar_mul(challenge))
+            && self.h.scalar_mul(response) == b.add(&self.y.scalar_mul(challenge))
+    }
+
+    fn simulate<R: CryptoRng>(
+        &self,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> ((C, C), Self::Response) {
+        let z = C::ScalarField::random(rng);
+        let a = self.g.scalar_mul(&z).sub(&self.x.scalar_mul(challenge));
+        let b = self.h.scalar_mul(&z).sub(&self.y.scalar_mul(challenge));
+        ((a, b), z)
+    }
+
+    fn append_statement(&self, transcript: &mut Transcript) {
+        transcript.append_message(b"sigma", b"dleq");
+        for (label, point) in [
+            (b"g" as &'static [u8], &self.g),
+            (b"h", &self.h),
+            (b"x", &self.x),
+            (b"y", &self.y),
+        ] {
+            transcript.append_point(label, point);
+        }
+    }
+
+    fn append_commitment(&self, (a, b): &(C, C), transcript: &mut Transcript) {
+        transcript.append_point(b"commitment", a);
+        transcript.append_point(b"commitment", b);
+    }
+}
@@ -0,0 +1,66 @@
+use rand::CryptoRng;
+use serde::{Deserialize, Serialize};
+
+use crate::{abbreviate::Abbreviate, sigma::SigmaProtocol, transcript::Transcript};
+
+/// A non-interactive proof for a Σ-protocol, by the Fiat–Shamir transform: the commitment and the
+/// response, with the challenge squeezed out of a [`Transcript`].
+///
+/// [`prove`](NonInteractiveProof::prove) appends the statement and the commitment to the
+/// transcript and takes the challenge from it; [`verify`](NonInteractiveProof::verify) replays the
+/// same appends on a transcript in the same state, and checks the conversation. The transcript may
+/// already hold context — a session identifier, earlier messages — which then binds the proof to
+/// it; the prover and the verifier must start from the same one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NonInteractiveProof<C, R> {
+    /// The prover's commitment.
+    commitment: C,
+    /// The prover's response.
+    response: R,
+}
+
+impl<C, R> Abbreviate for NonInteractiveProof<C, R> {
+    const ABBREVIATION: &'static str = "sigma proof";
+}
+
+impl<C, R> NonInteractiveProof<C, R> {
+    /// Proves `statement` with `witness`, deriving the challenge from `transcript`.
+    pub fn prove<P, G>(
+        statement: &P,
+        witness: &P::Witness,
+        transcript: &mut Transcript,
+        rng: &mut G,
+    ) -> Self
+    where
+        P: SigmaProtocol<Commitment = C, Response = R>,
+        G: CryptoRng,
+    {
+        let (state, commitment) = statement.commit(witness, rng);
+        let challenge = challenge(statement, &commitment, transcript);
+        let response = statement.respond(witness, state, &challenge);
+        Self {
+            commitment,
+            response,
+        }
+    }
+
+    /// Checks the proof of `statement`, deriving the challenge from `transcript`.
+    pub fn verify<P>(&self, statement: &P, transcript: &mut Transcript) -> bool
+    where
+        P: SigmaProtocol<Commitment = C, Response = R>,
+    {
+        let challenge = challenge(statement, &self.commitment, transcript);
+        statement.verify(&self.commitment, &challenge, &self.response)
+    }
+}
+
+/// Appends the statement and the commitment to `transcript`, and squeezes the challenge.
+fn challenge<P: SigmaProtocol>(
+    statement: &P,
+    commitment: &P::Commitment,
+    transcript: &mut Transcript,
+) -> P::Challenge {
+    statement.append_statement(transcript);
+    statement.append_commitment(commitment, transcript);
+    statement.squeeze_challenge(transcript)
+}
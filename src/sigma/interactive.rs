@@ -0,0 +1,126 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::ring::Ring,
+    net::{Network, Packet},
+    protocol::{Error, Protocol, ProtocolId, RandEnvironment},
+    sigma::SigmaProtocol,
+};
+
+/// A Σ-protocol run between two parties: the prover sends the commitment, the verifier a random
+/// challenge, and the prover the response, which the verifier checks.
+///
+/// Construct the prover's instance with [`prover`](InteractiveProof::prover) and the verifier's
+/// with [`verifier`](InteractiveProof::verifier), for the same statement. The verifier outputs
+/// `Some` of its verdict, and the prover `None`: it learns nothing from a proof it gave.
+///
+/// # Security model
+///
+/// The proof is sound against any prover, and zero-knowledge against an **honest verifier**, which
+/// samples the challenge uniformly and only after the commitment has arrived. A message that does
+/// not decode is reported as [`Error::Cheater`].
+pub struct InteractiveProof<P: SigmaProtocol> {
+    statement: P,
+    /// The witness, held by the prover only; `None` on the verifier.
+    witness: Option<P::Witness>,
+}
+
+impl<P: SigmaProtocol> InteractiveProof<P> {
+    /// Creates the protocol instance for the **prover** of `statement`, which knows `witness`.
+    pub fn prover(statement: P, witness: P::Witness) -> Self {
+        Self {
+            statement,
+            witness: Some(witness),
+        }
+    }
+
+    /// Creates the protocol instance for the **verifier** of `statement`.
+    pub fn verifier(statement: P) -> Self {
+        Self {
+            statement,
+            witness: None,
+        }
+    }
+}
+
+/// The prover's commitment.
+#[derive(Serialize, Deserialize)]
+struct CommitmentMessage<T>(T);
+
+impl<T> Abbreviate for CommitmentMessage<T> {
+    const ABBREVIATION: &'static str = "sigma com.";
+}
+
+/// The verifier's challenge.
+#[derive(Serialize, Deserialize)]
+struct ChallengeMessage<T>(T);
+
+impl<T> Abbreviate for ChallengeMessage<T> {
+    const ABBREVIATION: &'static str = "sigma chal.";
+}
+
+/// The prover's response.
+#[derive(Serialize, Deserialize)]
+struct ResponseMessage<T>(T);
+
+impl<T> Abbreviate for ResponseMessage<T> {
+    const ABBREVIATION: &'static str = "sigma resp.";
+}
+
+impl<P, E> Protocol<E> for InteractiveProof<P>
+where
+    P: SigmaProtocol + Send + Sync,
+    P::Witness: Send + Sync,
+    P::State: Send,
+    P::Commitment: Send + Sync,
+    P::Challenge: Send + Sync,
+    P::Response: Send + Sync,
+    E: RandEnvironment,
+{
+    /// `Some` of the verdict on the verifier; `None` on the prover.
+    type Output = Option<bool>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let other = env.network().other()?;
+        match self.witness {
+            Some(witness) => {
+                let (state, commitment) = self.statement.commit(&witness, env.rng_mut());
+                let mut pkt = Packet::empty();
+                pkt.write_labeled(&CommitmentMessage(commitment))?;
+                env.network_mut().send_to(other, &pkt).await?;
+
+                let pkt = env.network_mut().recv_from(other).await?;
+                let ChallengeMessage(challenge) = pkt.read(0).map_err(|_| Error::Cheater(other))?;
+                let response = self.statement.respond(&witness, state, &challenge);
+                let mut pkt = Packet::empty();
+                pkt.write_labeled(&ResponseMessage(response))?;
+                env.network_mut().send_to(other, &pkt).await?;
+                Ok(None)
+            }
+            None => {
+                let pkt = env.network_mut().recv_from(other).await?;
+                let CommitmentMessage(commitment) =
+                    pkt.read(0).map_err(|_| Error::Cheater(other))?;
+
+                // The challenge is sampled only once the commitment is in.
+                let challenge = P::Challenge::random(env.rng_mut());
+                let mut pkt = Packet::empty();
+                pkt.write_labeled(&ChallengeMessage(challenge))?;
+                env.network_mut().send_to(other, &pkt).await?;
+
+                let pkt = env.network_mut().recv_from(other).await?;
+                let ResponseMessage(response) = pkt.read(0).map_err(|_| Error::Cheater(other))?;
+                Ok(Some(self.statement.verify(
+                    &commitment,
+                    &challenge,
+                    &response,
+                )))
+            }
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("InteractiveProof")
+    }
+}
@@ -0,0 +1,128 @@
+//! Σ-protocols: three-move proofs of knowledge, their composition, and how to run them.
+//!
+//! A Σ-protocol proves knowledge of a witness for a public statement in three moves: the prover
+//! sends a **commitment**, the verifier answers with a random **challenge**, and the prover sends a
+//! **response**, which the verifier checks against the statement. Every protocol here is
+//! special-sound (two accepting answers to one commitment reveal the witness) and honest-verifier
+//! zero-knowledge (an accepting conversation can be simulated from the statement alone), which is
+//! what both the composition and the Fiat–Shamir transform rely on.
+//!
+//! A [`SigmaProtocol`](crate::sigma::SigmaProtocol) is the statement itself, with the prover's and
+//! the verifier's algorithms as methods. Over any
+//! [`EllipticCurve`](crate::math::ec::EllipticCurve):
+//!
+//! - [`schnorr`](crate::sigma::schnorr) — [`Schnorr`](crate::sigma::schnorr::Schnorr): knowledge of
+//!   the discrete logarithm `x` of `X = x · G`.
+//! - [`dleq`](crate::sigma::dleq) — [`Dleq`](crate::sigma::dleq::Dleq), Chaum–Pedersen: knowledge of
+//!   an `x` with `X = x · G` and `Y = x · H`.
+//! - [`representation`](crate::sigma::representation) —
+//!   [`Representation`](crate::sigma::representation::Representation): knowledge of `x_1, …, x_k`
+//!   with `X = Σ x_i · G_i`.
+//!
+//! [`composition`](crate::sigma::composition) builds new Σ-protocols out of two:
+//! [`And`](crate::sigma::composition::And) proves both statements, and
+//! [`Or`](crate::sigma::composition::Or) proves one of them without revealing which.
+//!
+//! Any Σ-protocol then runs in one of two ways:
+//!
+//! - [`interactive`](crate::sigma::interactive) —
+//!   [`InteractiveProof`](crate::sigma::interactive::InteractiveProof), a two-party
+//!   [`Protocol`](crate::protocol::Protocol) in which the verifier samples the challenge.
+//! - [`fiat_shamir`](crate::sigma::fiat_shamir) —
+//!   [`NonInteractiveProof`](crate::sigma::fiat_shamir::NonInteractiveProof), in which the challenge
+//!   is squeezed out of a [`Transcript`](crate::transcript::Transcript) of the statement and the
+//!   commitment, so the proof is a single message anyone can check.
+//!
+//! # Security model
+//!
+//! The interactive proofs are zero-knowledge against an **honest verifier** only; the
+//! non-interactive ones are zero-knowledge and sound in the random-oracle model. As everywhere in
+//! this crate, the arithmetic is not hardened against timing side channels.
+
+use rand::CryptoRng;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::transcript::{ChallengeField, Transcript};
+
+/// AND and OR composition of Σ-protocols.
+pub mod composition;
+/// Chaum–Pedersen proofs of equality of discrete logarithms.
+pub mod dleq;
+/// Non-interactive proofs by the Fiat–Shamir transform.
+pub mod fiat_shamir;
+/// Σ-protocols run interactively between a prover and a verifier.
+pub mod interactive;
+/// Proofs of knowledge of a representation over several generators.
+pub mod representation;
+/// Schnorr proofs of knowledge of a discrete logarithm.
+pub mod schnorr;
+
+/// A Σ-protocol for the statement `self`.
+///
+/// The prover runs [`commit`](SigmaProtocol::commit) and, once it has the challenge,
+/// [`respond`](SigmaProtocol::respond); the verifier runs [`verify`](SigmaProtocol::verify). The
+/// [`simulate`](SigmaProtocol::simulate) algorithm forges an accepting conversation for a given
+/// challenge without the witness, which is what [`Or`](composition::Or) builds on.
+pub trait SigmaProtocol {
+    /// What the prover knows.
+    type Witness;
+
+    /// The prover's first message.
+    type Commitment: Clone + Serialize + DeserializeOwned;
+
+    /// The verifier's random challenge, which the Fiat–Shamir transform squeezes out of a
+    /// [`Transcript`].
+    type Challenge: ChallengeField;
+
+    /// The prover's answer to the challenge.
+    type Response: Clone + Serialize + DeserializeOwned;
+
+    /// What the prover keeps between the commitment and the response.
+    type State;
+
+    /// Starts a proof with `witness`, returning the prover's state and the commitment.
+    fn commit<R: CryptoRng>(
+        &self,
+        witness: &Self::Witness,
+        rng: &mut R,
+    ) -> (Self::State, Self::Commitment);
+
+    /// Answers `challenge`, from the state [`commit`](SigmaProtocol::commit) returned with the
+    /// same `witness`.
+    fn respond(
+        &self,
+        witness: &Self::Witness,
+        state: Self::State,
+        challenge: &Self::Challenge,
+    ) -> Self::Response;
+
+    /// Checks the conversation `(commitment, challenge, response)` against the statement.
+    fn verify(
+        &self,
+        commitment: &Self::Commitment,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool;
+
+    /// Forges a commitment and a response that [`verify`](SigmaProtocol::verify) accepts with
+    /// `challenge`, distributed like those of an honest prover, without the witness.
+    fn simulate<R: CryptoRng>(
+        &self,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (Self::Commitment, Self::Response);
+
+    /// Appends the statement to `transcript`, for the Fiat–Shamir challenge.
+    fn append_statement(&self, transcript: &mut Transcript);
+
+    /// Appends `commitment` to `transcript`, for the Fiat–Shamir challenge.
+    fn append_commitment(&self, commitment: &Self::Commitment, transcript: &mut Transcript);
+
+    /// Squeezes the Fiat–Shamir challenge out of `transcript`, uniform in the challenge space.
+    ///
+    /// By default, the challenge is squeezed under the label `challenge` with
+    /// [`Transcript::challenge_field`].
+    fn squeeze_challenge(&self, transcript: &mut Transcript) -> Self::Challenge {
+        Self::Challenge::squeeze(transcript, b"challenge")
+    }
+}
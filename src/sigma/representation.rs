@@ -0,0 +1,116 @@
+use rand::CryptoRng;
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{ec::EllipticCurve, ring::Ring},
+    sigma::SigmaProtocol,
+    transcript::{ChallengeField, Transcript},
+};
+
+/// Proof of knowledge of a representation of `X` over the generators `G_1, …, G_k`: scalars
+/// `x_1, …, x_k` with `X = Σ x_i · G_i`.
+///
+/// The prover commits to `A = Σ k_i · G_i` for random `k_i`, and answers the challenge `c` with
+/// `z_i = k_i + c · x_i`; the verifier checks `Σ z_i · G_i = A + c · X`. With one generator this is
+/// [`Schnorr`](super::schnorr::Schnorr); with two, it proves knowledge of the opening of a
+/// [Pedersen commitment](crate::commitment::PedersenScheme).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Representation<const LIMBS: usize, C> {
+    /// The generators `G_i`.
+    generators: Vec<C>,
+    /// The point `X`.
+    public: C,
+}
+
+impl<const LIMBS: usize, C> Representation<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    /// Creates the statement "I know scalars that combine `generators` into `public`".
+    pub fn new(generators: Vec<C>, public: C) -> Self {
+        Self { generators, public }
+    }
+
+    /// Returns `Σ scalars_i · G_i`.
+    fn combine(&self, scalars: &[C::ScalarField]) -> C {
+        self.generators
+            .iter()
+            .zip(scalars)
+            .fold(C::ZERO, |sum, (generator, scalar)| {
+                sum.add(&generator.scalar_mul(scalar))
+            })
+    }
+}
+
+impl<const LIMBS: usize, C> SigmaProtocol for Representation<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS> + Abbreviate,
+    C::ScalarField: ChallengeField,
+{
+    /// The scalars `x_i`, one per generator.
+    type Witness = Vec<C::ScalarField>;
+    type Commitment = C;
+    type Challenge = C::ScalarField;
+    type Response = Vec<C::ScalarField>;
+    type State = Vec<C::ScalarField>;
+
+    fn commit<R: CryptoRng>(&self, _: &Self::Witness, rng: &mut R) -> (Self::State, C) {
+        let k: Vec<C::ScalarField> = self
+            .generators
+            .iter()
+            .map(|_| C::ScalarField::random(rng))
+            .collect();
+        let commitment = self.combine(&k);
+        (k, commitment)
+    }
+
+    fn respond(
+        &self,
+        witness: &Self::Witness,
+        state: Self::State,
+        challenge: &Self::Challenge,
+    ) -> Self::Response {
+        state
+            .into_iter()
+            .zip(witness)
+            .map(|(k, x)| k + &(*challenge * x))
+            .collect()
+    }
+
+    fn verify(
+        &self,
+        commitment: &C,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool {
+        response.len() == self.generators.len()
+            && self.combine(response) == commitment.add(&self.public.scalar_mul(challenge))
+    }
+
+    fn simulate<R: CryptoRng>(
+        &self,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (C, Self::Response) {
+        let z: Vec<C::ScalarField> = self
+            .generators
+            .iter()
+            .map(|_| C::ScalarField::random(rng))
+            .collect();
+        let commitment = self.combine(&z).sub(&self.public.scalar_mul(challenge));
+        (commitment, z)
+    }
+
+    fn append_statement(&self, transcript: &mut Transcript) {
+        transcript.append_message(b"sigma", b"representation");
+        transcript.append_message(b"generators", &(self.generators.len() as u64).to_le_bytes());
+        for generator in &self.generators {
+            transcript.append_point(b"generator", generator);
+        }
+        transcript.append_point(b"public", &self.public);
+    }
+
+    fn append_commitment(&self, commitment: &C, transcript: &mut Transcript) {
+        transcript.append_point(b"commitment", commitment);
+    }
+}
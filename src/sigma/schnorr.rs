@@ -0,0 +1,88 @@
+use rand::CryptoRng;
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{ec::EllipticCurve, ring::Ring},
+    sigma::SigmaProtocol,
+    transcript::{ChallengeField, Transcript},
+};
+
+/// Schnorr's proof of knowledge of the discrete logarithm `x` of `X = x · G`.
+///
+/// The prover commits to `A = k · G` for a random `k`, and answers the challenge `c` with
+/// `z = k + c · x`; the verifier checks `z · G = A + c · X`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Schnorr<const LIMBS: usize, C> {
+    /// The generator `G`.
+    generator: C,
+    /// The public point `X`.
+    public: C,
+}
+
+impl<const LIMBS: usize, C> Schnorr<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+{
+    /// Creates the statement "I know the discrete logarithm of `public` to the base `generator`".
+    pub fn new(generator: C, public: C) -> Self {
+        Self { generator, public }
+    }
+}
+
+impl<const LIMBS: usize, C> SigmaProtocol for Schnorr<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS> + Abbreviate,
+    C::ScalarField: ChallengeField,
+{
+    type Witness = C::ScalarField;
+    type Commitment = C;
+    type Challenge = C::ScalarField;
+    type Response = C::ScalarField;
+    type State = C::ScalarField;
+
+    fn commit<R: CryptoRng>(&self, _: &Self::Witness, rng: &mut R) -> (Self::State, C) {
+        let k = C::ScalarField::random(rng);
+        (k, self.generator.scalar_mul(&k))
+    }
+
+    fn respond(
+        &self,
+        witness: &Self::Witness,
+        state: Self::State,
+        challenge: &Self::Challenge,
+    ) -> Self::Response {
+        state + &(*challenge * witness)
+    }
+
+    fn verify(
+        &self,
+        commitment: &C,
+        challenge: &Self::Challenge,
+        response: &Self::Response,
+    ) -> bool {
+        self.generator.scalar_mul(response) == commitment.add(&self.public.scalar_mul(challenge))
+    }
+
+    fn simulate<R: CryptoRng>(
+        &self,
+        challenge: &Self::Challenge,
+        rng: &mut R,
+    ) -> (C, Self::Response) {
+        let z = C::ScalarField::random(rng);
+        let commitment = self
+            .generator
+            .scalar_mul(&z)
+            .sub(&self.public.scalar_mul(challenge));
+        (commitment, z)
+    }
+
+    fn append_statement(&self, transcript: &mut Transcript) {
+        transcript.append_message(b"sigma", b"schnorr");
+        transcript.append_point(b"generator", &self.generator);
+        transcript.append_point(b"public", &self.public);
+    }
+
+    fn append_commitment(&self, commitment: &C, transcript: &mut Transcript) {
+        transcript.append_point(b"commitment", commitment);
+    }
+}
@@ -0,0 +1,366 @@
+//! Authenticated additive secret sharing, as used by the SPDZ family of protocols (Damgård, Pastro,
+//! Smart and Zakarias, *Multiparty Computation from Somewhat Homomorphic Encryption*, CRYPTO 2012).
+//!
+//! A global **MAC key** `α` is itself additively shared, party `i` holding `α_i`. A secret `x` is
+//! shared as two additive sharings at once: one of `x`, and one of its MAC `α · x`. Party `i` holds
+//! the pair `(x_i, m_i)` with `Σ x_i = x` and `Σ m_i = α · x`. Linear operations act on both
+//! components, so the relation survives every local computation; a party that later lies about its
+//! `x_i` when opening would have to shift `Σ m_i` by `α` times the error without knowing `α`, which
+//! succeeds with probability `1 / |F|`. The check itself is interactive; see
+//! [`crate::protocol::spdz`].
+//!
+//! The MAC key only stays secret if shares are **never sent whole**: each share carries the
+//! holder's key share `α_i`, which it needs to add public constants. The type implements
+//! [`LinearShare`](crate::ss::LinearShare) like the other schemes, so generic code can deal and
+//! combine it; keeping the MAC material private is up to the protocol that opens it. Open
+//! authenticated shares through the SPDZ protocols, which reveal the value component only.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use crate::{abbreviate::Abbreviate, math::ring::Ring, net::PartyId};
+use rand::CryptoRng;
+use serde::{Deserialize, Serialize};
+
+use super::{additive::AdditiveSS, LinearShare, ShareError};
+
+/// The global MAC key `α`, additively shared among the parties.
+///
+/// `shares()[i]` is the key share `α_i` of the `i`-th party of the party list the key was created
+/// for. Whoever holds a `MacKey` knows `α`, so only a (trusted) dealer ever builds one whole; the
+/// parties each keep just their own `α_i`, inside their [`AuthenticatedSS`] shares.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct MacKey<F> {
+    /// The key share of every party, positionally.
+    shares: Vec<F>,
+}
+
+impl<F: Ring> MacKey<F> {
+    /// Creates a key from the parties' key shares; the key is their sum.
+    pub fn new(shares: Vec<F>) -> Self {
+        Self { shares }
+    }
+
+    /// Samples a uniform key for `n_parties` parties, sharing it additively among them.
+    ///
+    /// `rng` is bound on [`CryptoRng`] because the key is what makes the MACs unforgeable.
+    pub fn random<R: CryptoRng>(n_parties: usize, rng: &mut R) -> Self {
+        Self {
+            shares: (0..n_parties).map(|_| F::random(rng)).collect(),
+        }
+    }
+
+    /// Returns the key shares, one per party.
+    pub fn shares(&self) -> &[F] {
+        &self.shares
+    }
+
+    /// Returns the key `α` itself, the sum of the key shares.
+    pub fn key(&self) -> F {
+        self.shares.iter().fold(F::ZERO, |acc, share| acc + share)
+    }
+}
+
+/// Represents an authenticated additive share held by one party: a share of a secret `x`, a
+/// share of its MAC `α · x`, and the holder's share of the MAC key `α`.
+///
+/// As in [`AdditiveSS`], the **leader** — the party with the smallest id — alone absorbs public
+/// constants into the value component. The MAC component of `x + c` is `α · x + α · c`, and
+/// `α · c = Σ α_i · c`, so on adding `c` *every* party adds `α_i · c` to its MAC share. That is
+/// why each share carries the holder's key share.
+///
+/// Reconstruction from a full set of shares checks the MAC; interactive opening, which must not
+/// reveal the MAC shares or the key, is done by the protocols in [`crate::protocol::spdz`].
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq, Clone)]
+pub struct AuthenticatedSS<F> {
+    /// The holder's share of the secret.
+    value: F,
+    /// The holder's share of the MAC `α · x`.
+    mac: F,
+    /// The holder's share of the MAC key `α`.
+    mac_key_share: F,
+    /// The party holding this share.
+    party: PartyId,
+    /// Whether this party is the leader that absorbs public constants.
+    is_leader: bool,
+}
+
+impl<F> Abbreviate for AuthenticatedSS<F> {
+    const ABBREVIATION: &'static str = "auth. shr.";
+}
+
+impl<F> AuthenticatedSS<F>
+where
+    F: Ring,
+{
+    /// Creates a new authenticated share from its components.
+    ///
+    /// This is the low-level constructor; most callers deal shares from a secret with
+    /// [`shares_from_secret`](AuthenticatedSS::shares_from_secret).
+    pub fn new(value: F, mac: F, mac_key_share: F, party: PartyId, is_leader: bool) -> Self {
+        Self {
+            value,
+            mac,
+            mac_key_share,
+            party,
+            is_leader,
+        }
+    }
+
+    /// Returns the holder's share of the secret.
+    pub fn share(&self) -> &F {
+        &self.value
+    }
+
+    /// Returns the holder's share of the MAC of the secret.
+    pub fn mac(&self) -> &F {
+        &self.mac
+    }
+
+    /// Returns the holder's share of the MAC key.
+    pub fn mac_key_share(&self) -> &F {
+        &self.mac_key_share
+    }
+
+    /// Returns the party that holds this share.
+    pub fn party(&self) -> PartyId {
+        self.party
+    }
+
+    /// Returns whether the holder is the leader that absorbs public constants.
+    pub fn is_leader(&self) -> bool {
+        self.is_leader
+    }
+
+    /// Computes authenticated shares of `secret` under `key`, one for each party in `parties`.
+    ///
+    /// `key.shares()[i]` must be the key share of `parties[i]`. The value and the MAC are shared
+    /// independently, with the party of smallest id as the leader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scl_rs::math::field::mersenne61::Mersenne61;
+    /// use scl_rs::net::PartyId;
+    /// use scl_rs::ss::authenticated::{AuthenticatedSS, MacKey};
+    ///
+    /// let mut rng = rand::rng();
+    /// let parties: Vec<PartyId> = (0..3usize).map(PartyId::from).collect();
+    /// let key = MacKey::random(parties.len(), &mut rng);
+    ///
+    /// let secret = Mersenne61::from(42u64);
+    /// let shares = AuthenticatedSS::shares_from_secret(secret, &parties, &key, &mut rng).unwrap();
+    ///
+    /// // Adding a public constant keeps the MAC valid.
+    /// let seven = Mersenne61::from(7u64);
+    /// let shifted: Vec<_> = shares.into_iter().map(|share| share + &seven).collect();
+    /// assert_eq!(AuthenticatedSS::secret_from_shares(&shifted).unwrap(), secret + &seven);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShareError::LengthMismatch`] if `key` does not hold one share per party.
+    pub fn shares_from_secret<R: CryptoRng>(
+        secret: F,
+        parties: &[PartyId],
+        key: &MacKey<F>,
+        rng: &mut R,
+    ) -> Result<Vec<Self>, ShareError<F>> {
+        if key.shares().len() != parties.len() {
+            return Err(ShareError::LengthMismatch {
+                parties_idx_len: parties.len(),
+                shares_len: key.shares().len(),
+            });
+        }
+        let values = AdditiveSS::shares_from_secret(secret, parties, rng);
+        let macs = AdditiveSS::shares_from_secret(key.key() * &secret, parties, rng);
+        Ok(values
+            .into_iter()
+            .zip(macs)
+            .zip(key.shares())
+            .map(|((value, mac), mac_key_share)| Self {
+                value: *value.share(),
+                mac: *mac.share(),
+                mac_key_share: *mac_key_share,
+                party: value.party(),
+                is_leader: Some(value.party()) == parties.iter().copied().min(),
+            })
+            .collect())
+    }
+
+    /// Reconstructs the secret from every party's share, checking its MAC.
+    ///
+    /// A full set of shares also reveals the MAC key, so this is for a party (or a test) that is
+    /// entitled to see everything; the parties themselves open values with
+    /// [`crate::protocol::spdz`], which never sends the MAC material.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShareError::NotEnoughShares`] if `shares` is empty and [`ShareError::InvalidMac`]
+    /// if the reconstructed secret does not match the reconstructed MAC.
+    pub fn secret_from_shares(shares: &[Self]) -> Result<F, ShareError<F>> {
+        if shares.is_empty() {
+            return Err(ShareError::NotEnoughShares);
+        }
+        let (secret, mac, key) =
+            shares
+                .iter()
+                .fold((F::ZERO, F::ZERO, F::ZERO), |(secret, mac, key), share| {
+                    (
+                        secret + &share.value,
+                        mac + &share.mac,
+                        key + &share.mac_key_share,
+                    )
+                });
+        if key * &secret != mac {
+            return Err(ShareError::InvalidMac);
+        }
+        Ok(secret)
+    }
+}
+
+// --- Local (communication-free) linear operations. See [`LinearShare`] for their MPC meaning. ---
+
+impl<F: Ring> Add<&Self> for AuthenticatedSS<F> {
+    type Output = Self;
+
+    /// Adds two shares held by the same party, component by component: `[x] + [y] = [x + y]`.
+    fn add(self, rhs: &Self) -> Self {
+        debug_assert_eq!(
+            self.party, rhs.party,
+            "cannot add authenticated shares held by different parties"
+        );
+        debug_assert_eq!(self.mac_key_share, rhs.mac_key_share);
+        Self {
+            value: self.value + &rhs.value,
+            mac: self.mac + &rhs.mac,
+            ..self
+        }
+    }
+}
+
+impl<F: Ring> Add<&F> for AuthenticatedSS<F> {
+    type Output = Self;
+
+    /// Adds a public constant: `[x] + c = [x + c]`. Only the leader adds `c` to its value share,
+    /// but every party adds `α_i · c` to its MAC share, so the MACs sum to `α · (x + c)`.
+    fn add(self, rhs: &F) -> Self {
+        let value = if self.is_leader {
+            self.value + rhs
+        } else {
+            self.value
+        };
+        Self {
+            value,
+            mac: self.mac + &(self.mac_key_share * rhs),
+            ..self
+        }
+    }
+}
+
+impl<F: Ring> Sub<&Self> for AuthenticatedSS<F> {
+    type Output = Self;
+
+    /// Subtracts two shares held by the same party, component by component: `[x] - [y] = [x - y]`.
+    fn sub(self, rhs: &Self) -> Self {
+        debug_assert_eq!(
+            self.party, rhs.party,
+            "cannot subtract authenticated shares held by different parties"
+        );
+        debug_assert_eq!(self.mac_key_share, rhs.mac_key_share);
+        Self {
+            value: self.value - &rhs.value,
+            mac: self.mac - &rhs.mac,
+            ..self
+        }
+    }
+}
+
+impl<F: Ring> Sub<&F> for AuthenticatedSS<F> {
+    type Output = Self;
+
+    /// Subtracts a public constant: `[x] - c = [x - c]`. As for addition, only the leader adjusts
+    /// its value share, while every party subtracts `α_i · c` from its MAC share.
+    fn sub(self, rhs: &F) -> Self {
+        let value = if self.is_leader {
+            self.value - rhs
+        } else {
+            self.value
+        };
+        Self {
+            value,
+            mac: self.mac - &(self.mac_key_share * rhs),
+            ..self
+        }
+    }
+}
+
+impl<F: Ring> Mul<&F> for AuthenticatedSS<F> {
+    type Output = Self;
+
+    /// Multiplies by a public scalar: `c · [x] = [c · x]`. Every party scales both its value and
+    /// its MAC share.
+    fn mul(self, rhs: &F) -> Self {
+        Self {
+            value: self.value * rhs,
+            mac: self.mac * rhs,
+            ..self
+        }
+    }
+}
+
+impl<F: Ring> Neg for AuthenticatedSS<F> {
+    type Output = Self;
+
+    /// Negates a share: `-[x] = [-x]`, negating both components.
+    fn neg(self) -> Self {
+        Self {
+            value: self.value.negate(),
+            mac: self.mac.negate(),
+            ..self
+        }
+    }
+}
+
+impl<F: Ring> LinearShare for AuthenticatedSS<F>
+where
+    F: Send + Sync,
+{
+    type Value = F;
+
+    /// Dealing an authenticated sharing needs the MAC key: the dealer passes the parties' key
+    /// shares, which only a trusted dealer can know.
+    type Threshold = MacKey<F>;
+
+    /// Authenticated sharing does not place parties in the field, so this is never consulted and
+    /// simply returns the zero element.
+    fn encode_party(_party: PartyId) -> F {
+        F::ZERO
+    }
+
+    fn secret_from_shares(shares: &[Self], parties: &[PartyId]) -> Result<F, ShareError<F>> {
+        if shares.len() != parties.len() {
+            return Err(ShareError::LengthMismatch {
+                parties_idx_len: parties.len(),
+                shares_len: shares.len(),
+            });
+        }
+        // Resolves to the inherent `secret_from_shares(&[Self])` (single argument).
+        Self::secret_from_shares(shares)
+    }
+
+    /// Deals `secret` over `parties` under the MAC key whose shares are given as the threshold
+    /// parameter.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShareError::LengthMismatch`] if `key` does not hold one share per party.
+    fn shares_from_secret<R: CryptoRng>(
+        secret: F,
+        parties: &[PartyId],
+        key: MacKey<F>,
+        rng: &mut R,
+    ) -> Result<Vec<Self>, ShareError<F>> {
+        // Resolves to the inherent `shares_from_secret(F, &[PartyId], &MacKey<F>, _)`.
+        Self::shares_from_secret(secret, parties, &key, rng)
+    }
+}
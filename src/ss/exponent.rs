@@ -0,0 +1,209 @@
+//! Shamir sharing **in the exponent**: shares of `x · P` for a secret scalar `x` and a public
+//! point `P`.
+//!
+//! If the parties hold a Shamir sharing `[x]` over the scalar field of an elliptic curve, each
+//! party can locally multiply its share `x_i` by a public point `P` to obtain `x_i · P`. Since
+//! Shamir reconstruction is linear, interpolating these points with the usual Lagrange
+//! coefficients gives `Σ λ_i · (x_i · P) = x · P` — Lagrange interpolation "in the exponent" (the
+//! name comes from multiplicative notation, where the points are `P^{x_i}`). The secret `x` itself
+//! is never reconstructed.
+//!
+//! This is the building block of threshold cryptography over elliptic curves: a public key
+//! `x · G`, an ElGamal decryption share `x_i · C_1`, and a Schnorr nonce commitment `k · G` are
+//! all opened this way. Party points are placed exactly as in
+//! [`ShamirSS`](crate::ss::shamir::ShamirSS), through its
+//! [`encode_party`](crate::ss::LinearShare::encode_party), so a sharing dealt with `ShamirSS` lifts
+//! directly. For the interactive opening, see
+//! [`PassiveOpenInExponent`](crate::protocol::passive_shamir::open_exponent::PassiveOpenInExponent).
+//!
+//! # Examples
+//!
+//! ```
+//! use scl_rs::math::ec::{secp256k1::Secp256k1, EllipticCurve};
+//! use scl_rs::math::field::secp256k1_scalar::Secp256k1ScalarField;
+//! use scl_rs::net::PartyId;
+//! use scl_rs::prelude::Ring;
+//! use scl_rs::ss::{exponent::ExponentSS, shamir::ShamirSS, LinearShare};
+//!
+//! let mut rng = rand::rng();
+//! let parties: Vec<PartyId> = (0..4usize).map(PartyId::from).collect();
+//! let x = Secp256k1ScalarField::random(&mut rng);
+//! let shares = <ShamirSS<4, Secp256k1ScalarField> as LinearShare>::shares_from_secret(
+//!     x, &parties, 1, &mut rng,
+//! )
+//! .unwrap();
+//!
+//! // Any two parties' shares in the exponent reconstruct x · G, without revealing x.
+//! let lifted: Vec<ExponentSS<4, Secp256k1>> = shares[2..]
+//!     .iter()
+//!     .map(|share| ExponentSS::from_share(share, &Secp256k1::gen()))
+//!     .collect();
+//! let public = ExponentSS::secret_from_shares(&lifted, &parties[2..]).unwrap();
+//! assert_eq!(public, Secp256k1::gen().scalar_mul(&x));
+//! ```
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{ec::EllipticCurve, poly::compute_lagrange_basis, ring::Ring},
+    net::PartyId,
+};
+
+use super::{shamir::ShamirSS, LinearShare, ShareError};
+
+/// A Shamir share in the exponent: the point `x_i · P` for a share `x_i` of a degree-`degree`
+/// sharing of `x`.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize, Clone, Copy)]
+#[serde(bound(serialize = "C: Serialize", deserialize = "C: Deserialize<'de>"))]
+pub struct ExponentSS<const LIMBS: usize, C> {
+    /// The share, as a point.
+    point: C,
+    /// The degree of the underlying sharing polynomial.
+    degree: usize,
+}
+
+impl<const LIMBS: usize, C> Abbreviate for ExponentSS<LIMBS, C> {
+    const ABBREVIATION: &'static str = "exp. shr.";
+}
+
+impl<const LIMBS: usize, C> ExponentSS<LIMBS, C>
+where
+    C: EllipticCurve<LIMBS>,
+    C::ScalarField: From<u64> + Send + Sync,
+{
+    /// Creates a share in the exponent from the point it consists of and the degree of the
+    /// underlying sharing.
+    pub fn new(point: C, degree: usize) -> Self {
+        Self { point, degree }
+    }
+
+    /// Lifts a Shamir share `x_i` to the share in the exponent `x_i · base`. No communication is
+    /// needed.
+    pub fn from_share(share: &ShamirSS<LIMBS, C::ScalarField>, base: &C) -> Self {
+        Self {
+            point: base.scalar_mul(share.share()),
+            degree: share.degree(),
+        }
+    }
+
+    /// Returns the share, as a point.
+    pub fn point(&self) -> &C {
+        &self.point
+    }
+
+    /// Returns the degree of the underlying sharing.
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Maps a party to its evaluation point in the scalar field — the same point [`ShamirSS`] uses.
+    pub fn encode_party(party: PartyId) -> C::ScalarField {
+        <ShamirSS<LIMBS, C::ScalarField> as LinearShare>::encode_party(party)
+    }
+
+    /// Interpolates the shares held by `parties` in the exponent and evaluates the result at `x`,
+    /// returning `p(x) · P` for the sharing polynomial `p`. Positional, as in [`LinearShare`]:
+    /// `shares[i]` is held by `parties[i]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShareError::LengthMismatch`] if the slices differ in length,
+    /// [`ShareError::SharesWithDifferentDegree`] if the shares disagree on the degree,
+    /// [`ShareError::NotEnoughShares`] if there are fewer than `degree + 1` shares, and
+    /// [`ShareError::ReconstructionError`] if a party is listed twice.
+    pub fn interpolate_at(
+        shares: &[Self],
+        parties: &[PartyId],
+        x: &C::ScalarField,
+    ) -> Result<C, ShareError<C::ScalarField>> {
+        if shares.len() != parties.len() {
+            return Err(ShareError::LengthMismatch {
+                parties_idx_len: parties.len(),
+                shares_len: shares.len(),
+            });
+        }
+        let Some(first) = shares.first() else {
+            return Err(ShareError::NotEnoughShares);
+        };
+        if shares.iter().any(|share| share.degree != first.degree) {
+            return Err(ShareError::SharesWithDifferentDegree);
+        }
+        if shares.len() < first.degree + 1 {
+            return Err(ShareError::NotEnoughShares);
+        }
+
+        let nodes: Vec<C::ScalarField> = parties.iter().copied().map(Self::encode_party).collect();
+        let basis = compute_lagrange_basis(&nodes, x).map_err(ShareError::ReconstructionError)?;
+        Ok(shares
+            .iter()
+            .zip(&basis)
+            .fold(C::ZERO, |acc, (share, lambda)| {
+                acc.add(&share.point.scalar_mul(lambda))
+            }))
+    }
+
+    /// Reconstructs `x · P` from the shares in the exponent held by `parties`: interpolation in
+    /// the exponent at zero.
+    ///
+    /// # Errors
+    ///
+    /// As for [`interpolate_at`](Self::interpolate_at).
+    pub fn secret_from_shares(
+        shares: &[Self],
+        parties: &[PartyId],
+    ) -> Result<C, ShareError<C::ScalarField>> {
+        Self::interpolate_at(shares, parties, &C::ScalarField::ZERO)
+    }
+}
+
+impl<const LIMBS: usize, C: EllipticCurve<LIMBS>> Add<&Self> for ExponentSS<LIMBS, C> {
+    type Output = Self;
+
+    /// Adds two shares in the exponent of sharings of the same degree, giving a share of
+    /// `(x + y) · P`.
+    fn add(self, rhs: &Self) -> Self {
+        debug_assert_eq!(self.degree, rhs.degree);
+        Self {
+            point: self.point.add(&rhs.point),
+            degree: self.degree,
+        }
+    }
+}
+
+impl<const LIMBS: usize, C: EllipticCurve<LIMBS>> Sub<&Self> for ExponentSS<LIMBS, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: &Self) -> Self {
+        debug_assert_eq!(self.degree, rhs.degree);
+        Self {
+            point: self.point.sub(&rhs.point),
+            degree: self.degree,
+        }
+    }
+}
+
+impl<const LIMBS: usize, C: EllipticCurve<LIMBS>> Mul<&C::ScalarField> for ExponentSS<LIMBS, C> {
+    type Output = Self;
+
+    /// Multiplies by a public scalar `c`, giving a share of `(c · x) · P`.
+    fn mul(self, rhs: &C::ScalarField) -> Self {
+        Self {
+            point: self.point.scalar_mul(rhs),
+            degree: self.degree,
+        }
+    }
+}
+
+impl<const LIMBS: usize, C: EllipticCurve<LIMBS>> Neg for ExponentSS<LIMBS, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            point: self.point.negate(),
+            degree: self.degree,
+        }
+    }
+}
@@ -75,6 +75,12 @@ impl<const LIMBS: usize, C: EllipticCurve<LIMBS>> FeldmanSS<LIMBS, C> {
         &self.shamir_share
     }
 
+    /// Returns the dealer's commitments `a_k · G` to the coefficients of the sharing polynomial,
+    /// constant term first.
+    pub fn commitments(&self) -> &[C] {
+        &self.commitments
+    }
+
     /// Computes the Feldman Shares of a secret element.
     ///
     /// The underlying Shamir sharing hides the secret in the polynomial coefficients it samples, so
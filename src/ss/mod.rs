@@ -2,8 +2,11 @@
 //! schemes currently supported are:
 //!
 //! - Additive secret sharing scheme,
-//! - Feldman secret sharing scheme, and
-//! - Shamir secret sharing scheme.
+//! - Feldman secret sharing scheme,
+//! - Shamir secret sharing scheme,
+//! - XOR (boolean) secret sharing scheme,
+//! - authenticated (MAC-carrying) additive secret sharing scheme, and
+//! - Shamir secret sharing in the exponent of an elliptic curve.
 //!
 //! For more information about how the schemes work, please refer to each module.
 //!
@@ -15,12 +18,21 @@
 /// Implements additive secret sharing scheme.
 pub mod additive;
 
+/// Implements authenticated (MAC-carrying) additive secret sharing scheme.
+pub mod authenticated;
+
+/// Implements Shamir secret sharing in the exponent of an elliptic curve.
+pub mod exponent;
+
 /// Implements Feldman secret sharing scheme.
 pub mod feldman;
 
 /// Implements Shamir secret sharing scheme.
 pub mod shamir;
 
+/// Implements XOR (boolean) secret sharing scheme.
+pub mod xor;
+
 use std::ops::{Add, Mul, Neg, Sub};
 
 use crate::{math::ring::Ring, net::PartyId};
@@ -59,6 +71,10 @@ pub enum ShareError<T: Ring> {
         /// Length of shares.
         shares_len: usize,
     },
+    /// The shares reconstruct a value that does not match their MACs: at least one share was
+    /// tampered with.
+    #[error("the shares do not carry a valid MAC for the reconstructed secret")]
+    InvalidMac,
     /// The share is not valid.
     #[error("invalid share from party {party_idx:?}")]
     InvalidShare {
@@ -68,6 +84,13 @@ pub enum ShareError<T: Ring> {
     /// One of the parties has index zero when computing the shares.
     #[error("one of the parties has index zero when computing the shares")]
     ZeroPartyId,
+    /// A party is not a member of the party set an operation was asked to work over — e.g. the
+    /// reconstruction set of a Shamir-to-additive conversion.
+    #[error("party {party:?} is not in the party set")]
+    PartyNotInSet {
+        /// The party that was expected in the set.
+        party: PartyId,
+    },
     /// The dealing threshold cannot be satisfied by the given number of parties — e.g. a Shamir
     /// polynomial degree so high that not even all the dealt shares could reconstruct the secret.
     #[error("invalid reconstruction threshold {threshold} for {n_parties} parties")]
@@ -157,8 +180,9 @@ pub trait LinearShare:
     /// Schemes with a caller-chosen threshold expose it here: for Shamir this is the polynomial
     /// **degree** `t` — any `t + 1` shares reconstruct. Schemes whose threshold is structural use
     /// `()`: additive sharing always requires **all** shares, so there is nothing to choose — and
-    /// no parameter to silently ignore.
-    type Threshold: Copy + Send + Sync;
+    /// no parameter to silently ignore. Schemes that need more than a number to deal carry it here
+    /// too: authenticated sharing passes the parties' shares of the MAC key.
+    type Threshold: Clone + Send + Sync;
 
     /// Maps a party to its point in [`Value`](LinearShare::Value).
     ///
@@ -4,7 +4,7 @@ use crate::{
     abbreviate::Abbreviate,
     math::{
         field::FiniteField,
-        poly::{interpolate_polynomial_at, Polynomial},
+        poly::{compute_lagrange_basis, interpolate_polynomial_at, Polynomial},
         ring::Ring,
     },
     net::PartyId,
@@ -12,7 +12,7 @@ use crate::{
 use rand::CryptoRng;
 use serde::{Deserialize, Serialize};
 
-use super::{LinearShare, ShareError};
+use super::{additive::AdditiveSS, LinearShare, ShareError};
 
 /// Represents a Shamir secret share computed with a polynomial of degree `degree`.
 #[derive(Serialize, Clone, Debug, PartialEq, Eq, Deserialize)]
@@ -284,6 +284,76 @@ where
     }
 }
 
+impl<const LIMBS: usize, F> ShamirSS<LIMBS, F>
+where
+    F: FiniteField<LIMBS> + From<u64> + Send + Sync,
+{
+    /// Converts this share, held by `party`, into an additive share of the same secret among the
+    /// parties of `reconstruction_set`. No communication is needed.
+    ///
+    /// The secret is `s = Σ λ_i · s_i` over any `degree + 1` or more parties, where `λ_i` are the
+    /// Lagrange coefficients of that set at zero, so each party in the set simply scales its share
+    /// by its own `λ_i`. Every party must pass the same `reconstruction_set`, and only the members
+    /// of that set hold a component of the resulting sharing. As with
+    /// [`AdditiveSS::shares_from_secret`], the smallest party id in the set becomes the leader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use scl_rs::math::field::mersenne61::Mersenne61;
+    /// use scl_rs::net::PartyId;
+    /// use scl_rs::ss::{additive::AdditiveSS, shamir::ShamirSS, LinearShare};
+    ///
+    /// let secret = Mersenne61::from(99u64);
+    /// let parties: Vec<PartyId> = (0..5usize).map(PartyId::from).collect();
+    /// let mut rng = rand::rng();
+    /// let shares = <ShamirSS<1, Mersenne61> as LinearShare>::shares_from_secret(
+    ///     secret, &parties, 2, &mut rng,
+    /// )
+    /// .unwrap();
+    ///
+    /// // Any three parties can turn their Shamir shares into additive ones.
+    /// let set = &parties[1..4];
+    /// let additive: Vec<AdditiveSS<Mersenne61>> = set
+    ///     .iter()
+    ///     .map(|party| shares[party.as_usize()].to_additive(*party, set).unwrap())
+    ///     .collect();
+    /// assert_eq!(AdditiveSS::secret_from_shares(&additive), secret);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ShareError::PartyNotInSet`] if `party` is not in `reconstruction_set`,
+    /// [`ShareError::NotEnoughShares`] if the set has fewer than `degree + 1` parties, and
+    /// [`ShareError::ReconstructionError`] if it lists a party twice.
+    pub fn to_additive(
+        &self,
+        party: PartyId,
+        reconstruction_set: &[PartyId],
+    ) -> Result<AdditiveSS<F>, ShareError<F>> {
+        let position = reconstruction_set
+            .iter()
+            .position(|member| *member == party)
+            .ok_or(ShareError::PartyNotInSet { party })?;
+        if reconstruction_set.len() < self.degree + 1 {
+            return Err(ShareError::NotEnoughShares);
+        }
+        let points: Vec<F> = reconstruction_set
+            .iter()
+            .copied()
+            .map(<Self as LinearShare>::encode_party)
+            .collect();
+        let lagrange =
+            compute_lagrange_basis(&points, &F::ZERO).map_err(ShareError::ReconstructionError)?;
+        let leader = reconstruction_set.iter().copied().min();
+        Ok(AdditiveSS::new(
+            self.share * &lagrange[position],
+            party,
+            Some(party) == leader,
+        ))
+    }
+}
+
 /// A **double sharing**: a degree-`t` and a degree-`2t` sharing of one and the same secret.
 ///
 /// Multiplying two degree-`t` shares locally gives a degree-`2t` sharing of the product whose
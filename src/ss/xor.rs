@@ -0,0 +1,34 @@
+//! XOR secret sharing splits a boolean secret `x` into random words `[x_1, x_2, ..., x_n]` such
+//! that `x = x_1 ⊕ x_2 ⊕ ... ⊕ x_n`. It is additive sharing over GF(2), and this module
+//! implements it as exactly that: [`XorSS`](crate::ss::xor::XorSS) is
+//! [`AdditiveSS`](crate::ss::additive::AdditiveSS) over the packed word
+//! [`Gf2Word`](crate::math::gf2::Gf2Word), so every share carries 64 independent boolean secrets,
+//! one per bit.
+//!
+//! All the additive machinery carries over: XOR of two shares is [`Add`](std::ops::Add), XOR with a
+//! public word is absorbed by the leader alone, and AND with a public word (a bit mask) is
+//! [`Mul`](std::ops::Mul) by a constant. The AND of two *shared* words needs interaction; see
+//! [`crate::protocol::gmw`].
+//!
+//! # Examples
+//!
+//! ```
+//! use scl_rs::math::{gf2::Gf2Word, ring::Ring};
+//! use scl_rs::net::PartyId;
+//! use scl_rs::ss::xor::XorSS;
+//!
+//! let parties: Vec<PartyId> = (0..3usize).map(PartyId::from).collect();
+//! let x = Gf2Word::from(0b1011u64);
+//! let shares = XorSS::shares_from_secret(x, &parties, &mut rand::rng());
+//!
+//! // NOT is XOR with the all-ones word, which only the leader applies.
+//! let not_x: Vec<XorSS> = shares.into_iter().map(|share| share + &Gf2Word::ONE).collect();
+//! assert_eq!(XorSS::secret_from_shares(&not_x), Gf2Word::from(!0b1011u64));
+//! ```
+
+use crate::math::gf2::Gf2Word;
+
+use super::additive::AdditiveSS;
+
+/// An XOR share: an additive share over [`Gf2Word`], packing 64 boolean shares into one word.
+pub type XorSS = AdditiveSS<Gf2Word>;
@@ -0,0 +1,239 @@
+//! A running hash of a proof's messages, from which non-interactive challenges are derived.
+//!
+//! The Fiat–Shamir transform makes an interactive proof non-interactive by replacing every
+//! verifier challenge with a hash of everything sent before it. A
+//! [`Transcript`](crate::transcript::Transcript) keeps that hash as the proof goes: the prover and
+//! the verifier append the same labeled messages in the same order, and squeeze the same
+//! challenges out of it. Labels, and the domain the transcript is started with, keep messages of
+//! different proofs and different positions from being confused with one another.
+//!
+//! Field elements and curve points are appended in their canonical encodings, so equal values
+//! always hash alike, and challenges come out as field elements by wide reduction. Every append
+//! and squeeze is also logged with the value's [`Abbreviate`](crate::abbreviate::Abbreviate)
+//! label, and a transcript displays as that log, which shows what went into a challenge when a
+//! prover and a verifier disagree:
+//!
+//! ```text
+//! domain      example    (bytes)
+//! append      generator  (EC elem.)
+//! append      public     (EC elem.)
+//! append      commitment (EC elem.)
+//! squeeze     challenge  (field elem.)
+//! ```
+
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+
+use crate::{
+    abbreviate::Abbreviate,
+    math::{
+        ec::EllipticCurve,
+        field::{
+            mersenne61::Mersenne61, secp256k1_prime::Secp256k1PrimeField,
+            secp256k1_scalar::Secp256k1ScalarField, FiniteField,
+        },
+        ring::Ring,
+    },
+};
+
+/// The number of bytes a challenge is squeezed with beyond the field's size, so that its
+/// reduction is within statistical distance `2^-128` of uniform.
+const WIDE_REDUCTION_BYTES: usize = 16;
+
+/// A Fiat–Shamir transcript over SHA-256, in the style of Merlin.
+///
+/// Every appended message is hashed together with its label and its length, so no two sequences
+/// of messages hash alike. Squeezing a challenge hashes the current state with the challenge's
+/// label and length, in counter mode for as many bytes as needed, and then feeds the challenge
+/// back into the state, so later challenges depend on earlier ones.
+///
+/// # Examples
+///
+/// ```
+/// use scl_rs::math::ec::{secp256k1::Secp256k1, EllipticCurve};
+/// use scl_rs::math::field::secp256k1_scalar::Secp256k1ScalarField;
+/// use scl_rs::transcript::Transcript;
+///
+/// let mut prover = Transcript::new(b"example");
+/// prover.append_point(b"generator", &Secp256k1::gen());
+/// let c: Secp256k1ScalarField = prover.challenge_field(b"challenge");
+///
+/// // The verifier replays the same appends, and squeezes the same challenge.
+/// let mut verifier = Transcript::new(b"example");
+/// verifier.append_point(b"generator", &Secp256k1::gen());
+/// assert_eq!(verifier.challenge_field::<4, Secp256k1ScalarField>(b"challenge"), c);
+/// ```
+#[derive(Clone)]
+pub struct Transcript {
+    hasher: Sha256,
+    /// What was appended and squeezed, in order, for display.
+    log: Vec<Entry>,
+}
+
+/// One logged operation on a [`Transcript`].
+#[derive(Clone)]
+struct Entry {
+    operation: &'static str,
+    label: &'static [u8],
+    kind: &'static str,
+}
+
+impl Transcript {
+    /// Starts a transcript for the protocol or application named `domain`.
+    pub fn new(domain: &'static [u8]) -> Self {
+        let mut transcript = Self {
+            hasher: Sha256::new(),
+            log: Vec::new(),
+        };
+        transcript.absorb(b"scl-rs/transcript", domain);
+        transcript.log("domain", domain, "bytes");
+        transcript
+    }
+
+    /// Appends `message` under `label`.
+    pub fn append_message(&mut self, label: &'static [u8], message: &[u8]) {
+        self.absorb(label, message);
+        self.log("append", label, "bytes");
+    }
+
+    /// Appends the field element `value` under `label`, in its canonical encoding.
+    pub fn append_field<const LIMBS: usize, F>(&mut self, label: &'static [u8], value: &F)
+    where
+        F: FiniteField<LIMBS> + Abbreviate,
+    {
+        // Field elements are always stored reduced, so their serialization is canonical.
+        let bytes = postcard::to_allocvec(value).expect("field elements serialize");
+        self.absorb(label, &bytes);
+        self.log("append", label, F::ABBREVIATION);
+    }
+
+    /// Appends the curve point `point` under `label`, in its canonical encoding.
+    pub fn append_point<const LIMBS: usize, C>(&mut self, label: &'static [u8], point: &C)
+    where
+        C: EllipticCurve<LIMBS> + Abbreviate,
+    {
+        self.absorb(label, &point.to_canonical_bytes());
+        self.log("append", label, C::ABBREVIATION);
+    }
+
+    /// Squeezes a 32-byte challenge labeled `label` out of the transcript.
+    pub fn challenge_bytes(&mut self, label: &'static [u8]) -> [u8; 32] {
+        let mut challenge = [0; 32];
+        self.squeeze(label, &mut challenge);
+        self.log("squeeze", label, "bytes");
+        challenge
+    }
+
+    /// Squeezes a challenge labeled `label` out of the transcript, as a field element: the
+    /// field's size plus 16 bytes are read as an integer and reduced modulo the field's order, so
+    /// the challenge is within statistical distance `2^-128` of uniform.
+    pub fn challenge_field<const LIMBS: usize, F>(&mut self, label: &'static [u8]) -> F
+    where
+        F: FiniteField<LIMBS> + Abbreviate,
+    {
+        let mut bytes = vec![0; F::BIT_SIZE.div_ceil(8) + WIDE_REDUCTION_BYTES];
+        self.squeeze(label, &mut bytes);
+        self.log("squeeze", label, F::ABBREVIATION);
+
+        // Horner's rule over the big-endian bytes, with every step reduced in the field.
+        let mut digits = Vec::with_capacity(256);
+        let mut digit = F::ZERO;
+        for _ in 0..256 {
+            digits.push(digit);
+            digit = digit + &F::ONE;
+        }
+        let base = digit;
+        bytes
+            .iter()
+            .fold(F::ZERO, |acc, byte| acc * &base + &digits[*byte as usize])
+    }
+
+    /// Hashes `message` into the state under `label`, without logging it.
+    fn absorb(&mut self, label: &[u8], message: &[u8]) {
+        for part in [label, message] {
+            self.hasher.update((part.len() as u64).to_le_bytes());
+            self.hasher.update(part);
+        }
+    }
+
+    /// Fills `out` with challenge bytes labeled `label`, and feeds them back into the state.
+    fn squeeze(&mut self, label: &[u8], out: &mut [u8]) {
+        let mut prefix = self.hasher.clone();
+        prefix.update((label.len() as u64).to_le_bytes());
+        prefix.update(label);
+        prefix.update((out.len() as u64).to_le_bytes());
+        for (counter, block) in out.chunks_mut(32).enumerate() {
+            let mut hasher = prefix.clone();
+            hasher.update((counter as u64).to_le_bytes());
+            let digest = hasher.finalize();
+            block.copy_from_slice(&digest[..block.len()]);
+        }
+        self.absorb(b"challenge", out);
+    }
+
+    /// Records an operation for display.
+    fn log(&mut self, operation: &'static str, label: &'static [u8], kind: &'static str) {
+        self.log.push(Entry {
+            operation,
+            label,
+            kind,
+        });
+    }
+}
+
+/// A field that challenges can be squeezed as, by [`challenge_field`](Transcript::challenge_field).
+///
+/// It pins the field's number of limbs, so that code generic over the challenge type, such as the
+/// provided [`squeeze_challenge`](crate::sigma::SigmaProtocol::squeeze_challenge) of a Σ-protocol,
+/// can squeeze one.
+pub trait ChallengeField: Ring + Abbreviate {
+    /// Squeezes a challenge labeled `label` out of `transcript`, with
+    /// [`challenge_field`](Transcript::challenge_field).
+    fn squeeze(transcript: &mut Transcript, label: &'static [u8]) -> Self;
+}
+
+macro_rules! impl_challenge_field {
+    ($($t:ty => $limbs:literal),+ $(,)?) => {
+        $(impl ChallengeField for $t {
+            fn squeeze(transcript: &mut Transcript, label: &'static [u8]) -> Self {
+                transcript.challenge_field::<$limbs, Self>(label)
+            }
+        })+
+    };
+}
+
+impl_challenge_field!(
+    Mersenne61 => 1,
+    Secp256k1ScalarField => 4,
+    Secp256k1PrimeField => 4,
+);
+
+impl fmt::Display for Transcript {
+    /// One line per operation, in order: `domain`, `append` or `squeeze`, the label, and the
+    /// [`Abbreviate`] label of the value (`bytes` for raw messages).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self
+            .log
+            .iter()
+            .map(|entry| entry.label.len())
+            .max()
+            .unwrap_or(0);
+        for entry in &self.log {
+            writeln!(
+                f,
+                "{:<11} {:<width$} ({})",
+                entry.operation,
+                String::from_utf8_lossy(entry.label),
+                entry.kind,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Transcript {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
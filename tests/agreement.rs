@@ -0,0 +1,287 @@
+//! Tests for phase-king binary agreement and multivalued agreement (`protocol::agreement`), with
+//! silent and equivocating parties.
+
+use std::{collections::HashMap, time::Duration};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    net::{simulation::channel::SimpleNetworkConfig, Packet, PartyId},
+    prelude::{simulate, Environment, Error, GeneralEnv, Network, Protocol},
+    protocol::{
+        agreement::{binary::BinaryAgreement, multivalued::ByzantineAgreement, AgreementMessage},
+        ProtocolId,
+    },
+};
+
+const ROUND: Duration = Duration::from_secs(1);
+
+fn parties(n: usize) -> Vec<PartyId> {
+    (0..n).map(PartyId::from).collect()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Binary,
+    Multivalued,
+}
+
+/// How a party behaves.
+#[derive(Clone, Copy)]
+enum Behavior {
+    Honest,
+    /// Sends nothing.
+    Silent,
+    /// Sends one value to the parties below `split` and another to the others, in every round.
+    Equivocate {
+        split: usize,
+    },
+    /// Sends its messages of the first phase of binary agreement all at once, in the first round,
+    /// with no proposal and `true` as its value and as king, then stays silent.
+    Hasty,
+}
+
+/// One party of an agreement among parties tolerating `t` faults.
+struct Party {
+    kind: Kind,
+    behavior: Behavior,
+    /// The party's input; a bit is `"0"` or `"1"`.
+    input: String,
+    t: usize,
+}
+
+/// The value a party agreed on, `None` if it agreed on no value, or the error it failed with.
+type Outcome = Result<Option<String>, String>;
+
+impl Party {
+    /// A packet carrying `value` in `round`.
+    fn message<T: serde::Serialize>(round: u64, value: T) -> Packet {
+        let mut pkt = Packet::empty();
+        pkt.write(&AgreementMessage { round, value }).unwrap();
+        pkt
+    }
+}
+
+impl<E: Environment> Protocol<E> for Party {
+    type Output = Outcome;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        match self.behavior {
+            Behavior::Honest => {
+                let outcome = match self.kind {
+                    Kind::Binary => BinaryAgreement::new(self.input == "1", self.t, ROUND)
+                        .run(env)
+                        .await
+                        .map(|bit| Some(u8::from(bit).to_string())),
+                    Kind::Multivalued => {
+                        ByzantineAgreement::new(self.input, self.t, ROUND)
+                            .run(env)
+                            .await
+                    }
+                };
+                Ok(outcome.map_err(|err| err.to_string()))
+            }
+            Behavior::Silent => Ok(Err("silent".to_string())),
+            Behavior::Hasty => {
+                let packets = [
+                    Self::message(0, vec![true]),
+                    Self::message(1, vec![None::<bool>]),
+                    Self::message(2, vec![true]),
+                ];
+                for pkt in &packets {
+                    for party in env.network().party_ids() {
+                        if party != me {
+                            env.network_mut().send_to(party, pkt).await?;
+                        }
+                    }
+                }
+                Ok(Err("hasty".to_string()))
+            }
+            Behavior::Equivocate { split } => {
+                let value_rounds = match self.kind {
+                    Kind::Binary => 0,
+                    Kind::Multivalued => 2,
+                };
+                for round in 0..value_rounds + 3 * (self.t as u64 + 1) {
+                    let mut messages = Vec::new();
+                    for party in env.network().party_ids() {
+                        if party == me {
+                            continue;
+                        }
+                        let low = party.as_usize() < split;
+                        let pkt = if round < value_rounds {
+                            Self::message(round, if low { "a" } else { "b" }.to_string())
+                        } else if (round - value_rounds) % 3 == 1 {
+                            // Proposals may be missing.
+                            Self::message(round, vec![Some(low)])
+                        } else {
+                            Self::message(round, vec![low])
+                        };
+                        messages.push((party, pkt));
+                    }
+                    env.network_mut().send_many(&messages).await?;
+                    env.network_mut().sleep(ROUND).await?;
+                }
+                Ok(Err("equivocated".to_string()))
+            }
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("AgreementParty")
+    }
+}
+
+/// Runs an agreement among parties with the given inputs, with the given behavior for some of
+/// them, and returns every honest party's outcome.
+fn agree(
+    kind: Kind,
+    inputs: &[&str],
+    t: usize,
+    behaviors: &[(usize, Behavior)],
+) -> HashMap<PartyId, Outcome> {
+    let behaviors: HashMap<usize, Behavior> = behaviors.iter().copied().collect();
+    let mut outputs = simulate(
+        SimpleNetworkConfig::default(),
+        parties(inputs.len()),
+        |pid| Party {
+            kind,
+            behavior: behaviors
+                .get(&pid.as_usize())
+                .copied()
+                .unwrap_or(Behavior::Honest),
+            input: inputs[pid.as_usize()].to_string(),
+            t,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    outputs.retain(|party, _| !behaviors.contains_key(&party.as_usize()));
+    outputs
+}
+
+/// Asserts that every honest party agreed on the same value, and returns it.
+fn agreed(outcomes: &HashMap<PartyId, Outcome>) -> Option<String> {
+    let first = outcomes.values().next().unwrap();
+    for outcome in outcomes.values() {
+        assert_eq!(outcome, first);
+    }
+    first.clone().unwrap()
+}
+
+/// When every honest party has the same bit, they all agree on it, whatever the faulty parties
+/// send.
+#[test]
+fn binary_agreement_is_valid() {
+    for (n, t) in [(4, 1), (7, 2)] {
+        for bit in ["0", "1"] {
+            let equivocators: Vec<(usize, Behavior)> = (n - t..n)
+                .map(|i| (i, Behavior::Equivocate { split: n / 2 }))
+                .collect();
+            let outcomes = agree(Kind::Binary, &vec![bit; n], t, &equivocators);
+            assert_eq!(agreed(&outcomes), Some(bit.to_string()));
+        }
+    }
+}
+
+/// With mixed bits and faulty kings in the first phases, the honest parties still agree.
+#[test]
+fn binary_agreement_survives_faulty_kings() {
+    let inputs = ["0", "1", "0", "1", "0", "1", "1"];
+    for behavior in [Behavior::Silent, Behavior::Equivocate { split: 4 }] {
+        let outcomes = agree(Kind::Binary, &inputs, 2, &[(0, behavior), (1, behavior)]);
+        assert!(agreed(&outcomes).is_some());
+    }
+}
+
+/// Parties that send to some parties and not to others cannot split the honest ones.
+#[test]
+fn binary_agreement_survives_mixed_faults() {
+    let inputs = ["1", "0", "1", "0", "1", "0", "0"];
+    let outcomes = agree(
+        Kind::Binary,
+        &inputs,
+        2,
+        &[
+            (2, Behavior::Equivocate { split: 3 }),
+            (6, Behavior::Silent),
+        ],
+    );
+    assert!(agreed(&outcomes).is_some());
+}
+
+/// When every honest party has the same value, they all agree on it.
+#[test]
+fn multivalued_agreement_is_valid() {
+    let inputs = ["x"; 7];
+    for behavior in [Behavior::Silent, Behavior::Equivocate { split: 4 }] {
+        let outcomes = agree(
+            Kind::Multivalued,
+            &inputs,
+            2,
+            &[(0, behavior), (6, behavior)],
+        );
+        assert_eq!(agreed(&outcomes), Some("x".to_string()));
+    }
+}
+
+/// With different honest values, the honest parties agree on the same value or on none.
+#[test]
+fn multivalued_agreement_is_consistent() {
+    let inputs = ["x", "x", "y", "x", "y", "x", "x"];
+    for equivocators in [[0, 1], [5, 6]] {
+        let behaviors: Vec<(usize, Behavior)> = equivocators
+            .iter()
+            .map(|i| (*i, Behavior::Equivocate { split: 3 }))
+            .collect();
+        agreed(&agree(Kind::Multivalued, &inputs, 2, &behaviors));
+    }
+    // Too few honest parties hold the same value to settle on it.
+    let outcomes = agree(Kind::Multivalued, &inputs, 2, &[(0, Behavior::Silent)]);
+    assert_eq!(agreed(&outcomes), None);
+}
+
+/// Tolerating `t` faults needs more than `3t` parties.
+#[test]
+fn agreement_rejects_too_many_faults() {
+    for kind in [Kind::Binary, Kind::Multivalued] {
+        for outcome in agree(kind, &["1"; 3], 1, &[]).values() {
+            assert_eq!(*outcome, Err(Error::Input.to_string()));
+        }
+    }
+}
+
+/// Agreement messages are labeled in the simulator's traces.
+#[test]
+fn agreement_messages_are_labeled() {
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(4),
+        |_| Party {
+            kind: Kind::Multivalued,
+            behavior: Behavior::Honest,
+            input: "x".to_string(),
+            t: 1,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    assert!(outcome.traces[&PartyId::from(1)]
+        .to_string()
+        .contains("BA msg."));
+}
+
+/// A message that arrives rounds ahead of its round still counts in it: the first king sends its
+/// bit in the first round, and the honest parties, split on their inputs, all adopt it.
+#[test]
+fn early_messages_count_in_their_round() {
+    let outcomes = agree(
+        Kind::Binary,
+        &["1", "0", "0", "1"],
+        1,
+        &[(0, Behavior::Hasty)],
+    );
+    assert_eq!(agreed(&outcomes), Some("1".to_string()));
+}
@@ -0,0 +1,89 @@
+use rand::rngs::ThreadRng;
+use scl_rs::{
+    math::{field::mersenne61::Mersenne61, ring::Ring},
+    net::PartyId,
+    ss::{
+        authenticated::{AuthenticatedSS, MacKey},
+        LinearShare, ShareError,
+    },
+};
+
+type Share = AuthenticatedSS<Mersenne61>;
+
+fn setup(n: usize) -> (Vec<PartyId>, MacKey<Mersenne61>, ThreadRng) {
+    let mut rng = rand::rng();
+    let parties: Vec<PartyId> = (0..n).map(PartyId::from).collect();
+    let key = MacKey::random(n, &mut rng);
+    (parties, key, rng)
+}
+
+/// Every local operation keeps the MAC relation `Σ m_i = α · Σ x_i`, including the public-constant
+/// ones, where only the leader shifts its value but everyone shifts its MAC.
+#[test]
+fn linear_operations_preserve_the_mac() {
+    let (parties, key, mut rng) = setup(4);
+    let x = Mersenne61::random(&mut rng);
+    let y = Mersenne61::random(&mut rng);
+    let c = Mersenne61::random(&mut rng);
+    let xs = Share::shares_from_secret(x, &parties, &key, &mut rng).unwrap();
+    let ys = Share::shares_from_secret(y, &parties, &key, &mut rng).unwrap();
+
+    let combined: Vec<Share> = xs
+        .into_iter()
+        .zip(&ys)
+        .map(|(x, y)| -((x - y) * &c + &c) - &c)
+        .collect();
+    let expected = ((x - &y) * &c + &c).negate() - &c;
+    assert_eq!(Share::secret_from_shares(&combined).unwrap(), expected);
+}
+
+/// Dealing through `LinearShare` takes the MAC key shares as the dealing parameter.
+#[test]
+fn linear_share_deal_reconstructs() {
+    let (parties, key, mut rng) = setup(3);
+    let secret = Mersenne61::from(1234u64);
+    let shares =
+        <Share as LinearShare>::shares_from_secret(secret, &parties, key.clone(), &mut rng)
+            .unwrap();
+
+    assert_eq!(
+        <Share as LinearShare>::secret_from_shares(&shares, &parties).unwrap(),
+        secret
+    );
+    for (share, key_share) in shares.iter().zip(key.shares()) {
+        assert_eq!(share.mac_key_share(), key_share);
+    }
+    assert_eq!(shares.iter().filter(|share| share.is_leader()).count(), 1);
+}
+
+/// A share whose value was shifted no longer matches its MAC.
+#[test]
+fn tampered_share_fails_the_mac() {
+    let (parties, key, mut rng) = setup(3);
+    let mut shares =
+        Share::shares_from_secret(Mersenne61::from(5u64), &parties, &key, &mut rng).unwrap();
+    let victim = &shares[1];
+    shares[1] = Share::new(
+        *victim.share() + &Mersenne61::ONE,
+        *victim.mac(),
+        *victim.mac_key_share(),
+        victim.party(),
+        victim.is_leader(),
+    );
+
+    assert!(matches!(
+        Share::secret_from_shares(&shares),
+        Err(ShareError::InvalidMac)
+    ));
+}
+
+/// The key must hold one share per party.
+#[test]
+fn key_of_wrong_size_is_rejected() {
+    let (parties, _, mut rng) = setup(3);
+    let key = MacKey::random(2, &mut rng);
+    assert!(matches!(
+        Share::shares_from_secret(Mersenne61::ONE, &parties, &key, &mut rng),
+        Err(ShareError::LengthMismatch { .. })
+    ));
+}
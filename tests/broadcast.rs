@@ -0,0 +1,262 @@
+//! Tests for echo broadcast and Bracha's reliable broadcast (`protocol::broadcast`), with silent
+//! and equivocating parties.
+
+use std::{collections::HashMap, time::Duration};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    net::{simulation::channel::SimpleNetworkConfig, NetworkError, Packet, PartyId},
+    prelude::{simulate, Environment, Error, GeneralEnv, Network, Protocol},
+    protocol::{
+        broadcast::{bracha::BrachaBroadcast, echo::EchoBroadcast, BroadcastMessage},
+        ProtocolId,
+    },
+};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+const PAYLOAD: &str = "attack at dawn";
+
+fn parties(n: usize) -> Vec<PartyId> {
+    (0..n).map(PartyId::from).collect()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    Echo,
+    Bracha,
+}
+
+/// How a party behaves.
+#[derive(Clone, Copy)]
+enum Behavior {
+    Honest,
+    /// Sends nothing.
+    Silent,
+    /// Sends `"a"` to the parties below `split` and `"b"` to the others, at every step.
+    Equivocate {
+        split: usize,
+    },
+}
+
+/// One party of a broadcast from party 0 among `n` parties tolerating `t` faults.
+struct Party {
+    kind: Kind,
+    behavior: Behavior,
+    t: usize,
+}
+
+/// The value a party delivered, or the error it failed with.
+type Outcome = Result<String, String>;
+
+impl<E: Environment> Protocol<E> for Party {
+    type Output = Outcome;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let sender = PartyId::from(0);
+        match self.behavior {
+            Behavior::Honest => {
+                let outcome = match (self.kind, me == sender) {
+                    (Kind::Echo, true) => {
+                        EchoBroadcast::sender(sender, PAYLOAD.to_string(), self.t, TIMEOUT)
+                            .run(env)
+                            .await
+                    }
+                    (Kind::Echo, false) => {
+                        EchoBroadcast::receiver(sender, self.t, TIMEOUT)
+                            .run(env)
+                            .await
+                    }
+                    (Kind::Bracha, true) => {
+                        BrachaBroadcast::sender(sender, PAYLOAD.to_string(), self.t, TIMEOUT)
+                            .run(env)
+                            .await
+                    }
+                    (Kind::Bracha, false) => {
+                        BrachaBroadcast::receiver(sender, self.t, TIMEOUT)
+                            .run(env)
+                            .await
+                    }
+                };
+                Ok(outcome.map_err(|err| err.to_string()))
+            }
+            Behavior::Silent => Ok(Err("silent".to_string())),
+            Behavior::Equivocate { split } => {
+                // One message per step, as honest parties send them.
+                let steps: [fn(String) -> BroadcastMessage<String>; 3] = [
+                    BroadcastMessage::Send,
+                    BroadcastMessage::Echo,
+                    BroadcastMessage::Ready,
+                ];
+                let steps = if me == sender {
+                    &steps[..]
+                } else {
+                    &steps[1..]
+                };
+                for step in steps {
+                    let mut messages = Vec::new();
+                    for party in env.network().party_ids() {
+                        if party == me {
+                            continue;
+                        }
+                        let value = if party.as_usize() < split { "a" } else { "b" };
+                        let mut pkt = Packet::empty();
+                        pkt.write(&step(value.to_string()))?;
+                        messages.push((party, pkt));
+                    }
+                    env.network_mut().send_many(&messages).await?;
+                }
+                Ok(Err("equivocated".to_string()))
+            }
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("BroadcastParty")
+    }
+}
+
+/// Runs a broadcast from party 0 among `n` parties, with the given behavior for some of them, and
+/// returns every party's outcome.
+fn broadcast(
+    kind: Kind,
+    n: usize,
+    t: usize,
+    behaviors: &[(usize, Behavior)],
+) -> HashMap<PartyId, Outcome> {
+    let behaviors: HashMap<usize, Behavior> = behaviors.iter().copied().collect();
+    simulate(
+        SimpleNetworkConfig::default(),
+        parties(n),
+        |pid| Party {
+            kind,
+            behavior: behaviors
+                .get(&pid.as_usize())
+                .copied()
+                .unwrap_or(Behavior::Honest),
+            t,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs
+}
+
+fn timeout() -> String {
+    Error::Network(NetworkError::Timeout(None)).to_string()
+}
+
+/// With an honest sender, every honest party delivers its value, even with `t` silent parties.
+#[test]
+fn honest_sender_is_delivered_despite_silent_parties() {
+    for kind in [Kind::Echo, Kind::Bracha] {
+        for (n, t) in [(4, 1), (7, 2)] {
+            let silent: Vec<(usize, Behavior)> =
+                (n - t..n).map(|i| (i, Behavior::Silent)).collect();
+            let outcomes = broadcast(kind, n, t, &silent);
+            for i in 0..n - t {
+                assert_eq!(outcomes[&PartyId::from(i)], Ok(PAYLOAD.to_string()));
+            }
+        }
+    }
+}
+
+/// Parties that echo and announce a different value than the sender's do not stop delivery.
+#[test]
+fn byzantine_echoes_are_outvoted() {
+    for kind in [Kind::Echo, Kind::Bracha] {
+        let (n, t) = (7, 2);
+        let outcomes = broadcast(
+            kind,
+            n,
+            t,
+            &[
+                (5, Behavior::Equivocate { split: 0 }),
+                (6, Behavior::Equivocate { split: 4 }),
+            ],
+        );
+        for i in 0..n - t {
+            assert_eq!(outcomes[&PartyId::from(i)], Ok(PAYLOAD.to_string()));
+        }
+    }
+}
+
+/// A silent sender leaves every honest party without a value, and none of them blocks.
+#[test]
+fn silent_sender_times_out() {
+    for kind in [Kind::Echo, Kind::Bracha] {
+        let outcomes = broadcast(kind, 4, 1, &[(0, Behavior::Silent)]);
+        for i in 1..4 {
+            assert_eq!(outcomes[&PartyId::from(i)], Err(timeout()));
+        }
+    }
+}
+
+/// With an equivocating sender, echo broadcast stays consistent but not reliable: the parties
+/// that got the majority value deliver it, and the other one delivers nothing.
+#[test]
+fn echo_broadcast_is_consistent_under_equivocation() {
+    let outcomes = broadcast(Kind::Echo, 4, 1, &[(0, Behavior::Equivocate { split: 3 })]);
+    assert_eq!(outcomes[&PartyId::from(1)], Ok("a".to_string()));
+    assert_eq!(outcomes[&PartyId::from(2)], Ok("a".to_string()));
+    assert_eq!(outcomes[&PartyId::from(3)], Err(timeout()));
+}
+
+/// With an equivocating sender, Bracha broadcast is reliable: the parties that deliver pull the
+/// other one along with their readies, so every honest party delivers the same value.
+#[test]
+fn bracha_broadcast_is_reliable_under_equivocation() {
+    let outcomes = broadcast(
+        Kind::Bracha,
+        4,
+        1,
+        &[(0, Behavior::Equivocate { split: 3 })],
+    );
+    for i in 1..4 {
+        assert_eq!(outcomes[&PartyId::from(i)], Ok("a".to_string()));
+    }
+}
+
+/// An even split leaves no value with a quorum of echoes, and no honest party delivers.
+#[test]
+fn bracha_broadcast_delivers_nothing_without_an_echo_quorum() {
+    let outcomes = broadcast(
+        Kind::Bracha,
+        7,
+        2,
+        &[(0, Behavior::Equivocate { split: 4 })],
+    );
+    for i in 1..7 {
+        assert_eq!(outcomes[&PartyId::from(i)], Err(timeout()));
+    }
+}
+
+/// Tolerating `t` faults needs more than `3t` parties.
+#[test]
+fn broadcast_rejects_too_many_faults() {
+    for kind in [Kind::Echo, Kind::Bracha] {
+        for outcome in broadcast(kind, 3, 1, &[]).values() {
+            assert_eq!(*outcome, Err(Error::Input.to_string()));
+        }
+    }
+}
+
+/// Broadcast messages are labeled in the simulator's traces.
+#[test]
+fn broadcast_messages_are_labeled() {
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(4),
+        |_| Party {
+            kind: Kind::Bracha,
+            behavior: Behavior::Honest,
+            t: 1,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    assert!(outcome.traces[&PartyId::from(1)]
+        .to_string()
+        .contains("bcast msg."));
+}
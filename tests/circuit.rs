@@ -0,0 +1,318 @@
+//! Tests for arithmetic circuits (`circuit`): building, layering and evaluation in the clear, and
+//! their evaluation over Shamir sharing with `PassiveShamirCircuit` on the deterministic simulator.
+//!
+//! The parameters are `n = 5`, `t = 2`, as in `tests/passive_shamir.rs`.
+
+use std::collections::HashMap;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    circuit::{ArithCircuitError, ArithGate, ArithmeticCircuit},
+    math::field::mersenne61::Mersenne61,
+    net::{
+        simulation::{channel::SimpleNetworkConfig, event::EventType},
+        PartyId,
+    },
+    prelude::{simulate, Error, GeneralEnv, Protocol, RandEnvironment},
+    protocol::{
+        passive_shamir::{
+            circuit::PassiveShamirCircuit, double_rand_share::PassiveRandDoubleShr,
+            rand_share::PassiveRandShr, triple::PassiveTriple,
+        },
+        ProtocolId,
+    },
+};
+
+type F = Mersenne61;
+
+const N: usize = 5;
+const T: usize = 2;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+/// `(x0 · y0 + x1 · y1) · 3 · x0` for inputs `x` of party 0 and `y` of party 1, revealed to party
+/// 2, with `x0 + y1` also revealed to party 0: two multiplications at depth 1 and one at depth 2.
+fn inner_product_circuit() -> ArithmeticCircuit<F> {
+    let [p0, p1, p2] = [0, 1, 2].map(PartyId::from);
+    let mut circuit = ArithmeticCircuit::new();
+    let x = [circuit.input(p0), circuit.input(p0)];
+    let y = [circuit.input(p1), circuit.input(p1)];
+    let first = circuit.mul(x[0], y[0]);
+    let second = circuit.mul(x[1], y[1]);
+    let sum = circuit.add(first, second);
+    let scaled = circuit.const_mul(sum, F::from(3));
+    let result = circuit.mul(scaled, x[0]);
+    circuit.output(result, p2);
+    let linear = circuit.add(x[0], y[1]);
+    circuit.output(linear, p0);
+    circuit
+}
+
+/// Generates the triples for the circuit, `n - t` per pass, then evaluates it.
+struct GenAndEvaluate {
+    circuit: ArithmeticCircuit<F>,
+    inputs: Vec<F>,
+}
+
+impl<E: RandEnvironment> Protocol<E> for GenAndEvaluate {
+    type Output = Result<Vec<F>, String>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let king = PartyId::from(0);
+        let mut triples = Vec::new();
+        while triples.len() < self.circuit.mul_count() {
+            let a = PassiveRandShr::<1, F>::new(T, parties())?.run(env).await?;
+            let b = PassiveRandShr::<1, F>::new(T, parties())?.run(env).await?;
+            let doubles = PassiveRandDoubleShr::<1, F>::new(T, parties())?
+                .run(env)
+                .await?;
+            triples.extend(
+                PassiveTriple::new(king, parties(), a, b, doubles)?
+                    .run(env)
+                    .await?,
+            );
+        }
+        triples.truncate(self.circuit.mul_count());
+
+        let evaluation =
+            PassiveShamirCircuit::new(self.circuit, parties(), king, T, self.inputs, triples);
+        Ok(match evaluation {
+            Ok(protocol) => protocol.run(env).await.map_err(|error| error.to_string()),
+            Err(error) => Err(error.to_string()),
+        })
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("GenAndEvaluate")
+    }
+}
+
+/// Runs the circuit securely on `inputs` and returns every party's output.
+fn evaluate(
+    circuit: &ArithmeticCircuit<F>,
+    inputs: &HashMap<PartyId, Vec<F>>,
+) -> HashMap<PartyId, Result<Vec<F>, String>> {
+    simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| GenAndEvaluate {
+            circuit: circuit.clone(),
+            inputs: inputs.get(&pid).cloned().unwrap_or_default(),
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs
+}
+
+fn inputs() -> HashMap<PartyId, Vec<F>> {
+    HashMap::from([
+        (PartyId::from(0), vec![F::from(2), F::from(5)]),
+        (PartyId::from(1), vec![F::from(7), F::from(11)]),
+    ])
+}
+
+#[test]
+fn circuit_evaluates_in_the_clear() {
+    let circuit = inner_product_circuit();
+    let outputs = circuit.evaluate(&inputs()).unwrap();
+    // (2 · 7 + 5 · 11) · 3 · 2 = 414, and 2 + 11 = 13.
+    assert_eq!(
+        outputs,
+        HashMap::from([
+            (PartyId::from(2), vec![F::from(414)]),
+            (PartyId::from(0), vec![F::from(13)]),
+        ])
+    );
+
+    let mut missing = inputs();
+    missing.remove(&PartyId::from(1));
+    assert!(matches!(
+        circuit.evaluate(&missing),
+        Err(ArithCircuitError::InputCount {
+            expected: 2,
+            found: 0,
+            ..
+        })
+    ));
+    let mut extra = inputs();
+    extra.insert(PartyId::from(3), vec![F::from(1)]);
+    assert!(matches!(
+        circuit.evaluate(&extra),
+        Err(ArithCircuitError::InputCount {
+            expected: 0,
+            found: 1,
+            ..
+        })
+    ));
+}
+
+#[test]
+fn layers_group_multiplications_by_depth() {
+    let circuit = inner_product_circuit();
+    assert_eq!(circuit.mul_count(), 3);
+    assert_eq!(circuit.depth(), 2);
+    assert_eq!(circuit.input_count(PartyId::from(0)), 2);
+    assert_eq!(circuit.output_count(PartyId::from(2)), 1);
+
+    // Gates: 0-3 inputs, 4 and 5 products, 6 sum, 7 scaled, 8 product, 9 output, 10 linear sum,
+    // 11 output.
+    let layers = circuit.layers();
+    assert_eq!(layers.len(), 3);
+    assert_eq!((layers[0].muls(), layers[0].locals()), (&[][..], &[10][..]));
+    assert_eq!(
+        (layers[1].muls(), layers[1].locals()),
+        (&[4, 5][..], &[6, 7][..])
+    );
+    assert_eq!((layers[2].muls(), layers[2].locals()), (&[8][..], &[][..]));
+    assert!(matches!(circuit.gates()[9], ArithGate::Output { .. }));
+
+    let empty = ArithmeticCircuit::<F>::new();
+    assert_eq!(empty.depth(), 0);
+    assert_eq!(empty.layers().len(), 1);
+}
+
+/// Output gates compute no wire, so a wire pointing at one cannot be read.
+#[test]
+#[should_panic(expected = "is not a wire of this circuit")]
+fn circuit_rejects_output_gates_as_operands() {
+    let mut circuit = ArithmeticCircuit::<F>::new();
+    let x = circuit.input(PartyId::from(0));
+    circuit.output(x, PartyId::from(0));
+    // The second wire of another circuit is the output gate of this one.
+    let mut other = ArithmeticCircuit::<F>::new();
+    let [_, y] = [0, 1].map(|_| other.input(PartyId::from(0)));
+    circuit.add(x, y);
+}
+
+#[test]
+fn shamir_circuit_matches_evaluation_in_the_clear() {
+    let circuit = inner_product_circuit();
+    let expected = circuit.evaluate(&inputs()).unwrap();
+    let outputs = evaluate(&circuit, &inputs());
+    for party in parties() {
+        let output = expected.get(&party).cloned().unwrap_or_default();
+        assert_eq!(outputs[&party], Ok(output), "party {party:?}");
+    }
+}
+
+/// A product of six inputs as a balanced tree has depth 3, and so costs three multiplication
+/// rounds where a chain would cost five.
+#[test]
+fn shamir_circuit_evaluates_a_product_tree() {
+    let owner = PartyId::from(3);
+    let mut circuit = ArithmeticCircuit::new();
+    let mut level: Vec<_> = (0..6).map(|_| circuit.input(owner)).collect();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => circuit.mul(*a, *b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+    for party in parties() {
+        circuit.output(level[0], party);
+    }
+    assert_eq!(circuit.depth(), 3);
+
+    let values: Vec<F> = (1..=6).map(F::from).collect();
+    let outputs = evaluate(&circuit, &HashMap::from([(owner, values)]));
+    for party in parties() {
+        assert_eq!(outputs[&party], Ok(vec![F::from(720)]));
+    }
+}
+
+/// Every party deals all of its inputs in one packet to each other party, however many it has.
+#[test]
+fn shamir_circuit_deals_all_inputs_in_one_round() {
+    let [p0, p1, p2] = [0, 1, 2].map(PartyId::from);
+    let mut circuit = ArithmeticCircuit::new();
+    let x: Vec<_> = (0..3).map(|_| circuit.input(p0)).collect();
+    let y: Vec<_> = (0..3).map(|_| circuit.input(p1)).collect();
+    let sum = x
+        .iter()
+        .chain(&y)
+        .copied()
+        .reduce(|sum, wire| circuit.add(sum, wire))
+        .unwrap();
+    circuit.output(sum, p2);
+    let inputs = HashMap::from([
+        (p0, vec![F::from(1), F::from(2), F::from(3)]),
+        (p1, vec![F::from(4), F::from(5), F::from(6)]),
+    ]);
+
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            let inputs = inputs.get(&pid).cloned().unwrap_or_default();
+            PassiveShamirCircuit::<1, F>::new(circuit.clone(), parties(), p0, T, inputs, vec![])
+                .unwrap()
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    assert_eq!(outcome.outputs[&p2], vec![F::from(21)]);
+    // One input packet to each of the other `N - 1` parties, and its output shares to `p2`.
+    let sends = |party: PartyId| {
+        outcome.traces[&party]
+            .event_types()
+            .iter()
+            .filter(|event| **event == EventType::SendData)
+            .count()
+    };
+    assert_eq!(sends(p0), N);
+    assert_eq!(sends(p1), N);
+    assert_eq!(sends(PartyId::from(3)), 1);
+}
+
+/// The triples must match the circuit, and every owner must take part.
+#[test]
+fn shamir_circuit_rejects_mismatched_setups() {
+    let circuit = inner_product_circuit();
+    assert!(matches!(
+        PassiveShamirCircuit::<1, F>::new(
+            circuit.clone(),
+            parties(),
+            PartyId::from(0),
+            T,
+            vec![],
+            vec![]
+        ),
+        Err(Error::Input)
+    ));
+
+    let mut stranger = ArithmeticCircuit::<F>::new();
+    let x = stranger.input(PartyId::from(7));
+    stranger.output(x, PartyId::from(0));
+    assert!(matches!(
+        PassiveShamirCircuit::<1, F>::new(stranger, parties(), PartyId::from(0), T, vec![], vec![]),
+        Err(Error::Input)
+    ));
+
+    let linear = {
+        let mut circuit = ArithmeticCircuit::<F>::new();
+        let x = circuit.input(PartyId::from(0));
+        circuit.output(x, PartyId::from(1));
+        circuit
+    };
+    for (king, threshold) in [(PartyId::from(9), T), (PartyId::from(0), N)] {
+        assert!(matches!(
+            PassiveShamirCircuit::<1, F>::new(
+                linear.clone(),
+                parties(),
+                king,
+                threshold,
+                vec![],
+                vec![]
+            ),
+            Err(Error::Input)
+        ));
+    }
+}
@@ -0,0 +1,163 @@
+//! Tests for commit-then-open coin tossing (`protocol::randomness::coin_toss`).
+
+use std::collections::HashMap;
+
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    abbreviate::Abbreviate,
+    commitment::{ContextualCommitment, HashContext, HashScheme},
+    math::{field::mersenne61::Mersenne61, gf2::Gf2Word, ring::Ring},
+    net::{simulation::channel::SimpleNetworkConfig, Network, Packet, PartyId},
+    prelude::{simulate, Error, GeneralEnv, Protocol, RandEnvironment},
+    protocol::{randomness::coin_toss::CoinToss, ProtocolId},
+};
+
+const N: usize = 4;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+fn toss<F>(count: usize) -> HashMap<PartyId, Vec<F>>
+where
+    F: Ring + Abbreviate + Send + Sync + 'static,
+{
+    simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |_| CoinToss::<F>::new(b"coins", count),
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs
+}
+
+/// Every party outputs the same batch of coins, and two tosses give different coins.
+#[test]
+fn parties_agree_on_the_coins() {
+    let outputs = toss::<Mersenne61>(16);
+    let coins = &outputs[&parties()[0]];
+    assert_eq!(coins.len(), 16);
+    for party in parties() {
+        assert_eq!(&outputs[&party], coins);
+    }
+    assert_ne!(&toss::<Mersenne61>(16)[&parties()[0]], coins);
+}
+
+/// Over GF(2) words the contributions are XORed, and the coins are still agreed upon.
+#[test]
+fn boolean_coins_are_agreed_upon() {
+    let outputs = toss::<Gf2Word>(4);
+    let coins = &outputs[&parties()[0]];
+    for party in parties() {
+        assert_eq!(&outputs[&party], coins);
+    }
+}
+
+/// Tossing no coins is a valid, empty batch.
+#[test]
+fn empty_batch() {
+    for coins in toss::<Mersenne61>(0).values() {
+        assert!(coins.is_empty());
+    }
+}
+
+/// Parties with the same coins derive the same seed, and a seeded stream the same challenges.
+#[test]
+fn coins_seed_a_shared_stream() {
+    let outputs = toss::<Mersenne61>(2);
+    let seeds: Vec<[u8; 32]> = parties()
+        .iter()
+        .map(|party| CoinToss::seed(&outputs[party]))
+        .collect();
+    assert!(seeds.iter().all(|seed| *seed == seeds[0]));
+    let challenges: Vec<u64> = seeds
+        .iter()
+        .map(|seed| ChaCha20Rng::from_seed(*seed).random())
+        .collect();
+    assert!(challenges.iter().all(|c| *c == challenges[0]));
+
+    let mut coins = outputs[&parties()[0]].clone();
+    coins[1] = coins[1] + &Mersenne61::ONE;
+    assert_ne!(CoinToss::seed(&coins), seeds[0]);
+}
+
+/// Runs the coin toss honestly, or — for the cheater — commits to its contributions and then
+/// opens different ones.
+enum Party {
+    Honest(usize),
+    Cheater(usize),
+}
+
+impl<E: RandEnvironment> Protocol<E> for Party {
+    type Output = Result<Vec<Mersenne61>, String>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let count = match self {
+            Party::Honest(count) => {
+                let coins = CoinToss::<Mersenne61>::new(b"cheat", count).run(env).await;
+                return Ok(coins.map_err(|err| err.to_string()));
+            }
+            Party::Cheater(count) => count,
+        };
+        let me = env.network().local_party();
+        let others: Vec<PartyId> = parties().into_iter().filter(|p| *p != me).collect();
+
+        let mut coins = Vec::new();
+        let mut commitments = Vec::new();
+        let mut openings = Vec::new();
+        for _ in 0..count {
+            let coin = Mersenne61::random(env.rng_mut());
+            let (commitment, opening) =
+                HashScheme::commit_in(&HashContext::new(b"cheat", me), &coin, env.rng_mut());
+            coins.push(coin + &Mersenne61::ONE);
+            commitments.push(commitment);
+            openings.push(opening);
+        }
+        let mut pkt = Packet::empty();
+        pkt.write_many_labeled(&commitments)?;
+        let messages: Vec<_> = others.iter().map(|p| (*p, pkt.clone())).collect();
+        env.network_mut().send_many(&messages).await?;
+        for party in &others {
+            env.network_mut().recv_from(*party).await?;
+        }
+
+        let mut pkt = Packet::empty();
+        pkt.write_many_labeled(&coins)?;
+        pkt.write_many_labeled(&openings)?;
+        let messages: Vec<_> = others.iter().map(|p| (*p, pkt.clone())).collect();
+        env.network_mut().send_many(&messages).await?;
+        for party in &others {
+            env.network_mut().recv_from(*party).await?;
+        }
+        Ok(Err("cheated".to_string()))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("CoinTossParty")
+    }
+}
+
+/// A party that opens a value other than the one it committed to is named by every honest party.
+#[test]
+fn wrong_opening_names_the_cheater() {
+    let cheater = parties()[2];
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            if pid == cheater {
+                Party::Cheater(3)
+            } else {
+                Party::Honest(3)
+            }
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    let expected = Error::Cheater(cheater).to_string();
+    for party in parties().into_iter().filter(|p| *p != cheater) {
+        assert_eq!(outcome.outputs[&party], Err(expected.clone()));
+    }
+}
@@ -0,0 +1,154 @@
+//! Tests for the hash-based and Pedersen commitment schemes (`commitment`).
+
+use scl_rs::{
+    commitment::{
+        Commitment, ContextualCommitment, HashCommitment, HashContext, HashScheme,
+        PedersenCommitment, PedersenScheme,
+    },
+    math::{
+        ec::{secp256k1::Secp256k1, EllipticCurve},
+        field::{mersenne61::Mersenne61, secp256k1_scalar::Secp256k1ScalarField},
+        ring::Ring,
+    },
+    net::{Packet, PartyId},
+};
+
+type Scalar = Secp256k1ScalarField;
+
+/// A hash commitment opens to the committed value, and to no other value or nonce.
+#[test]
+fn hash_commitment_opens_only_to_the_committed_value() {
+    let mut rng = rand::rng();
+    for _ in 0..16 {
+        let value = Mersenne61::random(&mut rng);
+        let (commitment, opening) = HashScheme::commit(&value, &mut rng);
+        assert!(HashScheme::verify(&commitment, &value, &opening));
+        assert!(!HashScheme::verify(
+            &commitment,
+            &(value + &Mersenne61::ONE),
+            &opening
+        ));
+
+        let (_, other_opening) = HashScheme::commit(&value, &mut rng);
+        assert!(!HashScheme::verify(&commitment, &value, &other_opening));
+    }
+}
+
+/// A hash commitment made in a context opens only in that context: not for another committer, in
+/// another session, or without a context.
+#[test]
+fn hash_commitment_is_bound_to_its_context() {
+    let mut rng = rand::rng();
+    let value = Mersenne61::random(&mut rng);
+    let context = HashContext::new(b"test", PartyId::from(0));
+    let (commitment, opening) = HashScheme::commit_in(&context, &value, &mut rng);
+    assert!(HashScheme::verify_in(
+        &context,
+        &commitment,
+        &value,
+        &opening
+    ));
+    for other in [
+        HashContext::new(b"test", PartyId::from(1)),
+        HashContext::new(b"other", PartyId::from(0)),
+    ] {
+        assert!(!HashScheme::verify_in(
+            &other,
+            &commitment,
+            &value,
+            &opening
+        ));
+    }
+    assert!(!HashScheme::verify(&commitment, &value, &opening));
+}
+
+/// Committing twice to the same value gives different commitments.
+#[test]
+fn commitments_are_randomized() {
+    let mut rng = rand::rng();
+    let value = Scalar::random(&mut rng);
+    let (first, _) = HashScheme::commit(&value, &mut rng);
+    let (second, _) = HashScheme::commit(&value, &mut rng);
+    assert_ne!(first, second);
+
+    let (first, _) = PedersenScheme::commit(&value, &mut rng);
+    let (second, _) = PedersenScheme::commit(&value, &mut rng);
+    assert_ne!(first, second);
+}
+
+/// A Pedersen commitment opens to the committed value with its blinding, and not otherwise.
+#[test]
+fn pedersen_commitment_opens_only_to_the_committed_value() {
+    let mut rng = rand::rng();
+    for _ in 0..8 {
+        let value = Scalar::random(&mut rng);
+        let (commitment, opening) = PedersenScheme::commit(&value, &mut rng);
+        assert!(PedersenScheme::verify(&commitment, &value, &opening));
+        assert!(!PedersenScheme::verify(
+            &commitment,
+            &(value + &Scalar::ONE),
+            &opening
+        ));
+
+        let (_, other_opening) = PedersenScheme::commit(&value, &mut rng);
+        assert!(!PedersenScheme::verify(&commitment, &value, &other_opening));
+    }
+}
+
+/// The sum of two Pedersen commitments is opened by the sum of their openings to the sum of their
+/// values.
+#[test]
+fn pedersen_commitments_add() {
+    let mut rng = rand::rng();
+    let (a, b) = (Scalar::random(&mut rng), Scalar::random(&mut rng));
+    let (commitment_a, opening_a) = PedersenScheme::commit(&a, &mut rng);
+    let (commitment_b, opening_b) = PedersenScheme::commit(&b, &mut rng);
+    let sum = commitment_a + &commitment_b;
+    assert!(PedersenScheme::verify(
+        &sum,
+        &(a + &b),
+        &(opening_a + &opening_b)
+    ));
+    assert!(!PedersenScheme::verify(&sum, &a, &(opening_a + &opening_b)));
+}
+
+/// The second generator is a fixed point on the curve, independent of the first.
+#[test]
+fn pedersen_generator_is_fixed_and_distinct() {
+    let h = PedersenScheme::h();
+    assert_eq!(h, PedersenScheme::h());
+    assert!(h.is_on_curve());
+    assert!(!h.is_point_at_infinity());
+    assert_ne!(h, Secp256k1::gen());
+    assert_ne!(h, Secp256k1::gen().negate());
+}
+
+/// Commitments survive a trip through a packet, and are labeled in its composition.
+#[test]
+fn commitments_are_labeled_in_packets() {
+    let mut rng = rand::rng();
+    let value = Scalar::random(&mut rng);
+    let (hash, _) = HashScheme::commit(&value, &mut rng);
+    let (pedersen, opening) = PedersenScheme::commit(&value, &mut rng);
+
+    let mut packet = Packet::empty();
+    packet.write_labeled(&hash).unwrap();
+    packet.write_labeled(&pedersen).unwrap();
+    packet.write_labeled(&opening).unwrap();
+    assert_eq!(
+        packet.composition(),
+        vec![
+            ("hash com.", 1),
+            ("Pedersen com.", 1),
+            ("Pedersen opening", 1)
+        ]
+    );
+
+    assert_eq!(packet.read::<HashCommitment>(0).unwrap(), hash);
+    let received: PedersenCommitment = packet.read(1).unwrap();
+    assert!(PedersenScheme::verify(
+        &received,
+        &value,
+        &packet.read(2).unwrap()
+    ));
+}
@@ -5,13 +5,18 @@
 //! uses every helper.
 #![allow(dead_code)]
 
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug};
 
 use proptest::prelude::*;
 use rand::{rngs::StdRng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use scl_rs::{
     math::ring::Ring,
-    prelude::{EllipticCurve, FiniteField},
+    net::{
+        simulation::{channel::SimpleNetworkConfig, network::SimNetwork},
+        PartyId,
+    },
+    prelude::{simulate, EllipticCurve, FiniteField, GeneralEnv, Protocol},
 };
 use serde::{de::DeserializeOwned, Serialize};
 
@@ -45,3 +50,21 @@ where
     prop_assert_eq!(postcard::from_bytes::<T>(&bytes).unwrap(), x);
     Ok(())
 }
+
+/// Runs `make_protocol(party)` for every party in `parties` on the simulator, over default
+/// channels and freshly seeded environments, and returns every party's output.
+pub fn run<P, G>(parties: Vec<PartyId>, make_protocol: G) -> HashMap<PartyId, P::Output>
+where
+    G: Fn(PartyId) -> P,
+    P: Protocol<GeneralEnv<SimNetwork, ChaCha20Rng>> + 'static,
+    P::Output: Serialize + Send + Clone + 'static,
+{
+    simulate(
+        SimpleNetworkConfig::default(),
+        parties,
+        make_protocol,
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs
+}
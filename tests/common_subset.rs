@@ -0,0 +1,216 @@
+//! Tests for agreement on a common subset of dealers (`protocol::agreement::common_subset`), with
+//! missing, late and partial dealers.
+
+use std::{collections::HashMap, time::Duration};
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    math::field::mersenne61::Mersenne61,
+    net::{simulation::channel::SimpleNetworkConfig, Packet, PartyId},
+    prelude::{simulate, Error, GeneralEnv, Network, Protocol, RandEnvironment},
+    protocol::{
+        agreement::{
+            common_subset::{AgreeOnCommonSubset, CommonSubset},
+            AgreementMessage,
+        },
+        ProtocolId,
+    },
+    ss::{shamir::ShamirSS, LinearShare},
+};
+
+type Share = ShamirSS<1, Mersenne61>;
+
+const DEADLINE: Duration = Duration::from_secs(2);
+const ROUND: Duration = Duration::from_secs(1);
+
+fn parties(n: usize) -> Vec<PartyId> {
+    (0..n).map(PartyId::from).collect()
+}
+
+fn secret(party: PartyId) -> Mersenne61 {
+    Mersenne61::from(100 + party.as_usize() as u64)
+}
+
+/// How a party behaves.
+#[derive(Clone, Copy)]
+enum Behavior {
+    /// Deals its input.
+    Dealer,
+    /// Takes part without an input.
+    Receiver,
+    /// Sends nothing.
+    Silent,
+    /// Deals its input, but only starts after the given delay.
+    Late(Duration),
+    /// Sends shares to the parties below `split` only, and nothing else.
+    Partial { split: usize },
+}
+
+/// One party of an input collection among parties tolerating `t` faults, sharing at degree `t`.
+struct Party {
+    behavior: Behavior,
+    t: usize,
+}
+
+/// The agreed dealers and shares, or the error a party failed with.
+type Outcome = Result<CommonSubset<Share>, String>;
+
+impl<E: RandEnvironment> Protocol<E> for Party {
+    type Output = Outcome;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let dealer =
+            AgreeOnCommonSubset::<Share>::dealer(secret(me), self.t, self.t, DEADLINE, ROUND);
+        let outcome = match self.behavior {
+            Behavior::Dealer => dealer.run(env).await,
+            Behavior::Receiver => {
+                AgreeOnCommonSubset::<Share>::receiver(self.t, DEADLINE, ROUND)
+                    .run(env)
+                    .await
+            }
+            Behavior::Silent => return Ok(Err("silent".to_string())),
+            Behavior::Late(delay) => {
+                env.network_mut().sleep(delay).await?;
+                dealer.run(env).await
+            }
+            Behavior::Partial { split } => {
+                let parties = env.network().party_ids();
+                let shares = <Share as LinearShare>::shares_from_secret(
+                    secret(me),
+                    &parties,
+                    self.t,
+                    env.rng_mut(),
+                )?;
+                let mut messages = Vec::new();
+                for (party, share) in parties.into_iter().zip(shares) {
+                    if party != me && party.as_usize() < split {
+                        let mut pkt = Packet::empty();
+                        pkt.write(&AgreementMessage {
+                            round: 0,
+                            value: share,
+                        })?;
+                        messages.push((party, pkt));
+                    }
+                }
+                env.network_mut().send_many(&messages).await?;
+                return Ok(Err("partial".to_string()));
+            }
+        };
+        Ok(outcome.map_err(|err| err.to_string()))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("CommonSubsetParty")
+    }
+}
+
+/// Runs an input collection among `n` parties, with the given behavior for some of them (the
+/// others deal), and returns the outcome of every party that is not faulty.
+fn collect(n: usize, t: usize, behaviors: &[(usize, Behavior)]) -> HashMap<PartyId, Outcome> {
+    let behaviors: HashMap<usize, Behavior> = behaviors.iter().copied().collect();
+    let mut outputs = simulate(
+        SimpleNetworkConfig::default(),
+        parties(n),
+        |pid| Party {
+            behavior: behaviors
+                .get(&pid.as_usize())
+                .copied()
+                .unwrap_or(Behavior::Dealer),
+            t,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    outputs.retain(|party, _| {
+        matches!(
+            behaviors.get(&party.as_usize()),
+            None | Some(Behavior::Receiver)
+        )
+    });
+    outputs
+}
+
+/// Asserts that every honest party agreed on `dealers`, and that the shares they hold reconstruct
+/// the dealers' inputs. Returns, for every agreed dealer, the honest parties without its share.
+fn assert_agreed(outcomes: &HashMap<PartyId, Outcome>, dealers: &[usize]) -> Vec<Vec<PartyId>> {
+    let dealers: Vec<PartyId> = dealers.iter().copied().map(PartyId::from).collect();
+    let mut holders: Vec<Vec<PartyId>> = vec![Vec::new(); dealers.len()];
+    let mut shares: Vec<Vec<Share>> = vec![Vec::new(); dealers.len()];
+    let mut missing: Vec<Vec<PartyId>> = vec![Vec::new(); dealers.len()];
+    for (party, outcome) in outcomes {
+        let subset = outcome.as_ref().unwrap();
+        assert_eq!(subset.dealers, dealers);
+        for (i, share) in subset.shares.iter().enumerate() {
+            match share {
+                Some(share) => {
+                    holders[i].push(*party);
+                    shares[i].push(share.clone());
+                }
+                None => missing[i].push(*party),
+            }
+        }
+    }
+    for ((dealer, shares), holders) in dealers.iter().zip(shares).zip(holders) {
+        assert_eq!(
+            <Share as LinearShare>::secret_from_shares(&shares, &holders).unwrap(),
+            secret(*dealer)
+        );
+    }
+    for list in &mut missing {
+        list.sort();
+    }
+    missing
+}
+
+/// With every party on time, every dealer is agreed on, and every party holds every share.
+#[test]
+fn every_dealer_on_time_is_agreed_on() {
+    for (n, t) in [(4, 1), (7, 2)] {
+        let dealers: Vec<usize> = (0..n).collect();
+        let missing = assert_agreed(&collect(n, t, &[]), &dealers);
+        assert!(missing.iter().all(Vec::is_empty));
+    }
+}
+
+/// Missing and late dealers, and parties without an input, are left out; the others go on.
+#[test]
+fn missing_and_late_dealers_are_left_out() {
+    let outcomes = collect(
+        7,
+        2,
+        &[
+            (4, Behavior::Receiver),
+            (5, Behavior::Silent),
+            (6, Behavior::Late(DEADLINE + ROUND / 2)),
+        ],
+    );
+    assert_agreed(&outcomes, &[0, 1, 2, 3]);
+}
+
+/// A dealer that reaches too few parties is left out, though some of them hold its shares.
+#[test]
+fn partial_dealer_is_left_out() {
+    let outcomes = collect(7, 2, &[(0, Behavior::Partial { split: 4 })]);
+    assert_agreed(&outcomes, &[1, 2, 3, 4, 5, 6]);
+}
+
+/// A dealer that reaches exactly `n − t` parties is agreed on, and the party it left out outputs no
+/// share of its input instead of failing.
+#[test]
+fn dealer_reaching_n_minus_t_parties_is_agreed_on() {
+    let outcomes = collect(7, 2, &[(6, Behavior::Partial { split: 5 })]);
+    let missing = assert_agreed(&outcomes, &[0, 1, 2, 3, 4, 5, 6]);
+    assert_eq!(missing[6], vec![PartyId::from(5)]);
+    assert!(missing[..6].iter().all(Vec::is_empty));
+}
+
+/// Tolerating `t` faults needs more than `3t` parties.
+#[test]
+fn common_subset_rejects_too_many_faults() {
+    for outcome in collect(3, 1, &[]).values() {
+        assert_eq!(*outcome, Err(Error::Input.to_string()));
+    }
+}
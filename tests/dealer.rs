@@ -0,0 +1,279 @@
+//! End-to-end tests for trusted-dealer preprocessing (`protocol::dealer`) on the deterministic
+//! simulator, over Shamir and additive sharing.
+
+use scl_rs::{
+    math::{field::mersenne61::Mersenne61, ring::Ring},
+    net::{Network, PartyId},
+    prelude::{Error, Protocol, RandEnvironment},
+    protocol::{
+        dealer::{BeaverTriple, DealerRequest, TrustedDealer},
+        passive_shamir::{mul::PassiveShamirMul, triple::ShamirTriple},
+        share::open::BatchedPassiveOpenShr,
+        ProtocolId,
+    },
+    ss::{additive::AdditiveSS, shamir::ShamirSS, LinearShare},
+};
+
+mod common;
+
+type F = Mersenne61;
+type Shamir = ShamirSS<1, F>;
+type Additive = AdditiveSS<F>;
+
+const N: usize = 4;
+const T: usize = 1;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+const REQUEST: DealerRequest = DealerRequest {
+    triples: 3,
+    random_bits: 8,
+    random_shares: 2,
+    double_shares: 2,
+    input_masks: 0,
+};
+
+/// One party's dealt material, destructured so it can leave the simulator.
+type Dealt<S> = (Vec<(S, S, S)>, Vec<S>, Vec<S>, Vec<(S, S)>);
+
+/// The last party deals Shamir material to the others at degrees `T` and `2T`, and each receiver
+/// hands back everything it got.
+struct DealShamir;
+
+impl<E: RandEnvironment> Protocol<E> for DealShamir {
+    type Output = Dealt<Shamir>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let dealer = parties()[N - 1];
+        let protocol = if env.network().local_party() == dealer {
+            TrustedDealer::dealer(dealer, parties()[..N - 1].to_vec(), T, 2 * T, REQUEST)
+        } else {
+            TrustedDealer::receiver(dealer, REQUEST)
+        };
+        let mut dealt = protocol.run(env).await?;
+        let left = dealt.left();
+        Ok((
+            dealt
+                .take_triples(left.triples)?
+                .into_iter()
+                .map(BeaverTriple::into_parts)
+                .collect(),
+            dealt.take_random_bits(left.random_bits)?,
+            dealt.take_random_shares(left.random_shares)?,
+            dealt.take_double_shares(left.double_shares)?,
+        ))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("DealShamir")
+    }
+}
+
+/// Every kind of material is well formed: triples multiply, bits are bits, and both halves of a
+/// double sharing hide the same secret at their own degree. The dealer, not a receiver, keeps
+/// nothing.
+#[test]
+fn trusted_dealer_deals_well_formed_shamir_material() {
+    let outputs = common::run(parties(), |_| DealShamir);
+    let receivers = parties()[..N - 1].to_vec();
+    let open = |get: &dyn Fn(&Dealt<Shamir>) -> Shamir| -> F {
+        let shares: Vec<Shamir> = receivers.iter().map(|p| get(&outputs[p])).collect();
+        <Shamir as LinearShare>::secret_from_shares(&shares, &receivers).unwrap()
+    };
+
+    let dealer_output = &outputs[&parties()[N - 1]];
+    assert!(dealer_output.0.is_empty() && dealer_output.1.is_empty());
+
+    for k in 0..REQUEST.triples {
+        let a = open(&|d| d.0[k].0.clone());
+        let b = open(&|d| d.0[k].1.clone());
+        let c = open(&|d| d.0[k].2.clone());
+        assert_eq!(c, a * &b, "triple {k}");
+        assert_eq!(outputs[&receivers[0]].0[k].0.degree(), T);
+    }
+    for k in 0..REQUEST.random_bits {
+        let bit = open(&|d| d.1[k].clone());
+        assert!(bit == F::ZERO || bit == F::ONE, "random bit {k}");
+    }
+    for k in 0..REQUEST.double_shares {
+        let (low, high) = &outputs[&receivers[0]].3[k];
+        assert_eq!((low.degree(), high.degree()), (T, 2 * T));
+        assert_eq!(
+            open(&|d| d.3[k].0.clone()),
+            open(&|d| d.3[k].1.clone()),
+            "double sharing {k}"
+        );
+    }
+}
+
+/// Dealt Shamir triples plug straight into Beaver multiplication; party 0 deals both the inputs
+/// (as random sharings it does not reveal) and the triples.
+struct MulWithDealtTriples;
+
+impl<E: RandEnvironment> Protocol<E> for MulWithDealtTriples {
+    type Output = Vec<F>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let dealer = parties()[0];
+        let request = DealerRequest {
+            triples: 2,
+            random_shares: 4,
+            ..DealerRequest::default()
+        };
+        let mut dealt = if env.network().local_party() == dealer {
+            TrustedDealer::dealer(dealer, parties(), T, 2 * T, request)
+        } else {
+            TrustedDealer::receiver(dealer, request)
+        }
+        .run(env)
+        .await?;
+
+        let mut inputs = dealt.take_random_shares(4)?;
+        let y = inputs.split_off(2);
+        let x = inputs;
+        let triples = dealt
+            .take_triples(2)?
+            .into_iter()
+            .map(|triple| {
+                let (a, b, c) = triple.into_parts();
+                ShamirTriple::new(a, b, c)
+            })
+            .collect();
+        let z = PassiveShamirMul::new(dealer, parties(), x.clone(), y.clone(), triples)?
+            .run(env)
+            .await?;
+
+        // Open x, y and z together, and report [x_0, x_1, y_0, y_1, z_0, z_1].
+        BatchedPassiveOpenShr::new([x, y, z].concat())
+            .run(env)
+            .await
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("MulWithDealtTriples")
+    }
+}
+
+#[test]
+fn dealt_triples_drive_beaver_multiplication() {
+    let outputs = common::run(parties(), |_| MulWithDealtTriples);
+    let opened = &outputs[&parties()[0]];
+    for party in parties() {
+        assert_eq!(&outputs[&party], opened);
+    }
+    for k in 0..2 {
+        assert_eq!(opened[4 + k], opened[k] * &opened[2 + k], "product {k}");
+    }
+}
+
+/// The same dealer deals additive shares; the dealer is also a receiver.
+struct DealAdditive;
+
+impl<E: RandEnvironment> Protocol<E> for DealAdditive {
+    type Output = Vec<(Additive, Additive, Additive)>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let dealer = parties()[0];
+        let request = DealerRequest {
+            triples: 4,
+            ..DealerRequest::default()
+        };
+        let mut dealt = if env.network().local_party() == dealer {
+            TrustedDealer::dealer(dealer, parties(), (), (), request)
+        } else {
+            TrustedDealer::receiver(dealer, request)
+        }
+        .run(env)
+        .await?;
+        assert!(dealt.take_triples(5).is_err());
+        Ok(dealt
+            .take_triples(4)?
+            .into_iter()
+            .map(BeaverTriple::into_parts)
+            .collect())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("DealAdditive")
+    }
+}
+
+#[test]
+fn trusted_dealer_deals_additive_triples() {
+    let outputs = common::run(parties(), |_| DealAdditive);
+    let open = |shares: Vec<Additive>| Additive::secret_from_shares(&shares);
+    let per_party: Vec<&Vec<(Additive, Additive, Additive)>> =
+        parties().iter().map(|party| &outputs[party]).collect();
+    for k in 0..4 {
+        let (a, b, c): (Vec<_>, Vec<_>, Vec<_>) = {
+            let mut parts = (Vec::new(), Vec::new(), Vec::new());
+            for triples in &per_party {
+                let (a, b, c) = triples[k].clone();
+                parts.0.push(a);
+                parts.1.push(b);
+                parts.2.push(c);
+            }
+            parts
+        };
+        assert_eq!(open(c), open(a) * &open(b), "triple {k}");
+    }
+}
+
+/// Party 0 deals two additive input masks per party, and each party hands back, per owner in
+/// party order, its shares of that owner's masks and the clear values it learned.
+struct DealInputMasks;
+
+impl<E: RandEnvironment> Protocol<E> for DealInputMasks {
+    type Output = Vec<Vec<(Additive, Option<F>)>>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let dealer = parties()[0];
+        let request = DealerRequest {
+            input_masks: 2,
+            ..DealerRequest::default()
+        };
+        let mut dealt = if env.network().local_party() == dealer {
+            TrustedDealer::dealer(dealer, parties(), (), (), request)
+        } else {
+            TrustedDealer::receiver(dealer, request)
+        }
+        .run(env)
+        .await?;
+        assert_eq!(dealt.left().input_masks, 2);
+        parties()
+            .into_iter()
+            .map(|owner| {
+                let masks = dealt.take_input_masks(owner, 2)?;
+                Ok(masks.into_iter().map(|mask| mask.into_parts()).collect())
+            })
+            .collect()
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("DealInputMasks")
+    }
+}
+
+/// Every party shares every owner's masks, and only the owner learns their clear values, which
+/// match the shared secrets.
+#[test]
+fn trusted_dealer_deals_input_masks_to_their_owners() {
+    let outputs = common::run(parties(), |_| DealInputMasks);
+    let open = |o: usize, k: usize| {
+        let shares: Vec<Additive> = parties()
+            .iter()
+            .map(|p| outputs[p][o][k].0.clone())
+            .collect();
+        Additive::secret_from_shares(&shares)
+    };
+    for party in parties() {
+        for (o, (owner, masks)) in parties().into_iter().zip(&outputs[&party]).enumerate() {
+            for (k, (_, value)) in masks.iter().enumerate() {
+                let expected = (party == owner).then(|| open(o, k));
+                assert_eq!(*value, expected, "mask {k} of {owner:?} at {party:?}");
+            }
+        }
+    }
+}
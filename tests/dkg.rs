@@ -0,0 +1,353 @@
+//! Tests for the Feldman distributed key generation (`protocol::threshold::dkg`) over secp256k1,
+//! on the deterministic simulator and over a real TLS network.
+
+use std::fs;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use rcgen::{CertificateParams, Issuer, KeyPair, SanType};
+use scl_rs::{
+    math::{
+        ec::{secp256k1::Secp256k1, EllipticCurve},
+        field::secp256k1_scalar::Secp256k1ScalarField,
+        ring::Ring,
+    },
+    net::{
+        simulation::channel::SimpleNetworkConfig, Network, NetworkConfig, Packet, PartyId,
+        TcpNetwork,
+    },
+    prelude::{simulate, Error, GeneralEnv, Protocol, RandEnvironment},
+    protocol::{
+        threshold::dkg::{DkgOutput, FeldmanDkg},
+        ProtocolId,
+    },
+    ss::{feldman::FeldmanSS, shamir::ShamirSS, LinearShare},
+};
+use tempfile::TempDir;
+
+type Scalar = Secp256k1ScalarField;
+type Share = ShamirSS<4, Scalar>;
+type Output = DkgOutput<4, Secp256k1>;
+
+const N: usize = 5;
+const T: usize = 2;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+/// How the cheating dealer of a session deviates.
+#[derive(Clone, Copy)]
+enum Cheat {
+    /// It deals wrong shares to `victims`, and reveals the right ones when they complain.
+    FixedOnComplaint { victims: usize },
+    /// It deals wrong shares to `victims`, and reveals wrong ones again when they complain.
+    WrongOnComplaint { victims: usize },
+    /// It deals the right shares, but sends one commitment too few.
+    MalformedCommitments,
+    /// It deals the right shares, but sends an empty packet in place of its complaints.
+    MalformedComplaints,
+}
+
+/// A dealer that runs the message flow of [`FeldmanDkg`], but hands a wrong share to its first
+/// victims. It outputs nothing.
+struct CheatingDealer {
+    cheat: Cheat,
+}
+
+impl<E: RandEnvironment> Protocol<E> for CheatingDealer {
+    type Output = Option<Output>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let (victims, fix) = match self.cheat {
+            Cheat::FixedOnComplaint { victims } => (victims, true),
+            Cheat::WrongOnComplaint { victims } => (victims, false),
+            Cheat::MalformedCommitments | Cheat::MalformedComplaints => (0, true),
+        };
+        let points: Vec<Scalar> = parties().into_iter().map(Share::encode_party).collect();
+        let secret = Scalar::random(env.rng_mut());
+        let dealt: Vec<FeldmanSS<4, Secp256k1>> =
+            FeldmanSS::shares_from_secret(secret, T, &points, env.rng_mut());
+        let mut commitments = dealt[0].commitments().to_vec();
+        if let Cheat::MalformedCommitments = self.cheat {
+            commitments.pop();
+        }
+        let shares: Vec<Share> = dealt.iter().map(|s| s.shamir_share().clone()).collect();
+        let others: Vec<PartyId> = parties().into_iter().filter(|p| *p != me).collect();
+        let skew = |share: &Share| Share::new(*share.share() + &Scalar::ONE, T);
+
+        // Deal, with a wrong share for each victim.
+        let mut messages = Vec::new();
+        for (k, party) in others.iter().enumerate() {
+            let share = &shares[party.as_usize()];
+            let mut pkt = Packet::empty();
+            pkt.write_labeled(&if k < victims {
+                skew(share)
+            } else {
+                share.clone()
+            })?;
+            pkt.write_many_labeled(&commitments)?;
+            messages.push((*party, pkt));
+        }
+        env.network_mut().send_many(&messages).await?;
+        for party in &others {
+            env.network_mut().recv_from(*party).await?;
+        }
+
+        // Complain about no one, and collect who complained about us.
+        let mut pkt = Packet::empty();
+        if !matches!(self.cheat, Cheat::MalformedComplaints) {
+            pkt.write(&Vec::<PartyId>::new())?;
+        }
+        let messages: Vec<_> = others.iter().map(|p| (*p, pkt.clone())).collect();
+        env.network_mut().send_many(&messages).await?;
+        let mut accusers = Vec::new();
+        for party in &others {
+            let complaints: Vec<PartyId> = env.network_mut().recv_from(*party).await?.read(0)?;
+            if complaints.contains(&me) {
+                accusers.push(*party);
+            }
+        }
+
+        // Answer the complaints, unless there were too many to answer.
+        if !accusers.is_empty() && accusers.len() <= T {
+            let revealed: Vec<Share> = accusers
+                .iter()
+                .map(|p| {
+                    let share = &shares[p.as_usize()];
+                    if fix {
+                        share.clone()
+                    } else {
+                        skew(share)
+                    }
+                })
+                .collect();
+            let mut pkt = Packet::empty();
+            pkt.write_many_labeled(&revealed)?;
+            let messages: Vec<_> = others.iter().map(|p| (*p, pkt.clone())).collect();
+            env.network_mut().send_many(&messages).await?;
+        }
+        Ok(None)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("CheatingDealer")
+    }
+}
+
+/// Either an honest [`FeldmanDkg`] run, or a [`CheatingDealer`].
+enum Party {
+    Honest(FeldmanDkg<4, Secp256k1>),
+    Cheater(CheatingDealer),
+}
+
+impl<E: RandEnvironment> Protocol<E> for Party {
+    type Output = Option<Output>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        match self {
+            Party::Honest(dkg) => Ok(Some(dkg.run(env).await?)),
+            Party::Cheater(dealer) => dealer.run(env).await,
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("Party")
+    }
+}
+
+/// Runs a DKG among [`parties`] in which party 0 deviates as `cheat` says, and returns the honest
+/// parties' outputs in party order.
+fn run_dkg(cheat: Option<Cheat>) -> Vec<Output> {
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| match cheat {
+            Some(cheat) if pid == parties()[0] => Party::Cheater(CheatingDealer { cheat }),
+            _ => Party::Honest(FeldmanDkg::new(parties(), T).unwrap()),
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    parties()
+        .iter()
+        .filter_map(|party| outcome.outputs[party].clone())
+        .collect()
+}
+
+/// Checks that the honest outputs agree on the key and the qualified set, that every `T + 1` of
+/// their key shares reconstruct the secret key of the public key, and that each verification key
+/// matches its key share.
+fn assert_consistent(outputs: &[Output], qualified: &[PartyId]) {
+    let public_key = *outputs[0].public_key();
+    let holders: Vec<PartyId> = parties()[N - outputs.len()..].to_vec();
+    for output in outputs {
+        assert_eq!(*output.public_key(), public_key);
+        assert_eq!(output.qualified(), qualified);
+        assert_eq!(output.parties(), parties());
+        for (other, other_holder) in outputs.iter().zip(&holders) {
+            assert_eq!(
+                *output.verification_key(*other_holder).unwrap(),
+                Secp256k1::gen().scalar_mul(other.key_share().share())
+            );
+        }
+        assert_eq!(output.key_share().degree(), T);
+        assert!(output.verification_key(PartyId::from(N)).is_none());
+    }
+
+    for start in 0..outputs.len() - T {
+        let shares: Vec<Share> = outputs[start..start + T + 1]
+            .iter()
+            .map(|o| o.key_share().clone())
+            .collect();
+        let secret_key =
+            <Share as LinearShare>::secret_from_shares(&shares, &holders[start..start + T + 1])
+                .unwrap();
+        assert_eq!(Secp256k1::gen().scalar_mul(&secret_key), public_key);
+    }
+}
+
+/// With everyone honest, every dealer qualifies and the parties share the key of `Σ C_{i,0}`.
+#[test]
+fn honest_dkg_shares_the_public_key() {
+    let outputs = run_dkg(None);
+    assert_eq!(outputs.len(), N);
+    assert_consistent(&outputs, &parties());
+}
+
+/// A dealer that hands out wrong shares but reveals the right ones when accused stays qualified,
+/// and its victims use the revealed shares.
+#[test]
+fn dealer_answering_complaints_stays_qualified() {
+    let outputs = run_dkg(Some(Cheat::FixedOnComplaint { victims: T }));
+    assert_consistent(&outputs, &parties());
+}
+
+/// A dealer that answers a complaint with a share that does not verify is disqualified.
+#[test]
+fn dealer_answering_wrongly_is_disqualified() {
+    let outputs = run_dkg(Some(Cheat::WrongOnComplaint { victims: 1 }));
+    assert_consistent(&outputs, &parties()[1..]);
+}
+
+/// A dealer accused by more than `T` parties is disqualified without a chance to answer.
+#[test]
+fn dealer_with_too_many_complaints_is_disqualified() {
+    let outputs = run_dkg(Some(Cheat::FixedOnComplaint { victims: T + 1 }));
+    assert_consistent(&outputs, &parties()[1..]);
+}
+
+/// A dealer whose commitments do not parse is disqualified outright, without being accused or
+/// asked to answer.
+#[test]
+fn dealer_with_malformed_commitments_is_disqualified() {
+    let outputs = run_dkg(Some(Cheat::MalformedCommitments));
+    assert_consistent(&outputs, &parties()[1..]);
+}
+
+/// A party whose complaint list does not parse is taken to complain about no one, and does not
+/// abort the run.
+#[test]
+fn malformed_complaints_count_as_none() {
+    let outputs = run_dkg(Some(Cheat::MalformedComplaints));
+    assert_consistent(&outputs, &parties());
+}
+
+/// Parties must be distinct, and `2t + 1` of them are needed.
+#[test]
+fn dkg_rejects_bad_parameters() {
+    assert!(FeldmanDkg::<4, Secp256k1>::new(parties(), T).is_ok());
+    assert!(FeldmanDkg::<4, Secp256k1>::new(parties(), T + 1).is_err());
+    let mut twice = parties();
+    twice.push(PartyId::from(0));
+    assert!(FeldmanDkg::<4, Secp256k1>::new(twice, T).is_err());
+}
+
+/// Writes a root CA, a certificate and key per party, and a loopback network configuration per
+/// party into `dir`.
+fn write_tls_deployment(dir: &TempDir, n_parties: usize) {
+    let mut ca_params = CertificateParams::new(vec![]).unwrap();
+    ca_params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+    let ca_key = KeyPair::generate().unwrap();
+    let ca_cert = ca_params.self_signed(&ca_key).unwrap();
+    fs::write(dir.path().join("rootCA.crt"), ca_cert.pem()).unwrap();
+    let issuer = Issuer::new(ca_params, ca_key);
+
+    let base_port = std::net::TcpListener::bind("127.0.0.1:0")
+        .unwrap()
+        .local_addr()
+        .unwrap()
+        .port();
+    for i in 0..n_parties {
+        let mut params = CertificateParams::new(vec![]).unwrap();
+        params.subject_alt_names = vec![SanType::IpAddress("127.0.0.1".parse().unwrap())];
+        let key = KeyPair::generate().unwrap();
+        let cert = params.signed_by(&key, &issuer).unwrap();
+        let cert_path = dir.path().join(format!("server_cert_p{i}.crt"));
+        let key_path = dir.path().join(format!("priv_key_p{i}.pem"));
+        fs::write(&cert_path, cert.pem()).unwrap();
+        fs::write(&key_path, key.serialize_pem()).unwrap();
+
+        let config = serde_json::json!({
+            "base_port": base_port,
+            "timeout": 5000,
+            "sleep_time": 300,
+            "peer_ips": vec!["127.0.0.1"; n_parties],
+            "server_cert": cert_path,
+            "priv_key": key_path,
+            "trusted_certs": [dir.path().join("rootCA.crt")],
+        });
+        fs::write(
+            dir.path().join(format!("net_config_p{i}.json")),
+            config.to_string(),
+        )
+        .unwrap();
+    }
+}
+
+/// The same protocol runs unchanged over mutually authenticated TLS between three parties.
+#[tokio::test]
+async fn dkg_runs_over_tls() {
+    const PARTIES: usize = 3;
+    let dir = tempfile::tempdir().unwrap();
+    write_tls_deployment(&dir, PARTIES);
+    let config = |i: usize| {
+        NetworkConfig::new(dir.path().join(format!("net_config_p{i}.json")).as_path()).unwrap()
+    };
+
+    let (net0, net1, net2) = tokio::try_join!(
+        TcpNetwork::create(0, config(0)),
+        TcpNetwork::create(1, config(1)),
+        TcpNetwork::create(2, config(2)),
+    )
+    .unwrap();
+    let env = |net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng()));
+    let (mut env0, mut env1, mut env2) = (env(net0), env(net1), env(net2));
+    let parties: Vec<PartyId> = (0..PARTIES).map(PartyId::from).collect();
+    let dkg = || FeldmanDkg::<4, Secp256k1>::new(parties.clone(), 1).unwrap();
+
+    let outputs = tokio::try_join!(
+        dkg().run(&mut env0),
+        dkg().run(&mut env1),
+        dkg().run(&mut env2),
+    )
+    .unwrap();
+    let outputs = [outputs.0, outputs.1, outputs.2];
+
+    for output in &outputs {
+        assert_eq!(output.public_key(), outputs[0].public_key());
+        assert_eq!(output.qualified(), parties);
+    }
+    let shares: Vec<Share> = outputs.iter().map(|o| o.key_share().clone()).collect();
+    let secret_key =
+        <Share as LinearShare>::secret_from_shares(&shares[1..], &parties[1..]).unwrap();
+    assert_eq!(
+        Secp256k1::gen().scalar_mul(&secret_key),
+        *outputs[0].public_key()
+    );
+
+    env0.network.close().await.unwrap();
+    env1.network.close().await.unwrap();
+    env2.network.close().await.unwrap();
+}
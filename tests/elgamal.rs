@@ -0,0 +1,189 @@
+//! Tests for threshold ElGamal (`protocol::threshold::elgamal`) over secp256k1.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    math::{
+        ec::{secp256k1::Secp256k1, EllipticCurve},
+        field::secp256k1_scalar::Secp256k1ScalarField,
+        ring::Ring,
+    },
+    net::{simulation::channel::SimpleNetworkConfig, Network, PartyId},
+    prelude::{simulate, Error, GeneralEnv, Protocol, RandEnvironment},
+    protocol::{
+        threshold::{
+            dkg::FeldmanDkg,
+            elgamal::{ChaumPedersenProof, ElGamalCiphertext, ThresholdDecrypt},
+        },
+        ProtocolId,
+    },
+    ss::{shamir::ShamirSS, LinearShare},
+};
+
+type Scalar = Secp256k1ScalarField;
+type Share = ShamirSS<4, Scalar>;
+type Ciphertext = ElGamalCiphertext<4, Secp256k1>;
+type Proof = ChaumPedersenProof<4, Secp256k1>;
+
+const N: usize = 5;
+const T: usize = 2;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+fn random_point() -> Secp256k1 {
+    Secp256k1::gen().scalar_mul(&Scalar::random(&mut rand::rng()))
+}
+
+/// Decryption with the whole key inverts encryption.
+#[test]
+fn elgamal_round_trip() {
+    let mut rng = rand::rng();
+    let secret_key = Scalar::random_non_zero(&mut rng);
+    let public_key = Secp256k1::gen().scalar_mul(&secret_key);
+    let message = random_point();
+    let ciphertext = Ciphertext::encrypt(&public_key, &message, &mut rng);
+    assert_eq!(ciphertext.decrypt(&secret_key).unwrap(), message);
+    assert_ne!(
+        ciphertext.decrypt(&(secret_key + &Scalar::ONE)).unwrap(),
+        message
+    );
+}
+
+/// A Chaum–Pedersen proof verifies for the statement it was made for, and for no other.
+#[test]
+fn chaum_pedersen_proves_equal_discrete_logs() {
+    let mut rng = rand::rng();
+    let x = Scalar::random(&mut rng);
+    let (g, h) = (Secp256k1::gen(), random_point());
+    let proof = Proof::prove(&x, &g, &h, &mut rng);
+    assert!(proof.verify(&g, &h, &g.scalar_mul(&x), &h.scalar_mul(&x)));
+
+    let y = x + &Scalar::ONE;
+    assert!(!proof.verify(&g, &h, &g.scalar_mul(&x), &h.scalar_mul(&y)));
+    assert!(!proof.verify(&g, &h, &g.scalar_mul(&y), &h.scalar_mul(&x)));
+    assert!(!proof.verify(&g, &random_point(), &g.scalar_mul(&x), &h.scalar_mul(&x)));
+}
+
+/// The parties of a DKG decrypt a batch of ciphertexts encrypted to their public key.
+#[test]
+fn threshold_decryption_with_a_dkg_key() {
+    let keys = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |_| FeldmanDkg::<4, Secp256k1>::new(parties(), T).unwrap(),
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    let public_key = *keys[&parties()[0]].public_key();
+    let messages: Vec<Secp256k1> = (0..3).map(|_| random_point()).collect();
+    let ciphertexts: Vec<Ciphertext> = messages
+        .iter()
+        .map(|m| Ciphertext::encrypt(&public_key, m, &mut rand::rng()))
+        .collect();
+
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| ThresholdDecrypt::from_dkg(&keys[&pid], ciphertexts.clone()).unwrap(),
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    for party in parties() {
+        assert_eq!(outcome.outputs[&party], messages);
+    }
+}
+
+/// Decrypts with a key dealt from `secret_key`, where the parties in `cheaters` use a wrong key
+/// share, so their proofs do not match their verification keys.
+struct DecryptWithCheaters {
+    shares: Vec<Share>,
+    verification_keys: Vec<Secp256k1>,
+    ciphertexts: Vec<Ciphertext>,
+    cheaters: usize,
+}
+
+impl<E: RandEnvironment> Protocol<E> for DecryptWithCheaters {
+    type Output = Result<Vec<Secp256k1>, String>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let mut share = self.shares[me.as_usize()].clone();
+        if me.as_usize() < self.cheaters {
+            share = share + &Scalar::ONE;
+        }
+        let decrypt =
+            ThresholdDecrypt::new(share, self.verification_keys, parties(), self.ciphertexts)?;
+        Ok(decrypt.run(env).await.map_err(|err| err.to_string()))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("DecryptWithCheaters")
+    }
+}
+
+fn decrypt_with_cheaters(cheaters: usize) -> (Secp256k1, Vec<Result<Vec<Secp256k1>, String>>) {
+    let mut rng = rand::rng();
+    let secret_key = Scalar::random_non_zero(&mut rng);
+    let public_key = Secp256k1::gen().scalar_mul(&secret_key);
+    let shares =
+        <Share as LinearShare>::shares_from_secret(secret_key, &parties(), T, &mut rng).unwrap();
+    let verification_keys = shares
+        .iter()
+        .map(|share| Secp256k1::gen().scalar_mul(share.share()))
+        .collect::<Vec<_>>();
+    let message = random_point();
+    let ciphertexts = vec![Ciphertext::encrypt(&public_key, &message, &mut rng)];
+
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |_| DecryptWithCheaters {
+            shares: shares.clone(),
+            verification_keys: verification_keys.clone(),
+            ciphertexts: ciphertexts.clone(),
+            cheaters,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    let honest = parties()[cheaters..]
+        .iter()
+        .map(|party| outcome.outputs[party].clone())
+        .collect();
+    (message, honest)
+}
+
+/// Up to `t` parties with invalid proofs are left out, and the others still decrypt.
+#[test]
+fn threshold_decryption_is_robust_to_t_cheaters() {
+    let (message, outcomes) = decrypt_with_cheaters(T);
+    for outcome in outcomes {
+        assert_eq!(outcome, Ok(vec![message]));
+    }
+}
+
+/// With more than `t` invalid proofs there are not enough shares left, and the parties abort
+/// naming the first cheater.
+#[test]
+fn threshold_decryption_aborts_with_too_many_cheaters() {
+    let (_, outcomes) = decrypt_with_cheaters(T + 1);
+    let expected = Error::Cheater(parties()[0]).to_string();
+    for outcome in outcomes {
+        assert_eq!(outcome, Err(expected.clone()));
+    }
+}
+
+/// There must be a verification key per party, and enough parties to interpolate.
+#[test]
+fn threshold_decrypt_rejects_bad_parameters() {
+    let share = Share::new(Scalar::ONE, T);
+    let keys = vec![Secp256k1::gen(); N];
+    assert!(ThresholdDecrypt::new(share.clone(), keys.clone(), parties(), vec![]).is_ok());
+    assert!(ThresholdDecrypt::new(share.clone(), keys[1..].to_vec(), parties(), vec![]).is_err());
+    assert!(
+        ThresholdDecrypt::new(share, keys[..T].to_vec(), parties()[..T].to_vec(), vec![]).is_err()
+    );
+}
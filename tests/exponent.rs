@@ -0,0 +1,140 @@
+//! Tests for Shamir sharing in the exponent (`ss::exponent`) and its opening protocol
+//! (`protocol::passive_shamir::open_exponent`), over secp256k1.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    math::{
+        ec::{secp256k1::Secp256k1, EllipticCurve},
+        field::secp256k1_scalar::Secp256k1ScalarField,
+        ring::Ring,
+    },
+    net::{simulation::channel::SimpleNetworkConfig, PartyId},
+    prelude::{simulate, GeneralEnv},
+    protocol::passive_shamir::open_exponent::PassiveOpenInExponent,
+    ss::{exponent::ExponentSS, shamir::ShamirSS, LinearShare, ShareError},
+};
+
+type Scalar = Secp256k1ScalarField;
+type Share = ShamirSS<4, Scalar>;
+type Lifted = ExponentSS<4, Secp256k1>;
+
+const N: usize = 4;
+const T: usize = 1;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+fn deal(secret: Scalar) -> Vec<Share> {
+    <Share as LinearShare>::shares_from_secret(secret, &parties(), T, &mut rand::rng()).unwrap()
+}
+
+fn lift(shares: &[Share], base: &Secp256k1) -> Vec<Lifted> {
+    shares
+        .iter()
+        .map(|share| ExponentSS::from_share(share, base))
+        .collect()
+}
+
+/// Any `t + 1` shares in the exponent interpolate to `x · G`, and interpolating at a party's point
+/// gives back that party's own share in the exponent.
+#[test]
+fn lagrange_in_the_exponent_reconstructs_x_g() {
+    let mut rng = rand::rng();
+    let x = Scalar::random(&mut rng);
+    let g = Secp256k1::gen();
+    let lifted = lift(&deal(x), &g);
+
+    for window in 0..N - T {
+        let set = &parties()[window..window + T + 1];
+        let public = Lifted::secret_from_shares(&lifted[window..window + T + 1], set).unwrap();
+        assert_eq!(public, g.scalar_mul(&x));
+    }
+    let at_party_3 = Lifted::interpolate_at(
+        &lifted[..T + 1],
+        &parties()[..T + 1],
+        &Lifted::encode_party(parties()[3]),
+    )
+    .unwrap();
+    assert_eq!(at_party_3, *lifted[3].point());
+}
+
+/// The local operations commute with lifting: `[x]·P + [y]·P = [x + y]·P`, and `c · ([x]·P)` is a
+/// share of `(c · x) · P`.
+#[test]
+fn shares_in_the_exponent_are_linear() {
+    let mut rng = rand::rng();
+    let (x, y, c) = (
+        Scalar::random(&mut rng),
+        Scalar::random(&mut rng),
+        Scalar::random(&mut rng),
+    );
+    let g = Secp256k1::gen();
+    let (lx, ly) = (lift(&deal(x), &g), lift(&deal(y), &g));
+
+    let sums: Vec<Lifted> = lx.iter().zip(&ly).map(|(a, b)| *a + b).collect();
+    let scaled: Vec<Lifted> = lx.iter().map(|a| *a * &c).collect();
+    let negated: Vec<Lifted> = lx.iter().map(|a| -*a).collect();
+    assert_eq!(
+        Lifted::secret_from_shares(&sums, &parties()).unwrap(),
+        g.scalar_mul(&(x + &y))
+    );
+    assert_eq!(
+        Lifted::secret_from_shares(&scaled, &parties()).unwrap(),
+        g.scalar_mul(&(c * &x))
+    );
+    assert_eq!(
+        Lifted::secret_from_shares(&negated, &parties()).unwrap(),
+        g.scalar_mul(&x).negate()
+    );
+}
+
+/// Fewer than `t + 1` shares cannot interpolate, and the two slices must line up.
+#[test]
+fn interpolation_needs_enough_shares() {
+    let lifted = lift(&deal(Scalar::ONE), &Secp256k1::gen());
+    assert!(matches!(
+        Lifted::secret_from_shares(&lifted[..T], &parties()[..T]),
+        Err(ShareError::NotEnoughShares)
+    ));
+    assert!(matches!(
+        Lifted::secret_from_shares(&lifted, &parties()[..2]),
+        Err(ShareError::LengthMismatch { .. })
+    ));
+}
+
+/// Opening two scalars in the exponent of a random base gives every party `x · P`.
+#[test]
+fn open_in_exponent_reveals_x_times_base() {
+    let mut rng = rand::rng();
+    let secrets = [Scalar::random(&mut rng), Scalar::random(&mut rng)];
+    let base = Secp256k1::gen().scalar_mul(&Scalar::random_non_zero(&mut rng));
+    let dealt: Vec<Vec<Share>> = secrets.iter().map(|x| deal(*x)).collect();
+
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            let shares = dealt.iter().map(|s| s[pid.as_usize()].clone()).collect();
+            PassiveOpenInExponent::new(base, shares, parties()).unwrap()
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+
+    let expected: Vec<Secp256k1> = secrets.iter().map(|x| base.scalar_mul(x)).collect();
+    for party in parties() {
+        assert_eq!(outcome.outputs[&party], expected);
+    }
+}
+
+/// The shares must agree on the degree, and there must be enough parties to interpolate.
+#[test]
+fn open_in_exponent_rejects_bad_parameters() {
+    let g = Secp256k1::gen();
+    let mixed = vec![Share::new(Scalar::ONE, 1), Share::new(Scalar::ONE, 2)];
+    assert!(PassiveOpenInExponent::new(g, mixed, parties()).is_err());
+    let high = vec![Share::new(Scalar::ONE, N)];
+    assert!(PassiveOpenInExponent::new(g, high, parties()).is_err());
+}
@@ -0,0 +1,276 @@
+//! Tests for FROST threshold Schnorr signing (`protocol::threshold::frost`), checked against the
+//! BIP-340 implementation of the `k256` crate.
+
+use std::collections::HashMap;
+
+use k256::schnorr::{Signature, SigningKey, VerifyingKey};
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    math::ec::secp256k1::Secp256k1,
+    net::{
+        simulation::{channel::SimpleNetworkConfig, network::SimNetwork},
+        Network, Packet, PartyId,
+    },
+    prelude::{simulate, Error, GeneralEnv, Protocol, RandEnvironment},
+    protocol::{
+        threshold::{
+            dkg::{DkgOutput, FeldmanDkg},
+            frost::{x_only_public_key, Bip340Signature, FrostSign},
+        },
+        ProtocolId,
+    },
+};
+
+type Key = DkgOutput<4, Secp256k1>;
+
+const N: usize = 5;
+const T: usize = 2;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+fn env(net: SimNetwork) -> GeneralEnv<SimNetwork, ChaCha20Rng> {
+    GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng()))
+}
+
+/// Runs a DKG among [`parties`] and returns every party's key.
+fn keygen() -> HashMap<PartyId, Key> {
+    simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |_| FeldmanDkg::new(parties(), T).unwrap(),
+        |_, net| env(net),
+        vec![],
+    )
+    .outputs
+}
+
+/// The outcome of signing as a signer sees it: the signature, or the error it aborted with.
+type Outcome = Result<Bip340Signature, String>;
+
+/// Signs `message` with the keys in `keys`, run by exactly the parties in `signers`.
+struct Sign {
+    key: Key,
+    signers: Vec<PartyId>,
+    message: Vec<u8>,
+}
+
+impl<E: RandEnvironment> Protocol<E> for Sign {
+    type Output = Outcome;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let frost = FrostSign::new(self.key, self.signers, self.message)?;
+        Ok(frost.run(env).await.map_err(|err| err.to_string()))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("Sign")
+    }
+}
+
+fn sign(keys: &HashMap<PartyId, Key>, signers: &[PartyId], message: &[u8]) -> Vec<Outcome> {
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        signers.to_vec(),
+        |pid| Sign {
+            key: keys[&pid].clone(),
+            signers: signers.to_vec(),
+            message: message.to_vec(),
+        },
+        |_, net| env(net),
+        vec![],
+    );
+    signers
+        .iter()
+        .map(|signer| outcome.outputs[signer].clone())
+        .collect()
+}
+
+/// Checks `signature` with `k256`'s BIP-340 verifier and with [`Bip340Signature::verify`].
+fn verifies(public_key: &Secp256k1, message: &[u8], signature: &Bip340Signature) -> bool {
+    let public_key = x_only_public_key(public_key);
+    let ours = signature.verify(&public_key, message);
+    let theirs = VerifyingKey::from_bytes(&public_key).unwrap().verify_raw(
+        message,
+        &Signature::try_from(&signature.to_bytes()[..]).unwrap(),
+    );
+    assert_eq!(ours, theirs.is_ok());
+    ours
+}
+
+/// Signer sets of `t + 1` or more parties agree on a signature that standard BIP-340 tooling
+/// accepts, for keys of both `y` parities.
+#[test]
+fn frost_signatures_verify_under_bip340() {
+    let message = b"transfer 1 BTC to the cold wallet".to_vec();
+    let mut parities_seen = [false; 2];
+    for _ in 0..32 {
+        let keys = keygen();
+        let public_key = *keys[&parties()[0]].public_key();
+        let parity = (public_key.to_compressed()[0] & 1) as usize;
+        if parities_seen[parity] {
+            continue;
+        }
+        parities_seen[parity] = true;
+
+        for signers in [&parties()[..T + 1], &parties()[2..], &parties()[1..]] {
+            let outcomes = sign(&keys, signers, &message);
+            let signature = outcomes[0].clone().unwrap();
+            for outcome in &outcomes {
+                assert_eq!(outcome.as_ref().unwrap(), &signature);
+            }
+            assert!(verifies(&public_key, &message, &signature));
+            assert!(!verifies(&public_key, b"another message", &signature));
+        }
+        if parities_seen == [true, true] {
+            return;
+        }
+    }
+    panic!("no key of each parity in 32 key generations");
+}
+
+/// The local verifier agrees with `k256` on signatures from a single signer, and rejects tampered
+/// ones.
+#[test]
+fn verifier_agrees_with_k256() {
+    let message = b"hello".to_vec();
+    for _ in 0..8 {
+        let mut rng = rand::rng();
+        let signing_key = SigningKey::from_bytes(&rng.random::<[u8; 32]>()).unwrap();
+        let public_key: [u8; 32] = signing_key.verifying_key().to_bytes().into();
+        let signature = signing_key
+            .sign_raw(&message, &rng.random::<[u8; 32]>())
+            .unwrap()
+            .to_bytes();
+        let signature = Bip340Signature::from_bytes(&signature);
+        assert!(signature.verify(&public_key, &message));
+        assert!(!signature.verify(&public_key, b"hellp"));
+
+        let mut tampered = signature.to_bytes();
+        tampered[63] ^= 1;
+        assert!(!Bip340Signature::from_bytes(&tampered).verify(&public_key, &message));
+    }
+}
+
+/// A signer whose partial signature does not match its verification key — here, one holding a
+/// share of a different key — is named by every honest signer.
+#[test]
+fn inconsistent_partial_signature_names_the_signer() {
+    let keys = keygen();
+    let other_keys = keygen();
+    let signers = &parties()[..T + 1];
+    let cheater = signers[1];
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        signers.to_vec(),
+        |pid| Sign {
+            key: if pid == cheater {
+                other_keys[&pid].clone()
+            } else {
+                keys[&pid].clone()
+            },
+            signers: signers.to_vec(),
+            message: b"message".to_vec(),
+        },
+        |_, net| env(net),
+        vec![],
+    );
+    let expected = Error::Cheater(cheater).to_string();
+    for signer in signers.iter().filter(|s| **s != cheater) {
+        assert_eq!(outcome.outputs[signer], Err(expected.clone()));
+    }
+}
+
+/// A signer that sends an empty packet in place of its nonce commitments.
+struct MalformedCommitments {
+    signers: Vec<PartyId>,
+}
+
+impl<E: RandEnvironment> Protocol<E> for MalformedCommitments {
+    type Output = Outcome;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let messages: Vec<_> = self
+            .signers
+            .iter()
+            .filter(|signer| **signer != me)
+            .map(|signer| (*signer, Packet::empty()))
+            .collect();
+        env.network_mut().send_many(&messages).await?;
+        Ok(Err("sent malformed commitments".to_string()))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("MalformedCommitments")
+    }
+}
+
+/// Either an honest [`Sign`] run, or a [`MalformedCommitments`] signer.
+enum Signer {
+    Honest(Box<Sign>),
+    Malformed(MalformedCommitments),
+}
+
+impl<E: RandEnvironment> Protocol<E> for Signer {
+    type Output = Outcome;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        match self {
+            Signer::Honest(sign) => sign.run(env).await,
+            Signer::Malformed(signer) => signer.run(env).await,
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("Signer")
+    }
+}
+
+/// A signer whose nonce commitments do not parse is named by every honest signer, rather than
+/// making them abort with a decoding error.
+#[test]
+fn malformed_commitments_name_the_signer() {
+    let keys = keygen();
+    let signers = &parties()[..T + 1];
+    let cheater = signers[1];
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        signers.to_vec(),
+        |pid| {
+            if pid == cheater {
+                Signer::Malformed(MalformedCommitments {
+                    signers: signers.to_vec(),
+                })
+            } else {
+                Signer::Honest(Box::new(Sign {
+                    key: keys[&pid].clone(),
+                    signers: signers.to_vec(),
+                    message: b"message".to_vec(),
+                }))
+            }
+        },
+        |_, net| env(net),
+        vec![],
+    );
+    let expected = Error::Cheater(cheater).to_string();
+    for signer in signers.iter().filter(|s| **s != cheater) {
+        assert_eq!(outcome.outputs[signer], Err(expected.clone()));
+    }
+}
+
+/// The signer set must be distinct, large enough, and within the key's parties.
+#[test]
+fn frost_rejects_bad_signer_sets() {
+    let keys = keygen();
+    let key = keys[&parties()[0]].clone();
+    let message = b"m".to_vec();
+    assert!(FrostSign::new(key.clone(), parties()[..T].to_vec(), message.clone()).is_err());
+    let twice = vec![parties()[0], parties()[1], parties()[1]];
+    assert!(FrostSign::new(key.clone(), twice, message.clone()).is_err());
+    let outsider = vec![parties()[0], parties()[1], PartyId::from(N)];
+    assert!(FrostSign::new(key.clone(), outsider, message.clone()).is_err());
+    assert!(FrostSign::new(key, parties(), message).is_ok());
+}
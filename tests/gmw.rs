@@ -0,0 +1,300 @@
+//! End-to-end tests for XOR sharing and the GMW boolean protocols (`protocol::gmw`) on the
+//! deterministic simulator: trusted-dealer triples, batched AND gates, and a small circuit built
+//! from them.
+
+use rand::RngExt;
+use scl_rs::{
+    math::{gf2::Gf2Word, ring::Ring},
+    net::{Network, PartyId},
+    prelude::{Error, Protocol, RandEnvironment},
+    protocol::{
+        dealer::{DealerRequest, TrustedDealer},
+        gmw::{
+            and::GmwAnd,
+            triple::{BooleanTriple, TrustedBooleanTriples},
+        },
+        share::{deal::PassiveDealShr, open::BatchedPassiveOpenShr},
+        ProtocolId,
+    },
+    ss::xor::XorSS,
+};
+
+mod common;
+
+const N: usize = 3;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+/// Arithmetic in `GF(2)^64`: XOR is addition, AND is multiplication, every word is its own
+/// negative, and the all-ones word is the multiplicative identity.
+#[test]
+fn gf2_word_is_a_ring() {
+    let mut rng = rand::rng();
+    let x = Gf2Word::random(&mut rng);
+    let y = Gf2Word::random(&mut rng);
+
+    assert_eq!(x + &y, Gf2Word::new(x.bits() ^ y.bits()));
+    assert_eq!(x - &y, x + &y);
+    assert_eq!(x * &y, Gf2Word::new(x.bits() & y.bits()));
+    assert_eq!(x + &x.negate(), Gf2Word::ZERO);
+    assert_eq!(x * &Gf2Word::ONE, x);
+    assert_eq!(x * &Gf2Word::ZERO, Gf2Word::ZERO);
+    assert!(Gf2Word::new(0b100).bit(2));
+    assert!(!Gf2Word::new(0b100).bit(1));
+}
+
+/// Deals `count` triples from `dealer` and hands back every party's shares as plain tuples.
+struct DealTriples {
+    dealer: PartyId,
+    count: usize,
+}
+
+impl<E: RandEnvironment> Protocol<E> for DealTriples {
+    type Output = Vec<(XorSS, XorSS, XorSS)>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let triples = TrustedBooleanTriples::new(self.dealer, parties(), self.count)?
+            .run(env)
+            .await?;
+        Ok(triples.into_iter().map(BooleanTriple::into_parts).collect())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("DealTriples")
+    }
+}
+
+/// The dealer hands out well-formed triples and keeps its own shares like every other party.
+#[test]
+fn dealer_deals_valid_triples() {
+    let dealer = parties()[N - 1];
+    let outputs = common::run(parties(), |_| DealTriples { dealer, count: 4 });
+
+    let per_party: Vec<&Vec<(XorSS, XorSS, XorSS)>> =
+        parties().iter().map(|party| &outputs[party]).collect();
+    for k in 0..4 {
+        let open = |get: fn(&(XorSS, XorSS, XorSS)) -> &XorSS| {
+            let shares: Vec<XorSS> = per_party
+                .iter()
+                .map(|triples| get(&triples[k]).clone())
+                .collect();
+            XorSS::secret_from_shares(&shares)
+        };
+        let (a, b, c) = (open(|t| &t.0), open(|t| &t.1), open(|t| &t.2));
+        assert_eq!(c, a * &b, "triple {k}: c != a ∧ b");
+    }
+}
+
+/// GMW opens among every party, so a dealer that keeps no shares would stall it.
+#[test]
+fn dealer_outside_receivers_is_rejected() {
+    let receivers = parties()[..N - 1].to_vec();
+    assert!(matches!(
+        TrustedBooleanTriples::new(parties()[N - 1], receivers, 4),
+        Err(Error::Input)
+    ));
+}
+
+/// Deals `count` random bits from party 0 through the generic dealer, over XOR sharing.
+struct DealRandomBits {
+    count: usize,
+}
+
+impl<E: RandEnvironment> Protocol<E> for DealRandomBits {
+    type Output = Vec<XorSS>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let dealer = parties()[0];
+        let request = DealerRequest {
+            random_bits: self.count,
+            ..DealerRequest::default()
+        };
+        let mut dealt = if env.network().local_party() == dealer {
+            TrustedDealer::dealer(dealer, parties(), (), (), request)
+        } else {
+            TrustedDealer::receiver(dealer, request)
+        }
+        .run(env)
+        .await?;
+        dealt.take_random_bits(self.count)
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("DealRandomBits")
+    }
+}
+
+/// A random bit over `GF(2)^64` is 64 independent bits, not a word that is all zeros or all ones.
+#[test]
+fn xor_random_bits_are_independent_per_lane() {
+    let outputs = common::run(parties(), |_| DealRandomBits { count: 4 });
+    let open = |k: usize| {
+        let shares: Vec<XorSS> = parties().iter().map(|p| outputs[p][k].clone()).collect();
+        XorSS::secret_from_shares(&shares)
+    };
+    for k in 0..4 {
+        let word = open(k);
+        assert!(
+            word != Gf2Word::ZERO && word != Gf2Word::ONE,
+            "random bit {k} is a constant word"
+        );
+    }
+}
+
+/// Party 0 deals `x`, party 1 deals `y`, and the parties AND them word by word.
+struct AndWords {
+    x: Vec<Gf2Word>,
+    y: Vec<Gf2Word>,
+}
+
+impl<E: RandEnvironment> Protocol<E> for AndWords {
+    type Output = Vec<Gf2Word>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let x = deal_words(env, parties()[0], &self.x).await?;
+        let y = deal_words(env, parties()[1], &self.y).await?;
+        let triples = TrustedBooleanTriples::new(parties()[2], parties(), x.len())?
+            .run(env)
+            .await?;
+        let z = GmwAnd::new(x, y, triples)?.run(env).await?;
+        BatchedPassiveOpenShr::new(z).run(env).await
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("AndWords")
+    }
+}
+
+/// Deals each word of `words` from `owner` to every party; only the owner's `words` are read.
+async fn deal_words<E: RandEnvironment>(
+    env: &mut E,
+    owner: PartyId,
+    words: &[Gf2Word],
+) -> Result<Vec<XorSS>, Error> {
+    let mut shares = Vec::with_capacity(words.len());
+    for word in words {
+        let deal = if env.network().local_party() == owner {
+            PassiveDealShr::dealer(owner, *word, parties(), ())
+        } else {
+            PassiveDealShr::receiver(owner)
+        };
+        shares.push(deal.run(env).await?);
+    }
+    Ok(shares)
+}
+
+/// A batch of AND gates opens to the bitwise AND of the inputs.
+#[test]
+fn gmw_and_computes_bitwise_and() {
+    let mut rng = rand::rng();
+    let x: Vec<Gf2Word> = (0..5).map(|_| Gf2Word::random(&mut rng)).collect();
+    let y: Vec<Gf2Word> = (0..5).map(|_| Gf2Word::random(&mut rng)).collect();
+    let outputs = common::run(parties(), |_| AndWords {
+        x: x.clone(),
+        y: y.clone(),
+    });
+
+    let expected: Vec<Gf2Word> = x.iter().zip(&y).map(|(x, y)| *x * y).collect();
+    for party in parties() {
+        assert_eq!(outputs[&party], expected);
+    }
+}
+
+/// A two-layer circuit: equality of 4-bit numbers, bit-sliced so each word holds one bit position
+/// of 64 independent comparisons. `x == y` iff every `¬(x_j ⊕ y_j)` is set, which takes two layers
+/// of AND gates.
+struct Equal4 {
+    x: Vec<Gf2Word>,
+    y: Vec<Gf2Word>,
+}
+
+impl<E: RandEnvironment> Protocol<E> for Equal4 {
+    type Output = Gf2Word;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let x = deal_words(env, parties()[0], &self.x).await?;
+        let y = deal_words(env, parties()[1], &self.y).await?;
+        let mut triples = TrustedBooleanTriples::new(parties()[2], parties(), 3)?
+            .run(env)
+            .await?;
+
+        // Local layer: bit j agrees iff ¬(x_j ⊕ y_j).
+        let agree: Vec<XorSS> = x
+            .into_iter()
+            .zip(&y)
+            .map(|(x, y)| x + y + &Gf2Word::ONE)
+            .collect();
+
+        // First AND layer: two gates in one round.
+        let layer_1_triples = triples.drain(..2).collect();
+        let halves = GmwAnd::new(
+            vec![agree[0].clone(), agree[2].clone()],
+            vec![agree[1].clone(), agree[3].clone()],
+            layer_1_triples,
+        )?
+        .run(env)
+        .await?;
+
+        // Second AND layer: the last gate.
+        let equal = GmwAnd::new(vec![halves[0].clone()], vec![halves[1].clone()], triples)?
+            .run(env)
+            .await?;
+        Ok(BatchedPassiveOpenShr::new(equal).run(env).await?[0])
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("Equal4")
+    }
+}
+
+#[test]
+fn gmw_evaluates_a_two_layer_circuit() {
+    // 64 comparisons; make about half of them equal so both outcomes are exercised.
+    let mut rng = rand::rng();
+    let xs: Vec<u8> = (0..64).map(|_| rng.random_range(0..16)).collect();
+    let ys: Vec<u8> = xs
+        .iter()
+        .map(|x| {
+            if rng.random() {
+                *x
+            } else {
+                rng.random_range(0..16)
+            }
+        })
+        .collect();
+    let bit_slice = |values: &[u8]| -> Vec<Gf2Word> {
+        (0..4)
+            .map(|j| {
+                Gf2Word::new(
+                    values
+                        .iter()
+                        .enumerate()
+                        .fold(0, |word, (i, v)| word | (u64::from((v >> j) & 1) << i)),
+                )
+            })
+            .collect()
+    };
+
+    let outputs = common::run(parties(), |_| Equal4 {
+        x: bit_slice(&xs),
+        y: bit_slice(&ys),
+    });
+
+    for (i, (x, y)) in xs.iter().zip(&ys).enumerate() {
+        assert_eq!(
+            outputs[&parties()[0]].bit(i),
+            x == y,
+            "instance {i}: {x} vs {y}"
+        );
+    }
+}
+
+/// The pairs and the triples must line up one to one.
+#[test]
+fn gmw_and_rejects_mismatched_lengths() {
+    let share = XorSS::new(Gf2Word::ONE, parties()[0], true);
+    let triple = BooleanTriple::new(share.clone(), share.clone(), share.clone());
+    assert!(GmwAnd::new(vec![share.clone()], vec![], vec![triple]).is_err());
+}
@@ -0,0 +1,470 @@
+//! Tests for oblivious transfer (`protocol::ot`).
+
+use rand::{Rng, RngExt, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    abbreviate::Abbreviate,
+    math::{
+        field::{
+            mersenne61::Mersenne61, secp256k1_prime::Secp256k1PrimeField,
+            secp256k1_scalar::Secp256k1ScalarField, FiniteField,
+        },
+        ring::Ring,
+    },
+    net::{simulation::channel::SimpleNetworkConfig, Packet, PartyId},
+    prelude::{simulate, Error, GeneralEnv, Network, Protocol, RandEnvironment},
+    protocol::{
+        dealer::BeaverTriple,
+        ot::{
+            base::BaseOt,
+            extension::{ExtendedOts, IknpOtExtension},
+            triple::GilboaTriple,
+            OtBlock,
+        },
+        ProtocolId,
+    },
+    ss::additive::AdditiveSS,
+};
+
+const SENDER: usize = 0;
+
+fn parties() -> Vec<PartyId> {
+    vec![PartyId::from(0), PartyId::from(1)]
+}
+
+fn random_pairs(count: usize) -> Vec<(OtBlock, OtBlock)> {
+    let mut rng = rand::rng();
+    (0..count)
+        .map(|_| {
+            let (mut m0, mut m1) = ([0; 32], [0; 32]);
+            rng.fill_bytes(&mut m0);
+            rng.fill_bytes(&mut m1);
+            (m0, m1)
+        })
+        .collect()
+}
+
+/// Runs a batch of base OTs of `messages` with `choices`, returning the sender's and the
+/// receiver's outputs.
+fn base_ot(
+    messages: &[(OtBlock, OtBlock)],
+    choices: &[bool],
+) -> (Option<Vec<OtBlock>>, Option<Vec<OtBlock>>) {
+    let mut outputs = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            if pid.as_usize() == SENDER {
+                BaseOt::sender(messages.to_vec())
+            } else {
+                BaseOt::receiver(choices.to_vec())
+            }
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    (
+        outputs.remove(&PartyId::from(0)).unwrap(),
+        outputs.remove(&PartyId::from(1)).unwrap(),
+    )
+}
+
+/// The receiver learns the message it chose in every transfer, and not the other one; the sender
+/// learns nothing.
+#[test]
+fn base_ot_receiver_learns_its_choices() {
+    let messages = random_pairs(32);
+    let choices: Vec<bool> = (0..32).map(|_| rand::rng().random()).collect();
+    let (sent, received) = base_ot(&messages, &choices);
+    assert_eq!(sent, None);
+    let received = received.unwrap();
+    assert_eq!(received.len(), 32);
+    for ((received, (m0, m1)), choice) in received.iter().zip(&messages).zip(&choices) {
+        let (chosen, other) = if *choice { (m1, m0) } else { (m0, m1) };
+        assert_eq!(received, chosen);
+        assert_ne!(received, other);
+    }
+}
+
+/// An empty batch transfers nothing.
+#[test]
+fn base_ot_empty_batch() {
+    assert_eq!(base_ot(&[], &[]), (None, Some(Vec::new())));
+}
+
+/// Either side of a transfer.
+enum Side {
+    Honest(BaseOt),
+    /// Sends a message that is not a point, and stops.
+    Garbage,
+    /// Sends the coordinates `(1 : 1 : 1)`, which are off the curve, as its point, and stops.
+    OffCurve,
+}
+
+impl<E: RandEnvironment> Protocol<E> for Side {
+    type Output = Result<Option<Vec<OtBlock>>, String>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let outcome = match self {
+            Side::Honest(ot) => ot.run(env).await,
+            Side::Garbage => {
+                let other = env.network().other()?;
+                let mut pkt = Packet::empty();
+                pkt.write(&[7u8; 3])?;
+                env.network_mut().send_to(other, &pkt).await?;
+                Ok(None)
+            }
+            Side::OffCurve => {
+                let other = env.network().other()?;
+                let one = Secp256k1PrimeField::ONE;
+                let mut pkt = Packet::empty();
+                pkt.write(&(one, one, one))?;
+                env.network_mut().send_to(other, &pkt).await?;
+                Ok(None)
+            }
+        };
+        Ok(outcome.map_err(|err| err.to_string()))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("OtSide")
+    }
+}
+
+/// A sender that does not send a point is caught.
+#[test]
+fn base_ot_rejects_a_malformed_sender() {
+    let outputs = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            if pid.as_usize() == SENDER {
+                Side::Garbage
+            } else {
+                Side::Honest(BaseOt::receiver(vec![true]))
+            }
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    assert_eq!(
+        outputs[&PartyId::from(1)],
+        Err(Error::Cheater(PartyId::from(SENDER)).to_string())
+    );
+}
+
+/// A point off the curve, whether the sender's `A` or the receiver's `B_i`, is caught.
+#[test]
+fn base_ot_rejects_points_off_the_curve() {
+    for cheater in [SENDER, 1 - SENDER] {
+        let outputs = simulate(
+            SimpleNetworkConfig::default(),
+            parties(),
+            |pid| {
+                if pid.as_usize() == cheater {
+                    Side::OffCurve
+                } else if pid.as_usize() == SENDER {
+                    Side::Honest(BaseOt::sender(random_pairs(1)))
+                } else {
+                    Side::Honest(BaseOt::receiver(vec![true]))
+                }
+            },
+            |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+            vec![],
+        )
+        .outputs;
+        assert_eq!(
+            outputs[&PartyId::from(1 - cheater)],
+            Err(Error::Cheater(PartyId::from(cheater)).to_string())
+        );
+    }
+}
+
+/// OT messages are labeled in the simulator's traces.
+#[test]
+fn base_ot_messages_are_labeled() {
+    let messages = random_pairs(2);
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            if pid.as_usize() == SENDER {
+                BaseOt::sender(messages.clone())
+            } else {
+                BaseOt::receiver(vec![false, true])
+            }
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    assert!(outcome.traces[&PartyId::from(0)]
+        .to_string()
+        .contains("OT ctxt."));
+}
+
+/// Runs an OT extension to `choices.len()` OTs, with or without the KOS check, and returns the
+/// sender's and the receiver's outputs.
+fn extend(choices: &[bool], kos_check: bool) -> (ExtendedOts, ExtendedOts) {
+    let mut outputs = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            let extension = if pid.as_usize() == SENDER {
+                IknpOtExtension::sender(choices.len())
+            } else {
+                IknpOtExtension::receiver(choices.to_vec())
+            };
+            if kos_check {
+                extension.with_kos_check()
+            } else {
+                extension
+            }
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    (
+        outputs.remove(&PartyId::from(0)).unwrap(),
+        outputs.remove(&PartyId::from(1)).unwrap(),
+    )
+}
+
+/// The extended OTs are correlated by `Δ` on the OTs the receiver chose `true` in, and the
+/// receiver's random OT message is the one it chose of the sender's pair.
+#[test]
+fn extension_gives_correlated_and_random_ots() {
+    let choices: Vec<bool> = (0..1000).map(|_| rand::rng().random()).collect();
+    for kos_check in [false, true] {
+        let (ExtendedOts::Sender(sender), ExtendedOts::Receiver(receiver)) =
+            extend(&choices, kos_check)
+        else {
+            panic!("wrong sides");
+        };
+        assert_eq!(receiver.choices(), choices);
+        assert_eq!(sender.correlated().len(), choices.len());
+        for ((q, t), choice) in sender
+            .correlated()
+            .iter()
+            .zip(receiver.correlated())
+            .zip(&choices)
+        {
+            assert_eq!(q ^ t, if *choice { sender.delta() } else { 0 });
+        }
+        let pairs = sender.random();
+        for ((received, (m0, m1)), choice) in receiver.random().iter().zip(&pairs).zip(&choices) {
+            let (chosen, other) = if *choice { (m1, m0) } else { (m0, m1) };
+            assert_eq!(received, chosen);
+            assert_ne!(received, other);
+        }
+    }
+}
+
+/// Extending to no OTs gives empty outputs.
+#[test]
+fn extension_to_no_ots() {
+    let (ExtendedOts::Sender(sender), ExtendedOts::Receiver(receiver)) = extend(&[], true) else {
+        panic!("wrong sides");
+    };
+    assert!(sender.correlated().is_empty());
+    assert!(receiver.correlated().is_empty());
+}
+
+/// Either side of an OT extension with the KOS check.
+enum ExtensionSide {
+    Honest(IknpOtExtension),
+    /// Runs the base OTs honestly, then sends random columns for `count` OTs and a random answer
+    /// to the check.
+    Inconsistent {
+        count: usize,
+    },
+}
+
+impl<E: RandEnvironment> Protocol<E> for ExtensionSide {
+    type Output = Result<Option<ExtendedOts>, String>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let outcome = match self {
+            ExtensionSide::Honest(extension) => extension.run(env).await.map(Some),
+            ExtensionSide::Inconsistent { count } => {
+                let other = env.network().other()?;
+                BaseOt::sender(random_pairs(128)).execute(env).await?;
+                let bytes = (count + 128 + 64).div_ceil(8);
+                let columns: Vec<Vec<u8>> = (0..128)
+                    .map(|_| {
+                        let mut column = vec![0; bytes];
+                        env.rng_mut().fill_bytes(&mut column);
+                        column
+                    })
+                    .collect();
+                let mut pkt = Packet::empty();
+                pkt.write_many(&columns)?;
+                env.network_mut().send_to(other, &pkt).await?;
+                env.network_mut().recv_from(other).await?;
+                let answer: (u128, u128) = (env.rng_mut().random(), env.rng_mut().random());
+                let mut pkt = Packet::empty();
+                pkt.write(&answer)?;
+                env.network_mut().send_to(other, &pkt).await?;
+                Ok(None)
+            }
+        };
+        Ok(outcome.map_err(|err| err.to_string()))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("ExtensionSide")
+    }
+}
+
+/// The KOS check catches a receiver whose columns are not consistent with any choices.
+#[test]
+fn kos_check_catches_an_inconsistent_receiver() {
+    let outputs = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            if pid.as_usize() == SENDER {
+                ExtensionSide::Honest(IknpOtExtension::sender(100).with_kos_check())
+            } else {
+                ExtensionSide::Inconsistent { count: 100 }
+            }
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    assert_eq!(
+        outputs[&PartyId::from(SENDER)],
+        Err(Error::Cheater(PartyId::from(1)).to_string())
+    );
+}
+
+/// Extension messages are labeled in the simulator's traces.
+#[test]
+fn extension_messages_are_labeled() {
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            if pid.as_usize() == SENDER {
+                IknpOtExtension::sender(8).with_kos_check()
+            } else {
+                IknpOtExtension::receiver(vec![true; 8]).with_kos_check()
+            }
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    let trace = outcome.traces[&PartyId::from(1)].to_string();
+    assert!(trace.contains("IKNP col."));
+    assert!(trace.contains("KOS resp."));
+}
+
+/// Generates `count` Gilboa triples and returns their shares.
+struct GenGilboa<const LIMBS: usize, F> {
+    count: usize,
+    _field: std::marker::PhantomData<F>,
+}
+
+type AdditiveTriple<F> = (AdditiveSS<F>, AdditiveSS<F>, AdditiveSS<F>);
+
+impl<const LIMBS: usize, F, E> Protocol<E> for GenGilboa<LIMBS, F>
+where
+    E: RandEnvironment,
+    F: FiniteField<LIMBS> + Send + Sync + Abbreviate + 'static,
+{
+    type Output = Vec<AdditiveTriple<F>>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        Ok(GilboaTriple::<LIMBS, F>::new(self.count)
+            .run(env)
+            .await?
+            .into_iter()
+            .map(BeaverTriple::into_parts)
+            .collect())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("GenGilboa")
+    }
+}
+
+/// Runs a Gilboa triple generation of `count` triples, returning both parties' shares.
+fn gilboa<const LIMBS: usize, F>(count: usize) -> [Vec<AdditiveTriple<F>>; 2]
+where
+    F: FiniteField<LIMBS> + Send + Sync + Abbreviate + 'static,
+{
+    let mut outputs = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |_| GenGilboa::<LIMBS, F> {
+            count,
+            _field: std::marker::PhantomData,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    [
+        outputs.remove(&PartyId::from(0)).unwrap(),
+        outputs.remove(&PartyId::from(1)).unwrap(),
+    ]
+}
+
+/// Asserts that the shares of every triple reconstruct to `(a, b, a · b)`, and that exactly one
+/// party absorbs public constants.
+fn assert_triples<const LIMBS: usize, F: FiniteField<LIMBS>>(
+    shares: &[Vec<AdditiveTriple<F>>; 2],
+    count: usize,
+) {
+    assert_eq!(shares[0].len(), count);
+    assert_eq!(shares[1].len(), count);
+    for (first, second) in shares[0].iter().zip(&shares[1]) {
+        let open = |x: &AdditiveSS<F>, y: &AdditiveSS<F>| {
+            AdditiveSS::secret_from_shares(&[x.clone(), y.clone()])
+        };
+        let (a, b, c) = (
+            open(&first.0, &second.0),
+            open(&first.1, &second.1),
+            open(&first.2, &second.2),
+        );
+        assert_eq!(c, a * &b);
+        assert_eq!(
+            open(&(first.2.clone() + &F::ONE), &(second.2.clone() + &F::ONE)),
+            c + &F::ONE
+        );
+    }
+}
+
+/// Gilboa triples reconstruct to products, over a small and a large field.
+#[test]
+fn gilboa_triples_are_products() {
+    assert_triples(&gilboa::<1, Mersenne61>(8), 8);
+    assert_triples(&gilboa::<4, Secp256k1ScalarField>(2), 2);
+}
+
+/// Generating no triples gives empty outputs.
+#[test]
+fn gilboa_no_triples() {
+    assert_triples(&gilboa::<1, Mersenne61>(0), 0);
+}
+
+/// The OLE corrections are labeled as field elements in the simulator's traces.
+#[test]
+fn gilboa_messages_are_labeled() {
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |_| GenGilboa::<1, Mersenne61> {
+            count: 1,
+            _field: std::marker::PhantomData,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    assert!(outcome.traces[&PartyId::from(0)]
+        .to_string()
+        .contains("field elem."));
+}
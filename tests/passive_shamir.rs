@@ -1,5 +1,6 @@
 //! End-to-end tests for the passive DN07 protocols (`protocol::passive_shamir`) on the
-//! deterministic simulator: `Random`, `Double-Random`, the batched open, and triple generation.
+//! deterministic simulator: `Random`, `Double-Random`, the batched open, triple generation,
+//! resharing to a new committee, and conversions between sharings.
 //!
 //! The parameters are `n = 5`, `t = 2`, satisfying DN07's `n >= 2t + 1`; each run of `Random` /
 //! `Double-Random` therefore yields `n - t = 3` outputs.
@@ -8,18 +9,21 @@ use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
 use scl_rs::{
     math::field::mersenne61::Mersenne61,
-    net::{simulation::channel::SimpleNetworkConfig, PartyId},
+    net::{simulation::channel::SimpleNetworkConfig, Network, PartyId},
     prelude::{simulate, Error, GeneralEnv, Protocol, RandEnvironment},
     protocol::{
         passive_shamir::{
+            convert::{PassiveAdditiveToShamir, PassiveDegreeReduction},
             double_rand_share::PassiveRandDoubleShr,
             mul::PassiveShamirMul,
             rand_share::PassiveRandShr,
+            reshare::PassiveReshareShamir,
             triple::{PassiveTriple, ShamirTriple},
         },
+        share::deal::PassiveDealShr,
         ProtocolId,
     },
-    ss::{shamir::ShamirSS, LinearShare},
+    ss::{additive::AdditiveSS, shamir::ShamirSS, LinearShare},
 };
 
 type F = Mersenne61;
@@ -256,3 +260,241 @@ fn beaver_rejects_degree_at_or_above_party_count() {
     )
     .is_err());
 }
+
+/// Deals `secret` among the old committee (the first old party is the dealer), then reshares it to
+/// the new committee with degree `new_degree`.
+struct DealThenReshare {
+    old: Vec<PartyId>,
+    new: Vec<PartyId>,
+    new_degree: usize,
+    secret: F,
+}
+
+impl<E: RandEnvironment> Protocol<E> for DealThenReshare {
+    type Output = Option<Share>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let old_share = if self.old.contains(&me) {
+            let deal = if me == self.old[0] {
+                PassiveDealShr::dealer(me, self.secret, self.old.clone(), T)
+            } else {
+                PassiveDealShr::receiver(self.old[0])
+            };
+            Some(deal.run(env).await?)
+        } else {
+            None
+        };
+        PassiveReshareShamir::new(old_share, self.old, self.new, self.new_degree)?
+            .run(env)
+            .await
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("DealThenReshare")
+    }
+}
+
+/// Runs [`DealThenReshare`] over the union of both committees and returns the new committee's
+/// shares, in `new` order.
+fn reshare(old: Vec<PartyId>, new: Vec<PartyId>, new_degree: usize, secret: F) -> Vec<Share> {
+    let mut everyone = old.clone();
+    everyone.extend(new.iter().filter(|party| !old.contains(party)));
+
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        everyone.clone(),
+        |_| DealThenReshare {
+            old: old.clone(),
+            new: new.clone(),
+            new_degree,
+            secret,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+
+    for party in old.iter().filter(|party| !new.contains(party)) {
+        assert!(
+            outcome.outputs[party].is_none(),
+            "{party:?} left but kept a share"
+        );
+    }
+    new.iter()
+        .map(|party| {
+            outcome.outputs[party]
+                .clone()
+                .expect("new parties get a share")
+        })
+        .collect()
+}
+
+/// Resharing to a disjoint committee with a smaller threshold: the new parties alone, at the new
+/// degree, reconstruct the old secret.
+#[test]
+fn reshare_to_disjoint_committee() {
+    let secret = F::from(4242u64);
+    let new: Vec<PartyId> = (N..N + 3).map(PartyId::from).collect();
+    let shares = reshare(parties(), new.clone(), 1, secret);
+
+    assert!(shares.iter().all(|share| share.degree() == 1));
+    assert_eq!(
+        <Share as LinearShare>::secret_from_shares(&shares, &new).unwrap(),
+        secret
+    );
+    // Any `t' + 1 = 2` of the new shares suffice.
+    assert_eq!(
+        <Share as LinearShare>::secret_from_shares(&shares[1..], &new[1..]).unwrap(),
+        secret
+    );
+}
+
+/// Resharing to an overlapping committee that drops two parties, adds three and raises the
+/// threshold. Only `t + 1` old parties need to take part.
+#[test]
+fn reshare_to_overlapping_committee_with_higher_threshold() {
+    let secret = F::from(7u64);
+    let old: Vec<PartyId> = parties()[..T + 1].to_vec();
+    let new: Vec<PartyId> = [1, 2, 3, 4, 5, 6, 7]
+        .into_iter()
+        .map(PartyId::from)
+        .collect();
+    let shares = reshare(old, new.clone(), 3, secret);
+
+    assert!(shares.iter().all(|share| share.degree() == 3));
+    assert_eq!(
+        <Share as LinearShare>::secret_from_shares(&shares[..4], &new[..4]).unwrap(),
+        secret
+    );
+    assert_eq!(
+        <Share as LinearShare>::secret_from_shares(&shares[3..], &new[3..]).unwrap(),
+        secret
+    );
+}
+
+/// The new sharing must be reconstructible, and the old committee must be able to interpolate.
+#[test]
+fn reshare_rejects_bad_parameters() {
+    let share = Share::new(F::from(1u64), T);
+    let new: Vec<PartyId> = (N..N + 3).map(PartyId::from).collect();
+
+    assert!(PassiveReshareShamir::new(Some(share.clone()), parties(), new.clone(), 3).is_err());
+    assert!(PassiveReshareShamir::new(
+        Some(share.clone()),
+        parties()[..T].to_vec(),
+        new.clone(),
+        1
+    )
+    .is_err());
+    let repeated = vec![parties()[0], parties()[0], parties()[1]];
+    assert!(PassiveReshareShamir::new(Some(share.clone()), repeated, new.clone(), 1).is_err());
+    assert!(PassiveReshareShamir::new(Some(share), parties(), new, 2).is_ok());
+}
+
+/// Deals `secret` additively from the first party, then converts the sharing to Shamir at degree
+/// `T`.
+struct AdditiveThenShamir {
+    secret: F,
+}
+
+impl<E: RandEnvironment> Protocol<E> for AdditiveThenShamir {
+    type Output = Share;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let deal = if me == parties()[0] {
+            PassiveDealShr::dealer(me, self.secret, parties(), ())
+        } else {
+            PassiveDealShr::receiver(parties()[0])
+        };
+        let additive: AdditiveSS<F> = deal.run(env).await?;
+        PassiveAdditiveToShamir::new(additive, parties(), T)?
+            .run(env)
+            .await
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("AdditiveThenShamir")
+    }
+}
+
+/// Additive-to-Shamir conversion yields a degree-`t` sharing of the additively shared secret.
+#[test]
+fn additive_to_shamir_keeps_secret() {
+    let secret = F::from(31337u64);
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |_| AdditiveThenShamir { secret },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+
+    let shares: Vec<Share> = parties()
+        .iter()
+        .map(|p| outcome.outputs[p].clone())
+        .collect();
+    assert!(shares.iter().all(|share| share.degree() == T));
+    assert_eq!(
+        <Share as LinearShare>::secret_from_shares(&shares[..T + 1], &parties()[..T + 1]).unwrap(),
+        secret
+    );
+}
+
+/// Multiplies two random sharings locally, then reduces the degree-`2t` product back to degree
+/// `t`, returning `[x]`, `[y]` and the reduced `[x · y]`.
+struct MulThenReduce;
+
+impl<E: RandEnvironment> Protocol<E> for MulThenReduce {
+    type Output = (Share, Share, Share);
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let x = PassiveRandShr::<1, F>::new(T, parties())?.run(env).await?;
+        let y = PassiveRandShr::<1, F>::new(T, parties())?.run(env).await?;
+        let product = x[0].clone() * &y[0];
+        let reduced = PassiveDegreeReduction::new(product, parties(), T)?
+            .run(env)
+            .await?;
+        Ok((x[0].clone(), y[0].clone(), reduced))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("MulThenReduce")
+    }
+}
+
+/// Degree reduction turns the local degree-`2t` product into a degree-`t` sharing of the same
+/// value, so `t + 1` shares open it again.
+#[test]
+fn degree_reduction_halves_the_product_degree() {
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |_| MulThenReduce,
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+
+    let x = open(
+        parties()
+            .iter()
+            .map(|p| outcome.outputs[p].0.clone())
+            .collect(),
+    );
+    let y = open(
+        parties()
+            .iter()
+            .map(|p| outcome.outputs[p].1.clone())
+            .collect(),
+    );
+    let reduced: Vec<Share> = parties()
+        .iter()
+        .map(|p| outcome.outputs[p].2.clone())
+        .collect();
+
+    assert!(reduced.iter().all(|share| share.degree() == T));
+    assert_eq!(
+        <Share as LinearShare>::secret_from_shares(&reduced[..T + 1], &parties()[..T + 1]).unwrap(),
+        x * &y
+    );
+}
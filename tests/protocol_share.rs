@@ -1,7 +1,7 @@
-//! End-to-end tests for the passive-adversary deal/open protocols in `protocol::share`, run on the
-//! deterministic simulator over both built-in `LinearShare` schemes (additive and Shamir). The
-//! party ids are the usual `0`-based network ids; Shamir's `encode_party` shifts them to the field
-//! points `i + 1`, keeping the point `0` reserved for the secret.
+//! End-to-end tests for the passive-adversary deal/open/refresh protocols in `protocol::share`, run
+//! on the deterministic simulator over both built-in `LinearShare` schemes (additive and Shamir).
+//! The party ids are the usual `0`-based network ids; Shamir's `encode_party` shifts them to the
+//! field points `i + 1`, keeping the point `0` reserved for the secret.
 
 use rand::SeedableRng;
 use rand_chacha::ChaCha20Rng;
@@ -13,6 +13,7 @@ use scl_rs::{
         share::{
             deal::PassiveDealShr,
             open::{PassiveOpenShr, PassiveOpenToParty},
+            refresh::PassiveProactiveRefresh,
         },
         ProtocolId,
     },
@@ -87,7 +88,7 @@ where
         SimpleNetworkConfig::default(),
         parties.clone(),
         |pid| DealThenOpen::<S> {
-            deal: deal_for(pid, dealer, secret, threshold),
+            deal: deal_for(pid, dealer, secret, threshold.clone()),
         },
         |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
         vec![],
@@ -154,7 +155,7 @@ where
         SimpleNetworkConfig::default(),
         parties.clone(),
         |pid| DealAffineOpen::<S> {
-            deal: deal_for(pid, dealer, secret, threshold),
+            deal: deal_for(pid, dealer, secret, threshold.clone()),
             a,
             b,
         },
@@ -223,7 +224,7 @@ where
         SimpleNetworkConfig::default(),
         parties.clone(),
         |pid| DealThenOpenTo::<S> {
-            deal: deal_for(pid, dealer, secret, threshold),
+            deal: deal_for(pid, dealer, secret, threshold.clone()),
             receiver,
         },
         |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
@@ -249,3 +250,89 @@ fn additive_deal_then_open_to_party() {
 fn shamir_deal_then_open_to_party() {
     deal_then_open_to_party::<ShamirSS<1, Mersenne61>>(SHAMIR_DEGREE);
 }
+
+/// Composed test protocol: receive a share from the dealer, refresh it, and hand back both the old
+/// and the refreshed share so the test can mix them.
+struct DealThenRefresh<S>
+where
+    S: LinearShare,
+{
+    deal: PassiveDealShr<S>,
+    threshold: S::Threshold,
+}
+
+impl<S, E> Protocol<E> for DealThenRefresh<S>
+where
+    S: LinearShare + Abbreviate,
+    E: RandEnvironment,
+    S::Value: Send + Sync + 'static,
+{
+    type Output = (S, S);
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let old: S = self.deal.execute(env).await?;
+        let new = PassiveProactiveRefresh::new(old.clone(), parties(), self.threshold)
+            .execute(env)
+            .await?;
+        Ok((old, new))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("DealThenRefresh")
+    }
+}
+
+/// Deals a secret, refreshes it, and checks that the refreshed shares still reconstruct the secret
+/// while every share has changed — and that mixing party 0's *old* share into the *new* set (the
+/// leak-across-epochs scenario a refresh defends against) no longer reconstructs it.
+fn refresh_keeps_secret_and_breaks_mixing<S>(threshold: S::Threshold)
+where
+    S: LinearShare<Value = Mersenne61> + Abbreviate + PartialEq + std::fmt::Debug + 'static,
+{
+    let parties = parties();
+    let dealer = parties[0];
+    let secret = Mersenne61::from(271_828u64);
+
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties.clone(),
+        |pid| DealThenRefresh::<S> {
+            deal: deal_for(pid, dealer, secret, threshold.clone()),
+            threshold: threshold.clone(),
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+
+    let old: Vec<S> = parties
+        .iter()
+        .map(|p| outcome.outputs[p].0.clone())
+        .collect();
+    let new: Vec<S> = parties
+        .iter()
+        .map(|p| outcome.outputs[p].1.clone())
+        .collect();
+
+    assert_eq!(S::secret_from_shares(&new, &parties).unwrap(), secret);
+    for (old_share, new_share) in old.iter().zip(&new) {
+        assert_ne!(old_share, new_share, "a refresh must change every share");
+    }
+
+    let mut mixed = new.clone();
+    mixed[0] = old[0].clone();
+    assert_ne!(
+        S::secret_from_shares(&mixed, &parties).unwrap(),
+        secret,
+        "shares from different epochs must not combine"
+    );
+}
+
+#[test]
+fn additive_refresh_keeps_secret_and_breaks_mixing() {
+    refresh_keeps_secret_and_breaks_mixing::<AdditiveSS<Mersenne61>>(());
+}
+
+#[test]
+fn shamir_refresh_keeps_secret_and_breaks_mixing() {
+    refresh_keeps_secret_and_breaks_mixing::<ShamirSS<1, Mersenne61>>(SHAMIR_DEGREE);
+}
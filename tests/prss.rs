@@ -0,0 +1,238 @@
+//! Tests for pseudo-random secret sharing (`protocol::randomness::prss`).
+
+use std::collections::HashMap;
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    math::{field::mersenne61::Mersenne61, ring::Ring},
+    net::{simulation::channel::SimpleNetworkConfig, PartyId},
+    prelude::{simulate, GeneralEnv},
+    protocol::{
+        randomness::prss::{Prss, PrssEnv, PrssEnvironment, PrssSetup},
+        Error, Protocol, ProtocolId, RandEnvironment,
+    },
+    ss::{shamir::ShamirSS, LinearShare},
+};
+
+type Share = ShamirSS<1, Mersenne61>;
+type Sampler = Prss<1, Mersenne61>;
+
+fn parties(n: usize) -> Vec<PartyId> {
+    (0..n).map(PartyId::from).collect()
+}
+
+fn setup(n: usize, t: usize) -> HashMap<PartyId, Sampler> {
+    simulate(
+        SimpleNetworkConfig::default(),
+        parties(n),
+        |_| PrssSetup::new(parties(n), t).unwrap(),
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs
+}
+
+fn reconstruct(shares: &[Share], parties: &[PartyId]) -> Mersenne61 {
+    <Share as LinearShare>::secret_from_shares(shares, parties).unwrap()
+}
+
+/// Draws the next sharing from every party's sampler, with `draw`, in party order.
+fn draw(
+    samplers: &mut HashMap<PartyId, Sampler>,
+    n: usize,
+    draw: fn(&mut Sampler) -> Share,
+) -> Vec<Share> {
+    parties(n)
+        .iter()
+        .map(|party| draw(samplers.get_mut(party).unwrap()))
+        .collect()
+}
+
+/// Random sharings have degree `t`: every `t + 1` parties reconstruct the same value, and
+/// successive sharings are of different values.
+#[test]
+fn random_sharings_are_consistent() {
+    for (n, t) in [(3, 1), (4, 1), (5, 2), (6, 2)] {
+        let mut samplers = setup(n, t);
+        let mut previous = None;
+        for _ in 0..4 {
+            let shares = draw(&mut samplers, n, Sampler::random_share);
+            assert!(shares.iter().all(|share| share.degree() == t));
+            let secret = reconstruct(&shares, &parties(n));
+            for start in 0..n - t {
+                let window = start..start + t + 1;
+                assert_eq!(
+                    reconstruct(&shares[window.clone()], &parties(n)[window]),
+                    secret
+                );
+            }
+            assert_ne!(previous, Some(secret));
+            previous = Some(secret);
+        }
+    }
+}
+
+/// Zero sharings have degree `2t` and reconstruct to zero, though the shares themselves are not.
+#[test]
+fn zero_sharings_reconstruct_to_zero() {
+    for (n, t) in [(3, 1), (5, 2), (7, 3)] {
+        let mut samplers = setup(n, t);
+        for _ in 0..4 {
+            let shares = draw(&mut samplers, n, Sampler::zero_share);
+            assert!(shares.iter().all(|share| share.degree() == 2 * t));
+            assert_eq!(reconstruct(&shares, &parties(n)), Mersenne61::ZERO);
+            assert!(shares
+                .iter()
+                .any(|share| *share.share() != Mersenne61::ZERO));
+        }
+    }
+}
+
+/// A zero sharing masks a product of random sharings without changing its value.
+#[test]
+fn zero_sharing_masks_a_product() {
+    let (n, t) = (5, 2);
+    let mut samplers = setup(n, t);
+    let a = draw(&mut samplers, n, Sampler::random_share);
+    let b = draw(&mut samplers, n, Sampler::random_share);
+    let zero = draw(&mut samplers, n, Sampler::zero_share);
+    let product: Vec<Share> = (0..n)
+        .map(|i| Share::new(*a[i].share() * b[i].share() + zero[i].share(), 2 * t))
+        .collect();
+    assert_eq!(
+        reconstruct(&product, &parties(n)),
+        reconstruct(&a, &parties(n)) * &reconstruct(&b, &parties(n))
+    );
+}
+
+/// The batch samplers draw the same sharings as repeated single draws.
+#[test]
+fn batches_match_single_draws() {
+    let (n, t) = (4, 1);
+    let mut samplers = setup(n, t);
+    let mut copies = samplers.clone();
+    for party in parties(n) {
+        let batch = samplers.get_mut(&party).unwrap().random_shares(3);
+        let single: Vec<Share> = (0..3)
+            .map(|_| copies.get_mut(&party).unwrap().random_share())
+            .collect();
+        assert_eq!(batch, single);
+        let batch = samplers.get_mut(&party).unwrap().zero_shares(2);
+        let single: Vec<Share> = (0..2)
+            .map(|_| copies.get_mut(&party).unwrap().zero_share())
+            .collect();
+        assert_eq!(batch, single);
+    }
+}
+
+/// Duplicate parties and thresholds of `n` or more are rejected.
+#[test]
+fn setup_rejects_bad_parameters() {
+    assert!(PrssSetup::<1, Mersenne61>::new(parties(3), 2).is_ok());
+    assert!(PrssSetup::<1, Mersenne61>::new(parties(3), 3).is_err());
+    let twice = vec![PartyId::from(0), PartyId::from(1), PartyId::from(1)];
+    assert!(PrssSetup::<1, Mersenne61>::new(twice, 1).is_err());
+}
+
+/// A party that is not among the setup's parties gets `Error::Input` instead of waiting for keys
+/// that never come.
+#[test]
+fn setup_rejects_an_outsider() {
+    struct Outsider;
+
+    impl<E: RandEnvironment> Protocol<E> for Outsider {
+        type Output = Result<(), String>;
+
+        async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+            Ok(PrssSetup::<1, Mersenne61>::new(parties(3), 1)
+                .unwrap()
+                .execute(env)
+                .await
+                .map(|_| ())
+                .map_err(|err| err.to_string()))
+        }
+
+        fn id(&self) -> ProtocolId {
+            ProtocolId::from("Outsider")
+        }
+    }
+
+    let outputs = simulate(
+        SimpleNetworkConfig::default(),
+        parties(4),
+        |_| Outsider,
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    for (party, outcome) in outputs {
+        if party == PartyId::from(3) {
+            assert_eq!(outcome, Err(Error::Input.to_string()));
+        } else {
+            assert_eq!(outcome, Ok(()));
+        }
+    }
+}
+
+/// A sub-protocol that draws from the sampler of its environment.
+struct DrawFromEnv;
+
+impl<E: PrssEnvironment<1, Mersenne61>> Protocol<E> for DrawFromEnv {
+    type Output = Share;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        Ok(env.prss_mut().random_share())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("DrawFromEnv")
+    }
+}
+
+/// Sets up PRSS, then runs `DrawFromEnv` twice in a `PrssEnv` built on the session environment.
+struct SetupAndDraw(usize, usize);
+
+impl<E: RandEnvironment> Protocol<E> for SetupAndDraw {
+    type Output = Vec<Share>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let SetupAndDraw(n, t) = self;
+        let prss = PrssSetup::new(parties(n), t)?.execute(env).await?;
+        let mut env = PrssEnv::new(env, prss);
+        Ok(vec![
+            DrawFromEnv.execute(&mut env).await?,
+            DrawFromEnv.execute(&mut env).await?,
+        ])
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("SetupAndDraw")
+    }
+}
+
+/// Protocols reach the sampler through `PrssEnvironment`, and draw consistent sharings from it.
+#[test]
+fn sampler_is_reachable_through_the_environment() {
+    let (n, t) = (4, 1);
+    let outputs = simulate(
+        SimpleNetworkConfig::default(),
+        parties(n),
+        |_| SetupAndDraw(n, t),
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs;
+    let secrets: Vec<Mersenne61> = (0..2)
+        .map(|k| {
+            let shares: Vec<Share> = parties(n)
+                .iter()
+                .map(|party| outputs[party][k].clone())
+                .collect();
+            let secret = reconstruct(&shares, &parties(n));
+            assert_eq!(reconstruct(&shares[..t + 1], &parties(n)[..t + 1]), secret);
+            secret
+        })
+        .collect();
+    assert_ne!(secrets[0], secrets[1]);
+}
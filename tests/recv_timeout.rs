@@ -226,3 +226,65 @@ fn recv_any_prompt_sender_succeeds() {
     );
     assert!(outcome.outputs[&p0], "expected P0 to receive the packet");
 }
+
+/// P1 sends one packet right away and another after sleeping 2s; P0 sleeps 1s. Once awake, P0 must
+/// find the first packet already queued, while the second has not arrived yet.
+pub struct SleepProtocol;
+
+impl<E: Environment> Protocol<E> for SleepProtocol {
+    type Output = bool;
+
+    async fn run(self, environment: &mut E) -> Result<bool, Error> {
+        let me = environment.network().local_party();
+        let other = environment.network().other()?;
+        if me.as_usize() == 0 {
+            environment
+                .network_mut()
+                .sleep(Duration::from_secs(1))
+                .await?;
+            let first = environment
+                .network_mut()
+                .recv_any_with_timeout(Duration::ZERO)
+                .await;
+            let second = environment
+                .network_mut()
+                .recv_any_with_timeout(Duration::ZERO)
+                .await;
+            environment.network_mut().close().await?;
+            Ok(matches!(first, Ok((sender, _)) if sender == other)
+                && matches!(second, Err(scl_rs::net::NetworkError::Timeout(None))))
+        } else {
+            let mut packet = Packet::empty();
+            packet.write(&42_usize).unwrap();
+            environment.network_mut().send_to(other, &packet).await?;
+            environment
+                .network_mut()
+                .sleep(Duration::from_secs(2))
+                .await?;
+            environment.network_mut().send_to(other, &packet).await?;
+            environment.network_mut().close().await?;
+            Ok(true)
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("SleepProtocol")
+    }
+}
+
+#[test]
+fn sleep_keeps_packets_queued() {
+    let p0 = PartyId::from(0_usize);
+    let p1 = PartyId::from(1_usize);
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        vec![p0, p1],
+        |_| SleepProtocol,
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    assert!(
+        outcome.outputs[&p0],
+        "expected the first packet queued and the second still in flight"
+    );
+}
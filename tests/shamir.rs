@@ -4,7 +4,7 @@ use scl_rs::{
     math::field::mersenne61::Mersenne61,
     net::PartyId,
     prelude::Ring,
-    ss::{shamir::ShamirSS, LinearShare, ShareError},
+    ss::{additive::AdditiveSS, shamir::ShamirSS, LinearShare, ShareError},
 };
 
 fn party_indexes(n: u64) -> Vec<Mersenne61> {
@@ -118,3 +118,51 @@ proptest! {
         common::roundtrip(s)?;
     }
 }
+
+/// Shamir-to-additive conversion is local: the members of any reconstruction set of at least
+/// `t + 1` parties scale their shares into additive shares of the same secret, with the smallest
+/// member as the leader.
+#[test]
+fn to_additive_over_any_reconstruction_set() {
+    const T: usize = 2;
+    const N: usize = 6;
+
+    let mut rng = rand::rng();
+    let secret = Mersenne61::random(&mut rng);
+    let parties: Vec<PartyId> = (0..N).map(PartyId::from).collect();
+    let shares =
+        <ShamirSS<1, Mersenne61> as LinearShare>::shares_from_secret(secret, &parties, T, &mut rng)
+            .unwrap();
+
+    for set in [&parties[..T + 1], &parties[2..], &parties[..]] {
+        let additive: Vec<AdditiveSS<Mersenne61>> = set
+            .iter()
+            .map(|party| shares[party.as_usize()].to_additive(*party, set).unwrap())
+            .collect();
+        assert_eq!(AdditiveSS::secret_from_shares(&additive), secret);
+
+        // A public constant lands on exactly one component.
+        let one = Mersenne61::ONE;
+        let shifted: Vec<_> = additive.into_iter().map(|share| share + &one).collect();
+        assert_eq!(AdditiveSS::secret_from_shares(&shifted), secret + &one);
+    }
+}
+
+/// The converting party must belong to the reconstruction set, and the set must be large enough
+/// to interpolate.
+#[test]
+fn to_additive_rejects_bad_reconstruction_sets() {
+    const T: usize = 2;
+
+    let share = ShamirSS::<1, Mersenne61>::new(Mersenne61::from(5u64), T);
+    let parties: Vec<PartyId> = (0..5usize).map(PartyId::from).collect();
+
+    assert!(matches!(
+        share.to_additive(parties[4], &parties[..T + 1]),
+        Err(ShareError::PartyNotInSet { .. })
+    ));
+    assert!(matches!(
+        share.to_additive(parties[0], &parties[..T]),
+        Err(ShareError::NotEnoughShares)
+    ));
+}
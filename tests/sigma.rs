@@ -0,0 +1,208 @@
+//! Tests for Σ-protocols (`sigma`): the Schnorr, DLEQ and representation proofs, their AND/OR
+//! composition, and both ways of running them.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    math::{
+        ec::{secp256k1::Secp256k1, EllipticCurve},
+        field::secp256k1_scalar::Secp256k1ScalarField,
+        ring::Ring,
+    },
+    net::{simulation::channel::SimpleNetworkConfig, PartyId},
+    prelude::{simulate, GeneralEnv},
+    sigma::{
+        composition::{And, Or, OrWitness},
+        dleq::Dleq,
+        fiat_shamir::NonInteractiveProof,
+        interactive::InteractiveProof,
+        representation::Representation,
+        schnorr::Schnorr,
+        SigmaProtocol,
+    },
+    transcript::Transcript,
+};
+
+type Scalar = Secp256k1ScalarField;
+type Point = Secp256k1;
+
+fn random_scalar() -> Scalar {
+    Scalar::random(&mut rand::rng())
+}
+
+fn random_point() -> Point {
+    Point::gen().scalar_mul(&random_scalar())
+}
+
+/// A Schnorr statement and its witness.
+fn schnorr() -> (Schnorr<4, Point>, Scalar) {
+    let x = random_scalar();
+    (Schnorr::new(Point::gen(), Point::gen().scalar_mul(&x)), x)
+}
+
+/// Proves `statement` non-interactively, and verifies the proof on a fresh transcript of the same
+/// domain.
+fn prove_and_verify<P: SigmaProtocol>(statement: &P, witness: &P::Witness) -> bool {
+    let proof = NonInteractiveProof::prove(
+        statement,
+        witness,
+        &mut Transcript::new(b"sigma test"),
+        &mut rand::rng(),
+    );
+    proof.verify(statement, &mut Transcript::new(b"sigma test"))
+}
+
+#[test]
+fn schnorr_proof_verifies_only_with_the_witness() {
+    let (statement, x) = schnorr();
+    assert!(prove_and_verify(&statement, &x));
+    assert!(!prove_and_verify(&statement, &(x + &Scalar::ONE)));
+}
+
+#[test]
+fn dleq_proof_verifies_only_for_equal_logarithms() {
+    let (x, h) = (random_scalar(), random_point());
+    let g = Point::gen();
+    let statement = Dleq::new(g, h, g.scalar_mul(&x), h.scalar_mul(&x));
+    assert!(prove_and_verify(&statement, &x));
+
+    let unequal = Dleq::new(g, h, g.scalar_mul(&x), h.scalar_mul(&random_scalar()));
+    assert!(!prove_and_verify(&unequal, &x));
+}
+
+#[test]
+fn representation_proof_verifies_only_with_the_witness() {
+    let generators: Vec<Point> = (0..3).map(|_| random_point()).collect();
+    let witness: Vec<Scalar> = (0..3).map(|_| random_scalar()).collect();
+    let public = generators
+        .iter()
+        .zip(&witness)
+        .fold(Point::ZERO, |sum, (g, x)| sum.add(&g.scalar_mul(x)));
+    let statement = Representation::new(generators, public);
+    assert!(prove_and_verify(&statement, &witness));
+
+    let mut wrong = witness.clone();
+    wrong[1] = random_scalar();
+    assert!(!prove_and_verify(&statement, &wrong));
+    assert!(!prove_and_verify(&statement, &witness[..2].to_vec()));
+}
+
+#[test]
+fn and_proof_needs_both_witnesses() {
+    let ((left, x), (right, y)) = (schnorr(), schnorr());
+    let statement = And::new(left, right);
+    assert!(prove_and_verify(&statement, &(x, y)));
+    assert!(!prove_and_verify(&statement, &(x, x)));
+}
+
+#[test]
+fn or_proof_needs_either_witness() {
+    let ((left, x), (right, y)) = (schnorr(), schnorr());
+    let statement = Or::new(left, right);
+    assert!(prove_and_verify(&statement, &OrWitness::Left(x)));
+    assert!(prove_and_verify(&statement, &OrWitness::Right(y)));
+    assert!(!prove_and_verify(&statement, &OrWitness::Left(y)));
+    assert!(!prove_and_verify(&statement, &OrWitness::Right(x)));
+}
+
+/// Composition nests: an OR of a DLEQ and an AND of two Schnorr proofs.
+#[test]
+fn compositions_nest() {
+    let ((a, x), (b, y)) = (schnorr(), schnorr());
+    let h = random_point();
+    let g = Point::gen();
+    let z = random_scalar();
+    let dleq = Dleq::new(g, h, g.scalar_mul(&z), h.scalar_mul(&random_scalar()));
+    let statement = Or::new(dleq, And::new(a, b));
+    assert!(prove_and_verify(&statement, &OrWitness::Right((x, y))));
+    assert!(!prove_and_verify(&statement, &OrWitness::Left(z)));
+}
+
+/// A simulated conversation is accepted, for any challenge.
+#[test]
+fn simulated_conversations_are_accepted() {
+    let ((left, _), (right, _)) = (schnorr(), schnorr());
+    let statement = Or::new(And::new(left, right), left);
+    let challenge = random_scalar();
+    let (commitment, response) = statement.simulate(&challenge, &mut rand::rng());
+    assert!(statement.verify(&commitment, &challenge, &response));
+}
+
+/// A non-interactive proof is bound to its transcript: it fails under another domain, after other
+/// messages, or for another statement.
+#[test]
+fn non_interactive_proof_is_bound_to_its_transcript() {
+    let (statement, x) = schnorr();
+    let mut transcript = Transcript::new(b"session");
+    transcript.append_message(b"id", b"7");
+    let proof = NonInteractiveProof::prove(&statement, &x, &mut transcript, &mut rand::rng());
+
+    let mut same = Transcript::new(b"session");
+    same.append_message(b"id", b"7");
+    assert!(proof.verify(&statement, &mut same));
+
+    let mut other_message = Transcript::new(b"session");
+    other_message.append_message(b"id", b"8");
+    assert!(!proof.verify(&statement, &mut other_message));
+    assert!(!proof.verify(&statement, &mut Transcript::new(b"session")));
+
+    let (other_statement, _) = schnorr();
+    let mut same = Transcript::new(b"session");
+    same.append_message(b"id", b"7");
+    assert!(!proof.verify(&other_statement, &mut same));
+}
+
+/// Runs an interactive proof of `statement`, with the prover holding `witness`, and returns the
+/// prover's and the verifier's outputs and the prover's trace.
+fn interact<P>(statement: P, witness: P::Witness) -> (Option<bool>, Option<bool>, String)
+where
+    P: SigmaProtocol + Clone + Send + Sync + 'static,
+    P::Witness: Clone + Send + Sync + 'static,
+    P::State: Send,
+    P::Commitment: Send + Sync,
+    P::Challenge: Send + Sync,
+    P::Response: Send + Sync,
+{
+    let prover = PartyId::from(0);
+    let verifier = PartyId::from(1);
+    let mut outcome = simulate(
+        SimpleNetworkConfig::default(),
+        vec![prover, verifier],
+        |party| {
+            if party == prover {
+                InteractiveProof::prover(statement.clone(), witness.clone())
+            } else {
+                InteractiveProof::verifier(statement.clone())
+            }
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    let trace = outcome.traces[&prover].to_string();
+    (
+        outcome.outputs.remove(&prover).unwrap(),
+        outcome.outputs.remove(&verifier).unwrap(),
+        trace,
+    )
+}
+
+#[test]
+fn interactive_proof_convinces_the_verifier() {
+    let (statement, x) = schnorr();
+    let (prover, verifier, trace) = interact(statement, x);
+    assert_eq!(prover, None);
+    assert_eq!(verifier, Some(true));
+    assert!(trace.contains("sigma com."));
+    assert!(trace.contains("sigma resp."));
+
+    let ((left, _), (right, y)) = (schnorr(), schnorr());
+    let (_, verifier, _) = interact(Or::new(left, right), OrWitness::Right(y));
+    assert_eq!(verifier, Some(true));
+}
+
+#[test]
+fn interactive_proof_rejects_a_wrong_witness() {
+    let (statement, x) = schnorr();
+    let (_, verifier, _) = interact(statement, x + &Scalar::ONE);
+    assert_eq!(verifier, Some(false));
+}
@@ -0,0 +1,374 @@
+//! End-to-end tests for the SPDZ protocols (`protocol::spdz`) on the deterministic simulator.
+
+use rand::SeedableRng;
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    math::{field::mersenne61::Mersenne61, ring::Ring},
+    net::{
+        simulation::{channel::SimpleNetworkConfig, event::Event, simulator::SimulationOutcome},
+        Network, PartyId,
+    },
+    prelude::{simulate, Error, GeneralEnv, Protocol, RandEnvironment},
+    protocol::{
+        spdz::{
+            input::SpdzInput,
+            mac_check::MacCheck,
+            mul::SpdzMul,
+            open::AuthenticatedOpen,
+            output::SpdzOutput,
+            preprocessing::{InputMask, SpdzTriple, TrustedSpdzPreprocessing},
+        },
+        ProtocolId,
+    },
+    ss::authenticated::{AuthenticatedSS, MacKey},
+};
+
+type F = Mersenne61;
+type Share = AuthenticatedSS<F>;
+
+const N: usize = 3;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+/// The outcome of a session as a party sees it: the values it opened, or the error it aborted
+/// with, rendered as a string so it can be returned from the simulator.
+type Outcome = Result<Vec<F>, String>;
+
+/// Opens the local party's `shares`, then MAC-checks them. If `cheater` is the local party, it
+/// adds one to the first value component it reveals.
+struct OpenThenCheck {
+    shares: Vec<Share>,
+    cheater: Option<PartyId>,
+}
+
+impl<E: RandEnvironment> Protocol<E> for OpenThenCheck {
+    type Output = Outcome;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let mut revealed = self.shares.clone();
+        if self.cheater == Some(env.network().local_party()) {
+            let share = &revealed[0];
+            revealed[0] = Share::new(
+                *share.share() + &F::ONE,
+                *share.mac(),
+                *share.mac_key_share(),
+                share.party(),
+                share.is_leader(),
+            );
+        }
+        let opened = AuthenticatedOpen::new(revealed).run(env).await?;
+        match MacCheck::new(b"open-then-check", opened.clone(), self.shares)?
+            .run(env)
+            .await
+        {
+            Ok(()) => Ok(Ok(opened)),
+            Err(err) => Ok(Err(err.to_string())),
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("OpenThenCheck")
+    }
+}
+
+/// Deals `secrets` under a fresh key and runs [`OpenThenCheck`], returning every party's outcome.
+fn open_then_check(secrets: &[F], cheater: Option<PartyId>) -> Vec<Outcome> {
+    let mut rng = rand::rng();
+    let key = MacKey::random(N, &mut rng);
+    let dealt: Vec<Vec<Share>> = secrets
+        .iter()
+        .map(|secret| Share::shares_from_secret(*secret, &parties(), &key, &mut rng).unwrap())
+        .collect();
+
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| OpenThenCheck {
+            shares: dealt
+                .iter()
+                .map(|shares| shares[pid.as_usize()].clone())
+                .collect(),
+            cheater,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    parties()
+        .iter()
+        .map(|party| outcome.outputs[party].clone())
+        .collect()
+}
+
+/// Honest openings pass the check, and every party sees the dealt values.
+#[test]
+fn honest_openings_pass_the_mac_check() {
+    let secrets: Vec<F> = (1..=5u64).map(F::from).collect();
+    for outcome in open_then_check(&secrets, None) {
+        assert_eq!(outcome, Ok(secrets.clone()));
+    }
+}
+
+/// A party that lies about its share when opening makes every party abort with the MAC-check
+/// error — including the cheater, which cannot fix up its `σ` after seeing the others'.
+#[test]
+fn tampered_opening_aborts_everyone() {
+    let secrets: Vec<F> = (1..=5u64).map(F::from).collect();
+    let expected = Error::MacCheck.to_string();
+    for outcome in open_then_check(&secrets, Some(parties()[1])) {
+        assert_eq!(outcome, Err(expected.clone()));
+    }
+}
+
+/// An empty batch is trivially valid.
+#[test]
+fn empty_batch_passes() {
+    for outcome in open_then_check(&[], None) {
+        assert_eq!(outcome, Ok(vec![]));
+    }
+}
+
+/// Runs the MAC check honestly, except on `replayer`, which waits for `victim`'s commitment and
+/// sends it to everyone as its own.
+struct ReplayCommitment {
+    shares: Vec<Share>,
+    victim: PartyId,
+    replayer: PartyId,
+}
+
+impl<E: RandEnvironment> Protocol<E> for ReplayCommitment {
+    type Output = Outcome;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let opened = AuthenticatedOpen::new(self.shares.clone()).run(env).await?;
+        if env.network().local_party() == self.replayer {
+            let commitment = env.network_mut().recv_from(self.victim).await?;
+            let messages: Vec<_> = parties()
+                .into_iter()
+                .filter(|party| *party != self.replayer)
+                .map(|party| (party, commitment.clone()))
+                .collect();
+            env.network_mut().send_many(&messages).await?;
+            return Ok(Ok(opened));
+        }
+        match MacCheck::new(b"replay", opened.clone(), self.shares)?
+            .run(env)
+            .await
+        {
+            Ok(()) => Ok(Ok(opened)),
+            Err(err) => Ok(Err(err.to_string())),
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("ReplayCommitment")
+    }
+}
+
+/// A party that echoes another party's commitment — which, with two parties, would cancel the
+/// coin toss — is named by every honest party before anything is opened.
+#[test]
+fn replayed_commitment_is_rejected() {
+    let mut rng = rand::rng();
+    let key = MacKey::random(N, &mut rng);
+    let dealt = Share::shares_from_secret(F::from(5u64), &parties(), &key, &mut rng).unwrap();
+    let (victim, replayer) = (parties()[0], parties()[1]);
+
+    let outcome = simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| ReplayCommitment {
+            shares: vec![dealt[pid.as_usize()].clone()],
+            victim,
+            replayer,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    );
+    let expected = Error::Cheater(replayer).to_string();
+    for party in parties().into_iter().filter(|party| *party != replayer) {
+        assert_eq!(
+            outcome.outputs[&party],
+            Err(expected.clone()),
+            "party {party:?}"
+        );
+    }
+}
+
+/// The opened values and the shares must line up.
+#[test]
+fn mac_check_rejects_mismatched_lengths() {
+    assert!(MacCheck::<F>::new(b"lengths", vec![F::ONE], vec![]).is_err());
+}
+
+/// How the cheating party deviates in [`MulThenAdd`].
+#[derive(Clone, Copy, PartialEq)]
+enum Cheat {
+    /// Everyone follows the protocol.
+    None,
+    /// The cheater adds one to the value component of its share of the triple's product `c`.
+    Triple,
+    /// The cheater adds one to the value component of its share of `x_1`, which skews the masked
+    /// value `d` it reveals in the multiplication.
+    Opening,
+}
+
+/// Party `i` inputs `inputs[i]`; the parties compute `x_1 · x_2 + x_3` with preprocessing from a
+/// trusted dealer, and reveal it. Party 1 deviates as `cheat` says.
+struct MulThenAdd {
+    inputs: Vec<F>,
+    cheat: Cheat,
+}
+
+/// Returns `share` with one added to its value component only, leaving its MAC untouched.
+fn skew(share: Share) -> Share {
+    Share::new(
+        *share.share() + &F::ONE,
+        *share.mac(),
+        *share.mac_key_share(),
+        share.party(),
+        share.is_leader(),
+    )
+}
+
+impl<E: RandEnvironment> Protocol<E> for MulThenAdd {
+    type Output = Outcome;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let cheating = me == parties()[1];
+        let mut preprocessing = TrustedSpdzPreprocessing::new(parties()[2], parties(), 1, 1)
+            .run(env)
+            .await?;
+
+        let mut x = Vec::with_capacity(N);
+        for owner in parties() {
+            let masks = preprocessing.take_input_masks(owner, 1)?;
+            let input = if owner == me {
+                SpdzInput::owner(owner, vec![self.inputs[owner.as_usize()]], masks)?
+            } else {
+                SpdzInput::receiver(owner, masks)
+            };
+            x.extend(input.run(env).await?);
+        }
+        let mut triples = preprocessing.take_triples(1)?;
+        if cheating && self.cheat == Cheat::Triple {
+            let (a, b, c) = triples.remove(0).into_parts();
+            triples.push(SpdzTriple::new(a, b, skew(c)));
+        }
+        if cheating && self.cheat == Cheat::Opening {
+            x[0] = skew(x[0].clone());
+        }
+
+        let (products, log) = SpdzMul::new(vec![x[0].clone()], vec![x[1].clone()], triples)?
+            .run(env)
+            .await?;
+        let result = products[0].clone() + &x[2];
+        match SpdzOutput::new(b"mul-then-add", vec![result], log)
+            .run(env)
+            .await
+        {
+            Ok(outputs) => Ok(Ok(outputs)),
+            Err(err) => Ok(Err(err.to_string())),
+        }
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("MulThenAdd")
+    }
+}
+
+fn simulate_mul_then_add(inputs: &[F], cheat: Cheat) -> SimulationOutcome<Outcome> {
+    simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |_| MulThenAdd {
+            inputs: inputs.to_vec(),
+            cheat,
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+}
+
+fn mul_then_add(inputs: &[F], cheat: Cheat) -> Vec<Outcome> {
+    let outcome = simulate_mul_then_add(inputs, cheat);
+    parties()
+        .iter()
+        .map(|party| outcome.outputs[party].clone())
+        .collect()
+}
+
+/// An honest session outputs `x_1 · x_2 + x_3` to everyone.
+#[test]
+fn spdz_online_computes_a_circuit() {
+    let inputs: Vec<F> = [6u64, 7, 8].into_iter().map(F::from).collect();
+    for outcome in mul_then_add(&inputs, Cheat::None) {
+        assert_eq!(outcome, Ok(vec![F::from(50u64)]));
+    }
+}
+
+/// A corrupted triple is caught by the check of the opened outputs, and no party returns the (wrong)
+/// result.
+#[test]
+fn spdz_output_aborts_on_a_tampered_triple() {
+    let inputs: Vec<F> = [6u64, 7, 8].into_iter().map(F::from).collect();
+    let expected = Error::MacCheck.to_string();
+    for outcome in mul_then_add(&inputs, Cheat::Triple) {
+        assert_eq!(outcome, Err(expected.clone()));
+    }
+}
+
+/// Counts the openings in `party`'s trace.
+fn openings(outcome: &SimulationOutcome<Outcome>, party: PartyId) -> usize {
+    outcome.traces[&party]
+        .events()
+        .iter()
+        .filter(|event| {
+            matches!(event, Event::ProtocolBegin { protocol_name, .. }
+                if *protocol_name == ProtocolId::from("AuthenticatedOpen"))
+        })
+        .count()
+}
+
+/// The log is checked before the outputs are opened: after a lie in the multiplication's opening,
+/// the only opening in any trace is the multiplication's own. A tampered triple leaves the log
+/// intact, so the outputs are opened, but the check that follows keeps every party from returning
+/// them.
+#[test]
+fn spdz_output_checks_the_log_before_opening_outputs() {
+    let inputs: Vec<F> = [6u64, 7, 8].into_iter().map(F::from).collect();
+    let expected = Error::MacCheck.to_string();
+
+    let outcome = simulate_mul_then_add(&inputs, Cheat::Opening);
+    for party in parties() {
+        assert_eq!(openings(&outcome, party), 1, "party {party:?}");
+        assert_eq!(outcome.outputs[&party], Err(expected.clone()));
+    }
+
+    let outcome = simulate_mul_then_add(&inputs, Cheat::Triple);
+    for party in parties() {
+        assert_eq!(openings(&outcome, party), 2, "party {party:?}");
+        assert_eq!(outcome.outputs[&party], Err(expected.clone()));
+    }
+}
+
+/// A lie in a multiplication's opening is caught by the check of the log, before any output is
+/// opened.
+#[test]
+fn spdz_output_aborts_on_a_tampered_opening() {
+    let inputs: Vec<F> = [6u64, 7, 8].into_iter().map(F::from).collect();
+    let expected = Error::MacCheck.to_string();
+    for outcome in mul_then_add(&inputs, Cheat::Opening) {
+        assert_eq!(outcome, Err(expected.clone()));
+    }
+}
+
+/// The owner must hold the clear value of every mask it spends, one per input.
+#[test]
+fn spdz_input_requires_owned_masks() {
+    let share = Share::new(F::ONE, F::ONE, F::ONE, parties()[0], true);
+    let mask = InputMask::new(share, None);
+    assert!(SpdzInput::owner(parties()[0], vec![F::ONE], vec![mask]).is_err());
+}
@@ -0,0 +1,255 @@
+//! Tests for the persistent preprocessing store (`protocol::passive_shamir::store`): the file
+//! format, consume-once semantics across reloads, and an offline phase feeding a later online
+//! phase through the file system.
+
+use std::path::PathBuf;
+
+use scl_rs::{
+    math::{field::mersenne61::Mersenne61, ring::Ring},
+    net::{Network, PartyId},
+    prelude::{Error, Protocol, RandEnvironment},
+    protocol::{
+        dealer::{DealerRequest, TrustedDealer},
+        passive_shamir::{
+            mul::PassiveShamirMul,
+            store::{PreprocessingStore, StoreError, StoreHeader},
+            triple::ShamirTriple,
+        },
+        share::open::BatchedPassiveOpenShr,
+        ProtocolId,
+    },
+    ss::shamir::ShamirSS,
+};
+
+mod common;
+
+type F = Mersenne61;
+type Share = ShamirSS<1, F>;
+type Store = PreprocessingStore<1, F>;
+
+const N: usize = 3;
+const T: usize = 1;
+
+fn parties() -> Vec<PartyId> {
+    (0..N).map(PartyId::from).collect()
+}
+
+/// A fresh path in the temporary directory, unique to this test process and `name`.
+fn temp_path(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("scl-rs-store-{}-{name}.bin", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+    path
+}
+
+fn header(session_id: u64) -> StoreHeader {
+    StoreHeader::new::<1, F>(T, parties(), parties()[0], session_id)
+}
+
+fn triple(k: u64) -> ShamirTriple<1, F> {
+    let share = |v: u64| Share::new(F::from(v), T);
+    ShamirTriple::new(share(3 * k), share(3 * k + 1), share(3 * k + 2))
+}
+
+/// Taken items are gone for good: reopening the file hands out the next ones, never the same.
+#[test]
+fn consumed_triples_stay_consumed_across_reloads() {
+    let path = temp_path("consume");
+    let mut store = Store::create(&path, header(7)).unwrap();
+    store.add_triples((0..5).map(triple).collect()).unwrap();
+    store
+        .add_random_bits(vec![Share::new(F::ONE, T); 2])
+        .unwrap();
+
+    let first = store.take_triples(2).unwrap();
+    assert_eq!(first[1].a(), triple(1).a());
+    drop(store);
+
+    let mut store = Store::open(&path, &header(7)).unwrap();
+    assert_eq!(store.triples_left(), 3);
+    assert_eq!(store.random_bits_left(), 2);
+    assert_eq!(store.consumed(), (2, 0, 0));
+    let next = store.take_triples(1).unwrap();
+    assert_eq!(next[0].a(), triple(2).a());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+/// Asking for more than is left fails and takes nothing.
+#[test]
+fn exhausted_store_hands_out_nothing() {
+    let path = temp_path("exhausted");
+    let mut store = Store::create(&path, header(1)).unwrap();
+    store.add_triples(vec![triple(0)]).unwrap();
+
+    assert!(matches!(
+        store.take_triples(2),
+        Err(StoreError::Exhausted {
+            requested: 2,
+            left: 1,
+            ..
+        })
+    ));
+    assert_eq!(store.triples_left(), 1);
+    assert!(store.take_random_shares(1).is_err());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+/// The header guards against mixing material from different sessions or parameters, and sharings
+/// of the wrong degree are refused.
+#[test]
+fn store_rejects_foreign_material() {
+    let path = temp_path("foreign");
+    let mut store = Store::create(&path, header(1)).unwrap();
+    assert!(matches!(
+        store.add_random_shares(vec![Share::new(F::ONE, T + 1)]),
+        Err(StoreError::DegreeMismatch { .. })
+    ));
+    assert_eq!(store.random_shares_left(), 0);
+    drop(store);
+
+    assert!(matches!(
+        Store::open(&path, &header(2)),
+        Err(StoreError::HeaderMismatch { .. })
+    ));
+    // An existing store is never overwritten.
+    assert!(matches!(
+        Store::create(&path, header(1)),
+        Err(StoreError::Io(_))
+    ));
+
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes[8] = bytes[8].wrapping_add(1);
+    std::fs::write(&path, &bytes).unwrap();
+    assert!(matches!(
+        Store::open(&path, &header(1)),
+        Err(StoreError::UnsupportedVersion(_))
+    ));
+
+    std::fs::write(&path, b"not a store").unwrap();
+    assert!(matches!(
+        Store::open(&path, &header(1)),
+        Err(StoreError::NotAStore)
+    ));
+
+    let _ = std::fs::remove_file(&path);
+}
+
+/// Only one handle on a store exists at a time; dropping it releases the store.
+#[test]
+fn open_store_is_locked() {
+    let path = temp_path("locked");
+    let store = Store::create(&path, header(3)).unwrap();
+    assert!(matches!(
+        Store::open(&path, &header(3)),
+        Err(StoreError::Locked(_))
+    ));
+    drop(store);
+
+    let store = Store::open(&path, &header(3)).unwrap();
+    assert!(matches!(
+        Store::create(&path, header(3)),
+        Err(StoreError::Locked(_))
+    ));
+    drop(store);
+    assert!(Store::open(&path, &header(3)).is_ok());
+
+    let _ = std::fs::remove_file(&path);
+}
+
+/// The path of `party`'s store for the two-session test.
+fn session_path(party: PartyId) -> PathBuf {
+    std::env::temp_dir().join(format!(
+        "scl-rs-store-{}-session-{}.bin",
+        std::process::id(),
+        party.as_usize()
+    ))
+}
+
+fn session_header(party: PartyId) -> StoreHeader {
+    StoreHeader::new::<1, F>(T, parties(), party, 42)
+}
+
+/// Offline session: a dealer hands out triples and random sharings, and each party files them in
+/// its own store.
+struct Offline;
+
+impl<E: RandEnvironment> Protocol<E> for Offline {
+    type Output = ();
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let request = DealerRequest {
+            triples: 2,
+            random_shares: 4,
+            ..DealerRequest::default()
+        };
+        let mut dealt = if me == parties()[0] {
+            TrustedDealer::dealer(me, parties(), T, 2 * T, request)
+        } else {
+            TrustedDealer::receiver(parties()[0], request)
+        }
+        .run(env)
+        .await?;
+
+        let mut store = Store::create(session_path(me), session_header(me)).unwrap();
+        let triples = dealt.take_triples(2)?.into_iter().map(|triple| {
+            let (a, b, c) = triple.into_parts();
+            ShamirTriple::new(a, b, c)
+        });
+        store.add_triples(triples.collect()).unwrap();
+        store
+            .add_random_shares(dealt.take_random_shares(4)?)
+            .unwrap();
+        Ok(())
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("Offline")
+    }
+}
+
+/// Online session: each party reloads its store and multiplies two pairs of random sharings.
+struct Online;
+
+impl<E: RandEnvironment> Protocol<E> for Online {
+    type Output = Vec<F>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        let me = env.network().local_party();
+        let mut store = Store::open(session_path(me), &session_header(me)).unwrap();
+        let mut x = store.take_random_shares(4).unwrap();
+        let y = x.split_off(2);
+        let triples = store.take_triples(2).unwrap();
+        let z = PassiveShamirMul::new(parties()[0], parties(), x.clone(), y.clone(), triples)?
+            .run(env)
+            .await?;
+        BatchedPassiveOpenShr::new([x, y, z].concat())
+            .run(env)
+            .await
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("Online")
+    }
+}
+
+/// Material made in one session and filed away is consumed by a later one, and is then gone.
+#[test]
+fn stored_triples_serve_a_later_online_phase() {
+    for party in parties() {
+        let _ = std::fs::remove_file(session_path(party));
+    }
+    common::run(parties(), |_| Offline);
+    let outputs = common::run(parties(), |_| Online);
+
+    let opened = &outputs[&parties()[0]];
+    for k in 0..2 {
+        assert_eq!(opened[4 + k], opened[k] * &opened[2 + k], "product {k}");
+    }
+    for party in parties() {
+        let store = Store::open(session_path(party), &session_header(party)).unwrap();
+        assert_eq!((store.triples_left(), store.random_shares_left()), (0, 0));
+        std::fs::remove_file(session_path(party)).unwrap();
+    }
+}
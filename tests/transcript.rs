@@ -0,0 +1,96 @@
+//! Tests for Fiat–Shamir transcripts (`transcript`).
+
+use scl_rs::{
+    math::{
+        ec::{secp256k1::Secp256k1, EllipticCurve},
+        field::{mersenne61::Mersenne61, secp256k1_scalar::Secp256k1ScalarField},
+        ring::Ring,
+    },
+    transcript::Transcript,
+};
+
+type Scalar = Secp256k1ScalarField;
+
+/// A transcript with a point, a field element and a message appended.
+fn transcript(domain: &'static [u8], value: u64, message: &[u8]) -> Transcript {
+    let mut transcript = Transcript::new(domain);
+    transcript.append_point(b"generator", &Secp256k1::gen());
+    transcript.append_field(b"value", &Mersenne61::from(value));
+    transcript.append_message(b"message", message);
+    transcript
+}
+
+fn challenge(mut transcript: Transcript) -> Scalar {
+    transcript.challenge_field(b"challenge")
+}
+
+#[test]
+fn same_appends_give_same_challenges() {
+    let mut first = transcript(b"test", 1, b"hello");
+    let mut second = transcript(b"test", 1, b"hello");
+    for _ in 0..3 {
+        assert_eq!(
+            first.challenge_field::<4, Scalar>(b"c"),
+            second.challenge_field::<4, Scalar>(b"c")
+        );
+        assert_eq!(first.challenge_bytes(b"b"), second.challenge_bytes(b"b"));
+    }
+}
+
+#[test]
+fn challenges_depend_on_everything_appended() {
+    let reference = challenge(transcript(b"test", 1, b"hello"));
+    assert_ne!(challenge(transcript(b"other", 1, b"hello")), reference);
+    assert_ne!(challenge(transcript(b"test", 2, b"hello")), reference);
+    assert_ne!(challenge(transcript(b"test", 1, b"hellp")), reference);
+
+    let mut relabeled = Transcript::new(b"test");
+    relabeled.append_point(b"public", &Secp256k1::gen());
+    relabeled.append_field(b"value", &Mersenne61::from(1));
+    relabeled.append_message(b"message", b"hello");
+    assert_ne!(challenge(relabeled), reference);
+
+    // Moving bytes between a label-delimited message and the next one changes the challenge.
+    let mut split = Transcript::new(b"test");
+    split.append_message(b"m", b"ab");
+    split.append_message(b"m", b"c");
+    let mut resplit = Transcript::new(b"test");
+    resplit.append_message(b"m", b"a");
+    resplit.append_message(b"m", b"bc");
+    assert_ne!(challenge(split), challenge(resplit));
+}
+
+#[test]
+fn successive_challenges_differ() {
+    let mut transcript = transcript(b"test", 1, b"hello");
+    let first: Scalar = transcript.challenge_field(b"c");
+    let second: Scalar = transcript.challenge_field(b"c");
+    assert_ne!(first, second);
+    assert_ne!(first, Scalar::ZERO);
+
+    let small: Mersenne61 = transcript.challenge_field(b"c");
+    let again: Mersenne61 = transcript.challenge_field(b"c");
+    assert_ne!(small, again);
+}
+
+/// A transcript displays what was absorbed, with each value's type label.
+#[test]
+fn transcript_displays_what_was_absorbed() {
+    let mut transcript = transcript(b"test", 1, b"hello");
+    let _: Scalar = transcript.challenge_field(b"challenge");
+    let lines: Vec<String> = transcript
+        .to_string()
+        .lines()
+        .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+        .collect();
+    assert_eq!(
+        lines,
+        [
+            "domain test (bytes)",
+            "append generator (EC elem.)",
+            "append value (field elem.)",
+            "append message (bytes)",
+            "squeeze challenge (field elem.)",
+        ]
+    );
+}
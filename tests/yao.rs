@@ -0,0 +1,263 @@
+//! Tests for Yao's garbled circuits (`protocol::yao`): the Bristol Fashion loader, evaluation in
+//! the clear, and the two-party protocol on the deterministic simulator.
+
+use std::collections::HashMap;
+
+use rand::{RngExt, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+use scl_rs::{
+    net::{simulation::channel::SimpleNetworkConfig, PartyId},
+    prelude::{simulate, Error, GeneralEnv, Protocol, RandEnvironment},
+    protocol::{
+        yao::{
+            circuit::{BooleanCircuit, CircuitError, Gate},
+            garble::YaoGarbledCircuit,
+        },
+        ProtocolId,
+    },
+};
+
+fn parties() -> Vec<PartyId> {
+    vec![PartyId::from(0), PartyId::from(1)]
+}
+
+/// A ripple-carry adder of two `bits`-bit values in Bristol Fashion, least significant bit first,
+/// with one AND gate per bit: the carry is `c ⊕ ((a ⊕ c) ∧ (b ⊕ c))`. The initial carry is an EQ
+/// constant and the sum bits are copied to the output wires with EQW, so every gate kind but INV
+/// and MAND shows up.
+fn adder(bits: usize) -> String {
+    let mut gates = Vec::new();
+    let mut next = 2 * bits;
+    let mut wire = || {
+        next += 1;
+        next - 1
+    };
+    let mut carry = wire();
+    gates.push(format!("1 1 0 {carry} EQ"));
+    let mut sums = Vec::new();
+    for i in 0..bits {
+        let (a, b) = (i, bits + i);
+        let (ac, bc, and, sum, next_carry) = (wire(), wire(), wire(), wire(), wire());
+        gates.push(format!("2 1 {a} {carry} {ac} XOR"));
+        gates.push(format!("2 1 {b} {carry} {bc} XOR"));
+        gates.push(format!("2 1 {ac} {bc} {and} AND"));
+        gates.push(format!("2 1 {ac} {b} {sum} XOR"));
+        gates.push(format!("2 1 {carry} {and} {next_carry} XOR"));
+        sums.push(sum);
+        carry = next_carry;
+    }
+    for sum in sums {
+        let out = wire();
+        gates.push(format!("1 1 {sum} {out} EQW"));
+    }
+    format!(
+        "{} {next}\n2 {bits} {bits}\n1 {bits}\n\n{}\n",
+        gates.len(),
+        gates.join("\n")
+    )
+}
+
+fn to_bits(value: u64, bits: usize) -> Vec<bool> {
+    (0..bits).map(|i| value >> i & 1 == 1).collect()
+}
+
+fn from_bits(bits: &[bool]) -> u64 {
+    bits.iter()
+        .enumerate()
+        .fold(0, |value, (i, bit)| value | u64::from(*bit) << i)
+}
+
+/// Runs [`YaoGarbledCircuit`] on `circuit`, with `garbler` garbling, and returns every party's
+/// output, or its error as a string.
+fn yao(
+    circuit: &BooleanCircuit,
+    garbler: usize,
+    inputs: [Vec<bool>; 2],
+) -> HashMap<PartyId, Result<Vec<Vec<bool>>, String>> {
+    simulate(
+        SimpleNetworkConfig::default(),
+        parties(),
+        |pid| {
+            Guarded(YaoGarbledCircuit::new(
+                circuit.clone(),
+                PartyId::from(garbler),
+                inputs[usize::from(pid.as_usize() != garbler)].clone(),
+            ))
+        },
+        |_, net| GeneralEnv::new(net, ChaCha20Rng::from_rng(&mut rand::rng())),
+        vec![],
+    )
+    .outputs
+}
+
+/// Runs a protocol, turning its error into an output.
+struct Guarded(YaoGarbledCircuit);
+
+impl<E: RandEnvironment> Protocol<E> for Guarded {
+    type Output = Result<Vec<Vec<bool>>, String>;
+
+    async fn run(self, env: &mut E) -> Result<Self::Output, Error> {
+        Ok(self.0.run(env).await.map_err(|error| error.to_string()))
+    }
+
+    fn id(&self) -> ProtocolId {
+        ProtocolId::from("Guarded")
+    }
+}
+
+#[test]
+fn bristol_adder_adds_in_the_clear() {
+    let circuit = BooleanCircuit::from_bristol(&adder(8)).unwrap();
+    assert_eq!(circuit.inputs(), [8, 8]);
+    assert_eq!(circuit.outputs(), [8]);
+    assert_eq!(circuit.and_count(), 8);
+
+    let mut rng = rand::rng();
+    for _ in 0..32 {
+        let (a, b): (u8, u8) = (rng.random(), rng.random());
+        let sum = circuit
+            .evaluate(&[to_bits(a.into(), 8), to_bits(b.into(), 8)])
+            .unwrap();
+        assert_eq!(from_bits(&sum[0]), u64::from(a.wrapping_add(b)));
+    }
+    assert!(matches!(
+        circuit.evaluate(&[to_bits(1, 8)]),
+        Err(CircuitError::InputCount { .. })
+    ));
+    assert!(matches!(
+        circuit.evaluate(&[to_bits(1, 8), to_bits(1, 7)]),
+        Err(CircuitError::InputLength { index: 1, .. })
+    ));
+}
+
+#[test]
+fn bristol_loader_reads_mand_and_files() {
+    let text = "1 6\n2 2 2\n2 1 1\n\n4 2 0 1 2 3 4 5 MAND\n";
+    let circuit = BooleanCircuit::from_bristol(text).unwrap();
+    assert_eq!(
+        circuit.gates(),
+        [
+            Gate::And { a: 0, b: 2, out: 4 },
+            Gate::And { a: 1, b: 3, out: 5 }
+        ]
+    );
+
+    let path = std::env::temp_dir().join(format!("scl-rs-adder-{}.txt", std::process::id()));
+    std::fs::write(&path, adder(4)).unwrap();
+    let loaded = BooleanCircuit::load_bristol(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, BooleanCircuit::from_bristol(&adder(4)).unwrap());
+    assert!(matches!(
+        BooleanCircuit::load_bristol(&path),
+        Err(CircuitError::Io(_))
+    ));
+}
+
+/// Headers that declare more than the text can hold are refused before anything is allocated
+/// for them, and sizes that overflow are refused too.
+#[test]
+fn bristol_loader_rejects_malicious_headers() {
+    let max = usize::MAX;
+    let malicious = [
+        // Input sizes that overflow when summed.
+        (format!("1 3\n2 {max} 2\n1 1\n2 1 0 1 2 XOR"), 2),
+        // Output sizes that overflow when summed.
+        (format!("1 3\n1 2\n2 {max} 1\n2 1 0 1 2 XOR"), 3),
+        // Far more gates than lines.
+        (format!("{max} 3\n1 2\n1 1\n2 1 0 1 2 XOR"), 1),
+        // Far more wires than the gates could write.
+        (format!("1 {max}\n1 2\n1 1\n2 1 0 1 2 XOR"), 1),
+        (format!("1 {}\n1 2\n1 1\n2 1 0 1 2 XOR", 1u64 << 40), 1),
+    ];
+    for (text, expected) in malicious {
+        match BooleanCircuit::from_bristol(&text) {
+            Err(CircuitError::Parse { line, .. }) => assert_eq!(line, expected, "{text}"),
+            other => panic!("{text}: expected a parse error, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn bristol_loader_rejects_malformed_circuits() {
+    let malformed = [
+        // A header with a missing count.
+        ("1\n1 1\n1 1\n2 1 0 0 1 XOR", 1),
+        // More sizes than values.
+        ("1 3\n1 1 1\n1 1\n2 1 0 1 2 XOR", 2),
+        // A wire read before it is written.
+        ("1 3\n1 1\n1 1\n2 1 0 1 2 XOR", 4),
+        // A wire written twice.
+        ("2 3\n1 2\n1 1\n2 1 0 1 2 AND\n1 1 0 2 INV", 5),
+        // An unknown gate.
+        ("1 3\n1 2\n1 1\n2 1 0 1 2 NAND", 4),
+        // Fewer gates than declared.
+        ("2 3\n1 2\n1 1\n2 1 0 1 2 XOR", 5),
+        // More gates than declared.
+        ("1 3\n1 2\n1 1\n2 1 0 1 2 XOR\n1 1 0 2 INV", 5),
+        // Input and output counts whose sum overflows.
+        ("1 3\n1 2\n1 1\n18446744073709551615 1 0 2 XOR", 4),
+    ];
+    for (text, expected) in malformed {
+        match BooleanCircuit::from_bristol(text) {
+            Err(CircuitError::Parse { line, .. }) => assert_eq!(line, expected, "{text}"),
+            other => panic!("{text}: expected a parse error, got {other:?}"),
+        }
+    }
+}
+
+#[test]
+fn yao_computes_the_sum_for_either_garbler() {
+    let circuit = BooleanCircuit::from_bristol(&adder(16)).unwrap();
+    let mut rng = rand::rng();
+    for garbler in 0..2 {
+        let (a, b): (u16, u16) = (rng.random(), rng.random());
+        let outputs = yao(
+            &circuit,
+            garbler,
+            [to_bits(a.into(), 16), to_bits(b.into(), 16)],
+        );
+        for party in parties() {
+            let sum = outputs[&party].as_ref().unwrap();
+            assert_eq!(from_bits(&sum[0]), u64::from(a.wrapping_add(b)));
+        }
+    }
+}
+
+/// Every gate kind, constants on both values and a wire that is both read and output, against
+/// evaluation in the clear.
+#[test]
+fn yao_matches_evaluation_in_the_clear() {
+    let text = "\
+        7 12\n2 2 2\n2 2 2\n\n\
+        1 1 0 4 INV\n\
+        1 1 1 5 EQ\n\
+        4 2 4 1 2 5 6 7 MAND\n\
+        2 1 6 3 9 XOR\n\
+        1 1 7 8 EQW\n\
+        2 1 8 9 10 AND\n\
+        1 1 0 11 EQ\n";
+    let circuit = BooleanCircuit::from_bristol(text).unwrap();
+    let mut rng = rand::rng();
+    for _ in 0..2 {
+        let (a, b) = (rng.random_range(0..4), rng.random_range(0..4));
+        let inputs = [to_bits(a, 2), to_bits(b, 2)];
+        let expected = circuit.evaluate(&inputs).unwrap();
+        for output in yao(&circuit, 0, inputs).into_values() {
+            assert_eq!(output, Ok(expected.clone()), "a = {a}, b = {b}");
+        }
+    }
+}
+
+/// The inputs must match the circuit: two input values, and the local one of the right length.
+#[test]
+fn yao_rejects_mismatched_inputs() {
+    let circuit = BooleanCircuit::from_bristol(&adder(4)).unwrap();
+    for output in yao(&circuit, 1, [to_bits(0, 3), to_bits(0, 5)]).into_values() {
+        assert_eq!(output, Err(Error::Input.to_string()));
+    }
+
+    let three_inputs = BooleanCircuit::from_bristol("1 4\n3 1 1 1\n1 1\n2 1 0 1 3 XOR").unwrap();
+    for output in yao(&three_inputs, 0, [vec![true], vec![false]]).into_values() {
+        assert_eq!(output, Err(Error::Input.to_string()));
+    }
+}